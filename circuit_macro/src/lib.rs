@@ -6,27 +6,82 @@ use quote::{format_ident, quote};
 use std::collections::HashSet;
 use syn::{
     parse_macro_input, BinOp, Expr, ExprAssign, ExprBinary, ExprBlock, ExprIf, ExprLet, ExprMatch,
-    ExprReference, ExprUnary, FnArg, ItemFn, Lit, Pat, PatType,
+    ExprMethodCall, ExprReference, ExprUnary, ExprWhile, FnArg, ItemFn, Lit, Pat, PatType,
 };
 
 #[proc_macro_attribute]
 pub fn encrypted(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let mode = parse_macro_input!(attr as syn::Ident).to_string(); // Retrieve the mode (e.g., "compile" or "execute")
-    generate_macro(item, &mode)
+    // The mode ("compile" or "execute") followed by options, e.g. `execute, overflow_check`
+    let args = parse_macro_input!(
+        attr with syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated
+    );
+    let mut args = args.into_iter().map(|arg| arg.to_string());
+    let mode = args
+        .next()
+        .expect("Expected a mode: `compile` or `execute`");
+
+    let mut overflow_check = false;
+    for option in args {
+        match option.as_str() {
+            "overflow_check" => overflow_check = true,
+            other => panic!("Unknown option `{}`", other),
+        }
+    }
+
+    generate_macro(item, &mode, overflow_check)
 }
 
-/// Generates the macro code based on the mode (either "compile" or "execute")
-fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
+/// Generates the macro code based on the mode (either "compile" or "execute").
+///
+/// With `overflow_check`, `+`, `-` and `*` also record their carry, borrow or overflow bit and
+/// the function returns `(result, overflowed)`, where `overflowed` is the OR of all of them.
+fn generate_macro(item: TokenStream, mode: &str, overflow_check: bool) -> TokenStream {
+    // `**` is only visible in the token spacing, which parsing discards
+    let tokens = proc_macro2::TokenStream::from(item.clone());
     let input_fn = parse_macro_input!(item as ItemFn);
     let fn_name = &input_fn.sig.ident; // Function name
     let inputs = &input_fn.sig.inputs; // Function input parameters
 
-    // get the type of the first input parameter
-    let type_name = if let FnArg::Typed(PatType { ty, .. }) = &inputs[0] {
-        quote! {#ty}
-    } else {
-        panic!("Expected typed argument");
+    // The circuit width comes from the first primitive parameter type. Otherwise the first
+    // parameter type names a type parameter, e.g. `T`, unless the return type is a different
+    // primitive, which then sets the width for parameters that are all structs.
+    let param_type = |input: &FnArg| match input {
+        FnArg::Typed(PatType { ty, .. }) => (**ty).clone(),
+        _ => panic!("Expected typed argument"),
     };
+    let first_type = param_type(&inputs[0]);
+    let primitive_param = inputs
+        .iter()
+        .map(param_type)
+        .find(|ty| primitive_type_width(ty).is_some());
+    let (width_type, generic) = match (primitive_param, &input_fn.sig.output) {
+        (Some(ty), _) => (ty, true),
+        (None, syn::ReturnType::Type(_, ty))
+            if primitive_type_width(ty).is_some()
+                && quote! {#ty}.to_string() != quote! {#first_type}.to_string() =>
+        {
+            ((**ty).clone(), false)
+        }
+        _ => (first_type, true),
+    };
+    let type_name = quote! {#width_type};
+
+    // Parameters of any other non-primitive type derive `GarbledEncode`
+    let struct_params: HashSet<String> = inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(PatType { pat, ty, .. })
+                if primitive_type_width(ty).is_none()
+                    && quote! {#ty}.to_string() != type_name.to_string() =>
+            {
+                match &**pat {
+                    Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
 
     // get the type of the first output parameter
     let output_type = if let syn::ReturnType::Type(_, ty) = &input_fn.sig.output {
@@ -35,13 +90,32 @@ fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
         panic!("Expected typed return type");
     };
 
+    // Signed parameter types are lowered with two's complement comparisons
+    let signed = matches!(
+        type_name.to_string().as_str(),
+        "i8" | "i16" | "i32" | "i64" | "i128"
+    );
+
+    // Signed values convert through their two's complement `GarbledInt`
+    let garbled = if signed {
+        quote! {::compute::int::GarbledInt}
+    } else {
+        quote! {GarbledUint}
+    };
+
     // We need to extract each input's identifier
     let mapped_inputs = inputs.iter().map(|input| {
         if let FnArg::Typed(PatType { pat, .. }) = input {
             if let Pat::Ident(pat_ident) = &**pat {
                 let var_name = &pat_ident.ident;
+                if struct_params.contains(&var_name.to_string()) {
+                    // the fields are read through the `<Name>Wires` struct
+                    return quote! {
+                        let #var_name = &context.input_encoded(&#var_name);
+                    };
+                }
                 quote! {
-                    let #var_name = &context.input(&#var_name.clone().into());
+                    let #var_name = &context.keyed_input(stringify!(#var_name), &GarbledUint::<N>::from(Into::<#garbled<N>>::into(#var_name.clone())));
                 }
             } else {
                 quote! {}
@@ -51,13 +125,41 @@ fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
         }
     });
 
+    if overflow_check && mode != "execute" {
+        panic!("`overflow_check` is only supported in `execute` mode");
+    }
+    if overflow_check && signed {
+        panic!("`overflow_check` only supports unsigned parameters");
+    }
+
     // Extract constants to be added at the top of the function
-    let mut constants = vec![];
-    let transformed_block = modify_body(*input_fn.block, &mut constants);
+    let mut state = LoweringState {
+        signed,
+        overflow_check,
+        struct_params: struct_params.clone(),
+        ..Default::default()
+    };
+    find_pow_operators(tokens, &mut state.errors);
+    let transformed_block = modify_body(*input_fn.block.clone(), &mut state);
+
+    // report unsupported constructs at their spans, keeping the signature so callers still
+    // resolve and only the body's errors are shown
+    if !state.errors.is_empty() {
+        let errors = state.errors.iter().map(syn::Error::to_compile_error);
+        let signature = &input_fn.sig;
+        return TokenStream::from(quote! {
+            #(#errors)*
+            #[allow(unused_variables)]
+            #signature {
+                unreachable!()
+            }
+        });
+    }
 
     // remove duplicates
     let mut seen = HashSet::new();
-    let constants: Vec<proc_macro2::TokenStream> = constants
+    let constants: Vec<proc_macro2::TokenStream> = state
+        .constants
         .into_iter()
         .filter(|item| seen.insert(item.to_string()))
         .collect();
@@ -87,6 +189,11 @@ fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
             "u32" => generate::<32, #type_name>(#(#param_names),*),
             "u64" => generate::<64, #type_name>(#(#param_names),*),
             "u128" => generate::<128, #type_name>(#(#param_names),*),
+            "i8" => generate::<8, #type_name>(#(#param_names),*),
+            "i16" => generate::<16, #type_name>(#(#param_names),*),
+            "i32" => generate::<32, #type_name>(#(#param_names),*),
+            "i64" => generate::<64, #type_name>(#(#param_names),*),
+            "i128" => generate::<128, #type_name>(#(#param_names),*),
             _ => panic!("Unsupported type"),
         }
     };
@@ -94,13 +201,45 @@ fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
     // Set the output type and operation logic based on mode
     let output_type = if mode == "compile" {
         quote! {(Circuit, Vec<bool>)}
+    } else if overflow_check {
+        quote! {(#output_type, bool)}
     } else {
         quote! {#output_type}
     };
 
     let operation = if mode == "compile" {
         quote! {
-            (context.compile(&output), context.inputs().to_vec())
+            (context.compile(&output.into()), context.inputs().to_vec())
+        }
+    } else if overflow_check {
+        // the overflow flag is one extra output bit after the result
+        quote! {
+            let mut output_indices: GateIndexVec = output.into();
+            let width = output_indices.len();
+            let overflowed = context.overflowed();
+            output_indices.push(overflowed);
+
+            let compiled_circuit = context.compile(&output_indices);
+            let result = context.execute::<N>(&compiled_circuit).expect("Execution failed");
+            let bits = result.bits();
+            (GarbledUint::<N>::new(bits[..width].to_vec()).into(), bits[width])
+        }
+    } else if let Some(return_width) = signed_return_width(&output_type, &type_name) {
+        // a signed return type other than the parameter type is decoded as two's complement,
+        // sign-extending (or wrapping) the circuit output to the width of the return type
+        quote! {
+            let compiled_circuit = context.compile(&output.into());
+            let result = context.execute::<N>(&compiled_circuit).expect("Execution failed");
+            let mut bits = result.into_bits();
+            let sign = bits.last().copied().unwrap_or(false);
+            bits.resize(#return_width, sign);
+            ::compute::int::GarbledInt::<#return_width>::new(bits).into()
+        }
+    } else if output_type.to_string() == type_name.to_string() {
+        quote! {
+            let compiled_circuit = context.compile(&output.into());
+            let result = context.execute::<N>(&compiled_circuit).expect("Execution failed");
+            #garbled::<N>::from(result).into()
         }
     } else {
         quote! {
@@ -110,28 +249,40 @@ fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
         }
     };
 
+    // The function is generic over the primitive parameter type; with only struct parameters
+    // the width comes from the concrete return type
+    let signature = if generic {
+        quote! {
+            fn #fn_name<#type_name>(#inputs) -> #output_type
+            where
+            #type_name: Into<#garbled<1>> + From<#garbled<1>>
+                    + Into<#garbled<8>> + From<#garbled<8>>
+                    + Into<#garbled<16>> + From<#garbled<16>>
+                    + Into<#garbled<32>> + From<#garbled<32>>
+                    + Into<#garbled<64>> + From<#garbled<64>>
+                    + Into<#garbled<128>> + From<#garbled<128>>
+                    + Clone,
+        }
+    } else {
+        quote! {
+            fn #fn_name(#inputs) -> #output_type
+        }
+    };
+
     // Build the function body with circuit context, compile, and execute
     let expanded = quote! {
         #[allow(non_camel_case_types, non_snake_case, clippy::builtin_type_shadow, unused_assignments)]
-        fn #fn_name<#type_name>(#inputs) -> #output_type
-        where
-        #type_name: Into<GarbledUint<1>> + From<GarbledUint<1>>
-                + Into<GarbledUint<8>> + From<GarbledUint<8>>
-                + Into<GarbledUint<16>> + From<GarbledUint<16>>
-                + Into<GarbledUint<32>> + From<GarbledUint<32>>
-                + Into<GarbledUint<64>> + From<GarbledUint<64>>
-                + Into<GarbledUint<128>> + From<GarbledUint<128>>
-                + Clone,
+        #signature
         {
             fn generate<const N: usize, #type_name>(#inputs) -> #output_type
             where
-                #type_name: Into<GarbledUint<N>> + From<GarbledUint<N>> + Clone,
+                #type_name: Into<#garbled<N>> + From<#garbled<N>> + Clone,
             {
                 let mut context = WRK17CircuitBuilder::default();
                 #(#mapped_inputs)*
                 #(#constants)*
-                let const_true = &context.input::<N>(&true.into());
-                let const_false = &context.input::<N>(&false.into());
+                let const_true = &context.constant::<N>(&true.into());
+                let const_false = &context.constant::<N>(&false.into());
 
                 // Use the transformed function block (with context.add and if/else replacements)
                 let output = { #transformed_block };
@@ -143,22 +294,291 @@ fn generate_macro(item: TokenStream, mode: &str) -> TokenStream {
         }
     };
 
+    // Compile mode also describes the circuit interface for generic runners
+    let expanded = if mode == "compile" {
+        let metadata_fn = format_ident!("{}_metadata", fn_name);
+        let declared_output = match &input_fn.sig.output {
+            syn::ReturnType::Type(_, ty) => quote! {#ty},
+            syn::ReturnType::Default => quote! {()},
+        };
+        let metadata =
+            circuit_metadata(&fn_name.to_string(), &param_names, inputs, &declared_output);
+        quote! {
+            #expanded
+
+            #[allow(dead_code)]
+            fn #metadata_fn() -> &'static str {
+                #metadata
+            }
+        }
+    } else {
+        expanded
+    };
+
     // Print the expanded code to stderr
     // println!("Generated code:\n{}", expanded);
 
     TokenStream::from(expanded)
 }
 
+/// Implements `GarbledEncode` for a struct with named fields of primitive integer or `bool`
+/// type, and declares a `<Name>Wires` struct holding the wires of each field.
+///
+/// `#[encrypted]` functions accept such structs as parameters and read their fields as wires;
+/// fields used in arithmetic must have the width of the function's primitive parameters.
+#[proc_macro_derive(GarbledEncode)]
+pub fn garbled_encode(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as syn::DeriveInput);
+    match derive_garbled_encode(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(error) => TokenStream::from(error.to_compile_error()),
+    }
+}
+
+fn derive_garbled_encode(input: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let wires_name = format_ident!("{}Wires", name);
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`GarbledEncode` does not support generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`GarbledEncode` can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut wire_fields = Vec::new();
+    let mut encode = Vec::new();
+    let mut decode = Vec::new();
+    let mut split = Vec::new();
+    let mut flatten = Vec::new();
+    let mut offset = 0;
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let Some((width, signed)) = primitive_type_width(ty) else {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`GarbledEncode` only supports fields of primitive integer or `bool` type",
+            ));
+        };
+
+        // fields are laid out in declaration order, each least-significant bit first
+        let garbled = if signed {
+            quote! { ::compute::int::GarbledInt::<#width> }
+        } else {
+            quote! { ::compute::uint::GarbledUint::<#width> }
+        };
+        let end = offset + width;
+        wire_fields.push(quote! { #field_vis #ident: ::compute::prelude::GateIndexVec });
+        encode.push(quote! { bits.extend(#garbled::from(self.#ident).to_bits_le()); });
+        decode.push(quote! { #ident: #garbled::new(bits[#offset..#end].to_vec()).into() });
+        split.push(quote! {
+            #ident: (#offset..#end).map(|i| indices[i]).collect::<::std::vec::Vec<_>>().into()
+        });
+        flatten.push(quote! { indices.push_all(&wires.#ident); });
+        offset = end;
+    }
+    let width = offset;
+
+    Ok(quote! {
+        #[derive(Debug, Clone)]
+        #vis struct #wires_name {
+            #(#wire_fields),*
+        }
+
+        impl ::compute::prelude::GarbledEncode for #name {
+            type Wires = #wires_name;
+
+            const WIDTH: usize = #width;
+
+            fn encode(&self) -> ::std::vec::Vec<bool> {
+                let mut bits = ::std::vec::Vec::with_capacity(#width);
+                #(#encode)*
+                bits
+            }
+
+            fn decode(bits: &[bool]) -> Self {
+                assert_eq!(
+                    bits.len(),
+                    #width,
+                    "{} needs exactly {} bits, got {}",
+                    stringify!(#name),
+                    #width,
+                    bits.len()
+                );
+                #name {
+                    #(#decode),*
+                }
+            }
+
+            fn wires(indices: &::compute::prelude::GateIndexVec) -> Self::Wires {
+                assert_eq!(
+                    indices.len(),
+                    #width,
+                    "{} needs exactly {} wires, got {}",
+                    stringify!(#name),
+                    #width,
+                    indices.len()
+                );
+                #wires_name {
+                    #(#split),*
+                }
+            }
+
+            fn indices(wires: &Self::Wires) -> ::compute::prelude::GateIndexVec {
+                let mut indices = ::compute::prelude::GateIndexVec::with_capacity(#width);
+                #(#flatten)*
+                indices
+            }
+        }
+    })
+}
+
+/// Returns the bit width and signedness of a primitive parameter or return type.
+fn primitive_width(ty: &str) -> Option<(usize, bool)> {
+    match ty {
+        "bool" => Some((1, false)),
+        "u8" => Some((8, false)),
+        "u16" => Some((16, false)),
+        "u32" => Some((32, false)),
+        "u64" => Some((64, false)),
+        "u128" => Some((128, false)),
+        "i8" => Some((8, true)),
+        "i16" => Some((16, true)),
+        "i32" => Some((32, true)),
+        "i64" => Some((64, true)),
+        "i128" => Some((128, true)),
+        _ => None,
+    }
+}
+
+/// Like [`primitive_width`] for a field type, which may be spelled as a path such as
+/// `core::primitive::u32`.
+fn primitive_type_width(ty: &syn::Type) -> Option<(usize, bool)> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let segment = path.path.segments.last()?;
+    if !segment.arguments.is_none() {
+        return None;
+    }
+    primitive_width(&segment.ident.to_string())
+}
+
+/// Returns the width of a signed primitive return type that differs from the parameter type.
+/// A return type equal to the parameter type already has the circuit's width.
+fn signed_return_width(
+    output_type: &proc_macro2::TokenStream,
+    type_name: &proc_macro2::TokenStream,
+) -> Option<usize> {
+    let output_type = output_type.to_string();
+    if output_type == type_name.to_string() {
+        return None;
+    }
+    match primitive_width(&output_type) {
+        Some((width, true)) => Some(width),
+        _ => None,
+    }
+}
+
+/// Describes the compiled circuit as JSON: the inputs in the order their bits lead the input
+/// vector returned by compile mode (constants follow them), all contributed by the garbler, and
+/// the output. The output width is `null` when the declared return type is not a primitive,
+/// e.g. `(Circuit, Vec<bool>)`.
+fn circuit_metadata(
+    fn_name: &str,
+    param_names: &[syn::Ident],
+    inputs: &syn::punctuated::Punctuated<FnArg, syn::token::Comma>,
+    output_type: &proc_macro2::TokenStream,
+) -> String {
+    let describe = |ty: &str| match primitive_width(ty) {
+        Some((width, signed)) => format!("\"width\":{},\"signed\":{}", width, signed),
+        None => "\"width\":null,\"signed\":null".to_string(),
+    };
+
+    let params: Vec<String> = param_names
+        .iter()
+        .zip(inputs.iter())
+        .map(|(name, input)| {
+            let ty = match input {
+                FnArg::Typed(PatType { ty, .. }) => quote! {#ty}.to_string(),
+                _ => panic!("Expected typed argument"),
+            };
+            format!(
+                "{{\"name\":\"{}\",{},\"party\":\"contributor\"}}",
+                name,
+                describe(&ty)
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"name\":\"{}\",\"inputs\":[{}],\"output\":{{{}}}}}",
+        fn_name,
+        params.join(","),
+        describe(&output_type.to_string())
+    )
+}
+
+/// State shared while lowering a function body into circuit operations.
+#[derive(Default)]
+struct LoweringState {
+    /// Constant inputs to declare at the top of the generated function.
+    constants: Vec<proc_macro2::TokenStream>,
+    /// Whether the parameters are signed, selecting two's complement comparisons.
+    signed: bool,
+    /// Parameters of a `GarbledEncode` struct type, whose fields are read as wires.
+    struct_params: HashSet<String>,
+    /// Public `while` loop counters, which stay plain integers instead of circuit wires.
+    counters: HashSet<String>,
+    /// Secret `let mut` bindings, which are cloned on read so they can be reassigned.
+    mutables: HashSet<String>,
+    /// Whether `+`, `-` and `*` record their overflow bits.
+    overflow_check: bool,
+    /// Unsupported constructs found in the body, reported as compile errors at their spans.
+    errors: Vec<syn::Error>,
+}
+
 /// Traverse and transform the function body, replacing binary operators and if/else expressions.
 /// Also collects constants to add to the circuit context.
-fn modify_body(block: syn::Block, constants: &mut Vec<proc_macro2::TokenStream>) -> syn::Block {
+fn modify_body(block: syn::Block, state: &mut LoweringState) -> syn::Block {
+    collect_loop_counters(&block, state);
+
     let stmts = block
         .stmts
         .into_iter()
         .map(|stmt| {
             match stmt {
+                // `acc += x;` as a statement updates the binding, like `acc = acc + x;`
+                syn::Stmt::Expr(expr, Some(semi)) if !is_counter_expr(&expr, state) => {
+                    let expr = desugar_compound_assignment(expr);
+                    syn::Stmt::Expr(replace_expressions(expr, state), Some(semi))
+                }
                 syn::Stmt::Expr(expr, semi_opt) => {
-                    syn::Stmt::Expr(replace_expressions(expr, constants), semi_opt)
+                    syn::Stmt::Expr(replace_expressions(expr, state), semi_opt)
+                }
+                // loop counters are public and stay plain integers
+                syn::Stmt::Local(local)
+                    if local_name(&local).is_some_and(|name| state.counters.contains(&name)) =>
+                {
+                    syn::Stmt::Local(local)
                 }
                 syn::Stmt::Local(mut local) => {
                     if let Some(local_init) = &mut local.init {
@@ -166,17 +586,18 @@ fn modify_body(block: syn::Block, constants: &mut Vec<proc_macro2::TokenStream>)
                         //local_init.expr =
                         //    Box::new(replace_expressions(*local_init.expr.clone(), constants));
 
-                        let local_expr = replace_expressions(*local_init.expr.clone(), constants);
+                        let local_expr = replace_expressions(*local_init.expr.clone(), state);
 
                         if let syn::Pat::Ident(ref pat_ident) = local.pat {
                             if pat_ident.mutability.is_some() {
-                                local_init.expr = Box::new(syn::parse_quote! {
+                                state.mutables.insert(pat_ident.ident.to_string());
+                                local_init.expr = syn::parse_quote! {
                                     #local_expr.clone()
-                                });
+                                };
                             } else {
-                                local_init.expr = Box::new(syn::parse_quote! {
+                                local_init.expr = syn::parse_quote! {
                                     #local_expr
-                                });
+                                };
                             }
                         }
                     }
@@ -194,18 +615,255 @@ fn modify_body(block: syn::Block, constants: &mut Vec<proc_macro2::TokenStream>)
     }
 }
 
+/// Rewrites a compound assignment such as `acc += x` into `acc = acc + x`; other
+/// expressions are returned unchanged.
+fn desugar_compound_assignment(expr: Expr) -> Expr {
+    let Expr::Binary(ExprBinary {
+        attrs,
+        left,
+        op,
+        right,
+    }) = expr
+    else {
+        return expr;
+    };
+
+    let op = match op {
+        BinOp::AddAssign(_) => BinOp::Add(Default::default()),
+        BinOp::SubAssign(_) => BinOp::Sub(Default::default()),
+        BinOp::MulAssign(_) => BinOp::Mul(Default::default()),
+        BinOp::DivAssign(_) => BinOp::Div(Default::default()),
+        BinOp::RemAssign(_) => BinOp::Rem(Default::default()),
+        BinOp::BitAndAssign(_) => BinOp::BitAnd(Default::default()),
+        BinOp::BitOrAssign(_) => BinOp::BitOr(Default::default()),
+        BinOp::BitXorAssign(_) => BinOp::BitXor(Default::default()),
+        op => {
+            return Expr::Binary(ExprBinary {
+                attrs,
+                left,
+                op,
+                right,
+            })
+        }
+    };
+
+    Expr::Assign(ExprAssign {
+        attrs,
+        left: left.clone(),
+        eq_token: Default::default(),
+        right: Box::new(Expr::Binary(ExprBinary {
+            attrs: Vec::new(),
+            left,
+            op,
+            right,
+        })),
+    })
+}
+
+/// Returns the name bound by a `let` statement with a plain identifier pattern.
+fn local_name(local: &syn::Local) -> Option<String> {
+    match &local.pat {
+        Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the counter of a `while` condition of the form `counter <op> <integer literal>`.
+fn loop_counter(cond: &Expr) -> Option<String> {
+    match cond {
+        Expr::Binary(ExprBinary {
+            left,
+            right,
+            op: BinOp::Lt(_) | BinOp::Le(_) | BinOp::Gt(_) | BinOp::Ge(_) | BinOp::Ne(_),
+            ..
+        }) => match (&**left, &**right) {
+            (
+                Expr::Path(path),
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Int(_), ..
+                }),
+            ) => path.path.get_ident().map(|ident| ident.to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Registers the counters of `while` loops in this block. A counter must be declared in the
+/// same block as `let mut counter = <integer literal>`, which keeps the trip count public.
+fn collect_loop_counters(block: &syn::Block, state: &mut LoweringState) {
+    let public_locals: HashSet<String> = block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            syn::Stmt::Local(local) => match (&local.pat, &local.init) {
+                (Pat::Ident(pat_ident), Some(init))
+                    if pat_ident.mutability.is_some()
+                        && matches!(
+                            &*init.expr,
+                            Expr::Lit(syn::ExprLit {
+                                lit: Lit::Int(_),
+                                ..
+                            })
+                        ) =>
+                {
+                    Some(pat_ident.ident.to_string())
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    for stmt in &block.stmts {
+        if let syn::Stmt::Expr(Expr::While(ExprWhile { cond, .. }), _) = stmt {
+            if let Some(counter) = loop_counter(cond).filter(|name| public_locals.contains(name)) {
+                state.counters.insert(counter);
+            }
+        }
+    }
+}
+
+/// Reports every `**` in the body. Rust parses `a ** b` as `a * *b`, so the two stars are told
+/// apart from a multiplication by a dereference by the first one being joined to the second.
+fn find_pow_operators(tokens: proc_macro2::TokenStream, errors: &mut Vec<syn::Error>) {
+    let mut previous: Option<proc_macro2::Punct> = None;
+    for token in tokens {
+        match &token {
+            proc_macro2::TokenTree::Group(group) => find_pow_operators(group.stream(), errors),
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '*' => {
+                if let Some(star) = previous
+                    .take()
+                    .filter(|star| star.spacing() == proc_macro2::Spacing::Joint)
+                {
+                    errors.push(syn::Error::new_spanned(
+                        quote! {#star #punct},
+                        "operator `**` is not supported in #[encrypted] functions",
+                    ));
+                    continue;
+                }
+                previous = Some(punct.clone());
+                continue;
+            }
+            _ => {}
+        }
+        previous = None;
+    }
+}
+
+/// Names an operator that has no circuit lowering, e.g. `<<`.
+fn unsupported_operator(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::Binary(ExprBinary { op, .. }) => match op {
+            BinOp::Shl(_) => Some("<<"),
+            BinOp::Shr(_) => Some(">>"),
+            BinOp::ShlAssign(_) => Some("<<="),
+            BinOp::ShrAssign(_) => Some(">>="),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the expression updates or compares a loop counter, e.g. `i += 1` or `i < 4`.
+fn is_counter_expr(expr: &Expr, state: &LoweringState) -> bool {
+    let target = match expr {
+        Expr::Binary(ExprBinary { left, .. }) => left,
+        Expr::Assign(ExprAssign { left, .. }) => left,
+        _ => return false,
+    };
+    match &**target {
+        Expr::Path(path) => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| state.counters.contains(&ident.to_string())),
+        _ => false,
+    }
+}
+
+/// Returns the context method lowering an operation whose circuit depends on signedness, such
+/// as a comparison or a division, using the two's complement gadget when the circuit parameters
+/// are signed.
+fn signed_method(op: &str, signed: bool) -> syn::Ident {
+    if signed {
+        format_ident!("signed_{}", op)
+    } else {
+        format_ident!("{}", op)
+    }
+}
+
+/// Lowers an arithmetic operation that also returns an overflow bit, recording the bit with
+/// the context.
+fn checked_arithmetic(method: &str, left_expr: Expr, right_expr: Expr) -> Expr {
+    let method = format_ident!("{}", method);
+    syn::parse_quote! {{
+        let left = #left_expr;
+        let right = #right_expr;
+        let (result, overflow) = context.#method(&left.into(), &right.into());
+        context.flag_overflow(overflow);
+        result
+    }}
+}
+
+/// Returns the context method and argument count lowering a whitelisted method call such as
+/// `a.max(b)`, or `None` for methods that are left as plain Rust.
+fn gadget_method(method: &str, signed: bool) -> Option<(syn::Ident, usize)> {
+    match method {
+        "eq" | "ne" => Some((format_ident!("{}", method), 1)),
+        "lt" | "le" | "gt" | "ge" | "min" | "max" => Some((signed_method(method, signed), 1)),
+        "clamp" => Some((signed_method(method, signed), 2)),
+        "abs" => Some((format_ident!("abs"), 0)),
+        _ => None,
+    }
+}
+
 /// Replaces binary operators and if/else expressions with appropriate context calls.
-fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>) -> Expr {
+fn replace_expressions(expr: Expr, state: &mut LoweringState) -> Expr {
+    // loop counter arithmetic is public and is left as plain Rust
+    if is_counter_expr(&expr, state) {
+        return expr;
+    }
+
+    if let Some(operator) = unsupported_operator(&expr) {
+        state.errors.push(syn::Error::new_spanned(
+            &expr,
+            format!(
+                "operator `{}` is not supported in #[encrypted] functions",
+                operator
+            ),
+        ));
+        return expr;
+    }
+
     match expr {
+        // while loops with a public counter and constant bound are unrolled into the circuit
+        Expr::While(expr_while) => {
+            let is_public =
+                loop_counter(&expr_while.cond).is_some_and(|name| state.counters.contains(&name));
+            if !is_public {
+                state.errors.push(syn::Error::new_spanned(
+                    &expr_while.cond,
+                    "while loop condition must compare a counter declared with `let mut` against a constant bound; loops over secret values are not supported",
+                ));
+                return Expr::While(expr_while);
+            }
+
+            let ExprWhile { cond, body, .. } = expr_while;
+            let transformed_body = modify_body(body, state);
+            syn::parse_quote! {
+                while #cond #transformed_body
+            }
+        }
         // if there is a block, recursively call modify_body
         Expr::Block(ExprBlock { block, .. }) => {
-            let transformed_block = modify_body(block, constants);
+            let transformed_block = modify_body(block, state);
             syn::parse_quote! { #transformed_block }
         }
         // implement assignment
+        // the target is a `let mut` binding and is left as is
         Expr::Assign(ExprAssign { left, right, .. }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = *left;
+            let right_expr = replace_expressions(*right, state);
 
             match right_expr {
                 Expr::Reference(ExprReference { .. }) => {
@@ -221,12 +879,34 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             }
         }
         // return statement
-        Expr::Return(_) => {
-            panic!("Return statement not allowed in circuit macro");
+        Expr::Return(expr_return) => {
+            state.errors.push(syn::Error::new_spanned(
+                &expr_return,
+                "`return` is not supported in #[encrypted] functions; end the body with the result instead",
+            ));
+            Expr::Return(expr_return)
+        }
+        // reading a mutable binding clones it so that it is not moved before being reassigned
+        Expr::Path(path)
+            if path
+                .path
+                .get_ident()
+                .is_some_and(|ident| state.mutables.contains(&ident.to_string())) =>
+        {
+            syn::parse_quote! { #path.clone() }
+        }
+        // a field of a struct parameter is borrowed from its wires, like a primitive parameter
+        Expr::Field(field)
+            if matches!(&*field.base, Expr::Path(path) if path
+                .path
+                .get_ident()
+                .is_some_and(|ident| state.struct_params.contains(&ident.to_string()))) =>
+        {
+            syn::parse_quote! { &#field }
         }
         // parentheses to ensure proper order of operations
         Expr::Paren(expr_paren) => {
-            let inner_expr = replace_expressions(*expr_paren.expr, constants);
+            let inner_expr = replace_expressions(*expr_paren.expr, state);
             syn::parse_quote! { (#inner_expr) }
         }
         // boolean literal
@@ -238,12 +918,12 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             let const_var = format_ident!("const_{}", value as u128);
 
             if value {
-                constants.push(quote! {
-                    let #const_var = &context.input::<N>(&1_u128.into()).clone();
+                state.constants.push(quote! {
+                    let #const_var = &context.constant::<N>(&1_u128.into());
                 });
             } else {
-                constants.push(quote! {
-                    let #const_var = &context.input::<N>(&0_u128.into()).clone();
+                state.constants.push(quote! {
+                    let #const_var = &context.constant::<N>(&0_u128.into());
                 });
             }
             syn::parse_quote! {#const_var}
@@ -257,8 +937,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                 .base10_parse::<u128>()
                 .expect("Expected an integer literal");
             let const_var = format_ident!("const_{}", value);
-            constants.push(quote! {
-                let #const_var = &context.input::<N>(&#value.into()).clone();
+            state.constants.push(quote! {
+                let #const_var = &context.constant::<N>(&#value.into());
             });
             syn::parse_quote! {#const_var}
         }
@@ -269,8 +949,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Eq(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -284,8 +964,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Ne(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -299,12 +979,13 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Gt(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            let method = signed_method("gt", state.signed);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
-                context.gt(&left.into(), &right.into())
+                context.#method(&left.into(), &right.into())
             }}
         }
         // greater than or equal
@@ -314,12 +995,13 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Ge(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            let method = signed_method("ge", state.signed);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
-                context.ge(&left.into(), &right.into())
+                context.#method(&left.into(), &right.into())
             }}
         }
         // less than
@@ -329,12 +1011,13 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Lt(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            let method = signed_method("lt", state.signed);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
-                context.lt(&left.into(), &right.into())
+                context.#method(&left.into(), &right.into())
             }}
         }
         // less than or equal
@@ -344,12 +1027,13 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Le(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            let method = signed_method("le", state.signed);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
-                context.le(&left.into(), &right.into())
+                context.#method(&left.into(), &right.into())
             }}
         }
         // addition
@@ -359,8 +1043,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Add(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            if state.overflow_check {
+                return checked_arithmetic("add_with_carry", left_expr, right_expr);
+            }
             syn::parse_quote! {{
                 let left = &#left_expr;
                 let right = &#right_expr;
@@ -374,6 +1061,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::AddAssign(_),
             ..
         }) => {
+            if state.overflow_check {
+                let left_expr = replace_expressions(*left, state);
+                let right_expr = replace_expressions(*right, state);
+                return checked_arithmetic("add_with_carry", left_expr, right_expr);
+            }
             syn::parse_quote! {
                 context.add(&#left, &#right)
             }
@@ -385,8 +1077,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Sub(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            if state.overflow_check {
+                return checked_arithmetic("sub_with_borrow", left_expr, right_expr);
+            }
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -400,6 +1095,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::SubAssign(_),
             ..
         }) => {
+            if state.overflow_check {
+                let left_expr = replace_expressions(*left, state);
+                let right_expr = replace_expressions(*right, state);
+                return checked_arithmetic("sub_with_borrow", left_expr, right_expr);
+            }
             syn::parse_quote! {
                 context.sub(&#left, &#right)
             }
@@ -411,8 +1111,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Mul(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            if state.overflow_check {
+                return checked_arithmetic("mul_with_overflow", left_expr, right_expr);
+            }
             syn::parse_quote! {{
                 let left = &#left_expr;
                 let right = &#right_expr;
@@ -426,6 +1129,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::MulAssign(_),
             ..
         }) => {
+            if state.overflow_check {
+                let left_expr = replace_expressions(*left, state);
+                let right_expr = replace_expressions(*right, state);
+                return checked_arithmetic("mul_with_overflow", left_expr, right_expr);
+            }
             syn::parse_quote! {
                 context.mul(&#left, &#right)
             }
@@ -437,12 +1145,13 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Div(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            let method = signed_method("div", state.signed);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
-                context.div(&left.into(), &right.into())
+                context.#method(&left.into(), &right.into())
             }}
         }
         // division assignment
@@ -452,8 +1161,9 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::DivAssign(_),
             ..
         }) => {
+            let method = signed_method("div", state.signed);
             syn::parse_quote! {
-                context.div(&#left, &#right)
+                context.#method(&#left, &#right)
             }
         }
         // modulo
@@ -463,12 +1173,13 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Rem(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
+            let method = signed_method("rem", state.signed);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
-                context.rem(&left.into(), &right.into())
+                context.#method(&left.into(), &right.into())
             }}
         }
         // modulo assignment
@@ -478,8 +1189,9 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::RemAssign(_),
             ..
         }) => {
+            let method = signed_method("rem", state.signed);
             syn::parse_quote! {
-                context.rem(&#left, &#right)
+                context.#method(&#left, &#right)
             }
         }
         // logical AND
@@ -489,8 +1201,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::And(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -505,8 +1217,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::Or(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -521,8 +1233,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::BitAnd(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -548,8 +1260,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::BitOr(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -575,8 +1287,8 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             op: BinOp::BitXor(_),
             ..
         }) => {
-            let left_expr = replace_expressions(*left, constants);
-            let right_expr = replace_expressions(*right, constants);
+            let left_expr = replace_expressions(*left, state);
+            let right_expr = replace_expressions(*right, state);
             syn::parse_quote! {{
                 let left = #left_expr;
                 let right = #right_expr;
@@ -595,13 +1307,89 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             }
         }
 
+        // whitelisted gadgets such as `a.max(b)` or `a.lt(&b)` lower to the builder method
+        Expr::MethodCall(call)
+            if gadget_method(&call.method.to_string(), state.signed).is_some() =>
+        {
+            let (context_method, arity) =
+                gadget_method(&call.method.to_string(), state.signed).unwrap();
+            if call.method == "abs" && !state.signed {
+                state.errors.push(syn::Error::new_spanned(
+                    &call,
+                    "`abs` requires signed circuit parameters",
+                ));
+                return Expr::MethodCall(call);
+            }
+            if call.args.len() != arity {
+                state.errors.push(syn::Error::new_spanned(
+                    &call,
+                    format!(
+                        "`{}` takes {} argument(s) inside a circuit, got {}",
+                        call.method,
+                        arity,
+                        call.args.len()
+                    ),
+                ));
+                return Expr::MethodCall(call);
+            }
+
+            let ExprMethodCall { receiver, args, .. } = call;
+            let receiver_expr = replace_expressions(*receiver, state);
+            // comparison methods take their argument by reference, e.g. `a.lt(&b)`
+            let arg_exprs: Vec<Expr> = args
+                .into_iter()
+                .map(|arg| match arg {
+                    Expr::Reference(ExprReference { expr, .. }) => *expr,
+                    other => other,
+                })
+                .map(|arg| replace_expressions(arg, state))
+                .collect();
+            let arg_names: Vec<syn::Ident> = (0..arg_exprs.len())
+                .map(|i| format_ident!("arg{}", i))
+                .collect();
+
+            syn::parse_quote! {{
+                let receiver = #receiver_expr;
+                #(let #arg_names = #arg_exprs;)*
+                context.#context_method(&receiver.into() #(, &#arg_names.into())*)
+            }}
+        }
+
+        // two's complement negation; a negated integer literal is folded into a constant
+        Expr::Unary(ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => {
+            if let Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(lit_int),
+                ..
+            }) = &*expr
+            {
+                let value = lit_int
+                    .base10_parse::<u128>()
+                    .expect("Expected an integer literal");
+                let const_var = format_ident!("const_neg_{}", value);
+                let bits = value.wrapping_neg();
+                state.constants.push(quote! {
+                    let #const_var = &context.constant::<N>(&#bits.into());
+                });
+                return syn::parse_quote! {#const_var};
+            }
+
+            let single_expr = replace_expressions(*expr, state);
+            syn::parse_quote! {{
+                let single = #single_expr;
+                context.neg(&single.into())
+            }}
+        }
         // bitwise NOT
         Expr::Unary(ExprUnary {
             op: syn::UnOp::Not(_),
             expr,
             ..
         }) => {
-            let single_expr = replace_expressions(*expr, constants);
+            let single_expr = replace_expressions(*expr, state);
             syn::parse_quote! {{
                 let single = #single_expr;
                 context.not(&single.into())
@@ -615,12 +1403,12 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             else_branch,
             ..
         }) => {
-            let cond_expr = replace_expressions(*cond, constants);
-            let then_block = modify_body(then_branch, constants);
+            let cond_expr = replace_expressions(*cond, state);
+            let then_block = modify_body(then_branch, state);
 
             // If there's an explicit else block, use it; otherwise, continue with remaining expressions
             let else_expr = if let Some((_, else_expr)) = else_branch {
-                replace_expressions(*else_expr, constants)
+                replace_expressions(*else_expr, state)
             } else {
                 // Placeholder for remaining function body as the fall-through `else` case
                 //syn::parse_quote! { context.input::<N>(&0u128.into()) }
@@ -642,6 +1430,10 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
             else_branch,
             ..
         }) => {
+            let ge = signed_method("ge", state.signed);
+            let le = signed_method("le", state.signed);
+            let lt = signed_method("lt", state.signed);
+
             // Check if `cond` is an `if let` with a range pattern
             let cond_expr = match *cond {
                 Expr::Let(ExprLet { pat, expr, .. }) => {
@@ -653,14 +1445,14 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                             limits: syn::RangeLimits::Closed(_),
                             ..
                         }) => {
-                            let start_expr = replace_expressions(*start.clone(), constants);
-                            let end_expr = replace_expressions(*end.clone(), constants);
-                            let input_expr = replace_expressions(*expr, constants);
+                            let start_expr = replace_expressions(*start.clone(), state);
+                            let end_expr = replace_expressions(*end.clone(), state);
+                            let input_expr = replace_expressions(*expr, state);
 
                             // Inclusive range with embedded `let` statements for `lhs` and `rhs`
                             syn::parse_quote! {{
-                                let lhs = &context.ge(&#input_expr.into(), &#start_expr.into()).into();
-                                let rhs = &context.le(&#input_expr.into(), &#end_expr.into()).into();
+                                let lhs = &context.#ge(&#input_expr.into(), &#start_expr.into()).into();
+                                let rhs = &context.#le(&#input_expr.into(), &#end_expr.into()).into();
                                 context.and(lhs, rhs)
                             }}
                         }
@@ -671,21 +1463,21 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                             limits: syn::RangeLimits::HalfOpen(_),
                             ..
                         }) => {
-                            let start_expr = replace_expressions(*start.clone(), constants);
-                            let end_expr = replace_expressions(*end.clone(), constants);
-                            let input_expr = replace_expressions(*expr, constants);
+                            let start_expr = replace_expressions(*start.clone(), state);
+                            let end_expr = replace_expressions(*end.clone(), state);
+                            let input_expr = replace_expressions(*expr, state);
 
                             // Exclusive range with embedded `let` statements for `lhs` and `rhs`
                             syn::parse_quote! {{
-                                let lhs = &context.ge(&#input_expr.into(), &#start_expr.into()).into();
-                                let rhs = &context.lt(&#input_expr.into(), &#end_expr.into()).into();
+                                let lhs = &context.#ge(&#input_expr.into(), &#start_expr.into()).into();
+                                let rhs = &context.#lt(&#input_expr.into(), &#end_expr.into()).into();
                                 context.and(lhs, rhs)
                             }}
                         }
                         // Handle single literal pattern, e.g., `if let 5 = n`
                         syn::Pat::Lit(lit) => {
-                            let lit_expr = replace_expressions(Expr::Lit(lit.clone()), constants);
-                            let input_expr = replace_expressions(*expr, constants);
+                            let lit_expr = replace_expressions(Expr::Lit(lit.clone()), state);
+                            let input_expr = replace_expressions(*expr, state);
 
                             syn::parse_quote! {
                                 context.eq(&#input_expr.into(), &#lit_expr.into())
@@ -697,15 +1489,15 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                     }
                 }
                 ref _other => {
-                    replace_expressions(*cond, constants) // Fallback for non-let conditions
+                    replace_expressions(*cond, state) // Fallback for non-let conditions
                 }
             };
 
-            let then_block = modify_body(then_branch, constants);
+            let then_block = modify_body(then_branch, state);
 
             // Check if an `else` branch exists, as it's required.
             let else_expr = if let Some((_, else_expr)) = else_branch {
-                replace_expressions(*else_expr, constants)
+                replace_expressions(*else_expr, state)
             } else {
                 panic!("else branch is required for range if let");
             };
@@ -721,7 +1513,10 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
 
         // Support match arms with mux and other operations
         Expr::Match(ExprMatch { expr, arms, .. }) => {
-            let match_expr = replace_expressions(*expr, constants);
+            let match_expr = replace_expressions(*expr, state);
+            let ge = signed_method("ge", state.signed);
+            let le = signed_method("le", state.signed);
+            let lt = signed_method("lt", state.signed);
 
             // Define an input variable to use in range proof processing
             let input = syn::Ident::new("input", proc_macro2::Span::call_site());
@@ -733,7 +1528,7 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                 .rev()
                 .fold(None as Option<Expr>, |acc, arm| {
                     let pat = arm.pat;
-                    let body_expr = replace_expressions(*arm.body, constants);
+                    let body_expr = replace_expressions(*arm.body, state);
 
                     // Create conditional expression for each arm, handling ranges
                     let cond_expr = match &pat {
@@ -744,11 +1539,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                             limits: syn::RangeLimits::Closed(_),
                             ..
                         }) => {
-                            let start = replace_expressions(*start.clone(), constants);
-                            let end = replace_expressions(*end.clone(), constants);
+                            let start = replace_expressions(*start.clone(), state);
+                            let end = replace_expressions(*end.clone(), state);
                             quote! {
-                                let lhs = &context.ge(&#input.into(), &#start.into()).into();
-                                let rhs = &context.le(&#input.into(), &#end.into()).into();
+                                let lhs = &context.#ge(&#input.into(), &#start.into()).into();
+                                let rhs = &context.#le(&#input.into(), &#end.into()).into();
                                 context.and(
                                     lhs,
                                     rhs
@@ -762,11 +1557,11 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                             limits: syn::RangeLimits::HalfOpen(_),
                             ..
                         }) => {
-                            let start = replace_expressions(*start.clone(), constants);
-                            let end = replace_expressions(*end.clone(), constants);
+                            let start = replace_expressions(*start.clone(), state);
+                            let end = replace_expressions(*end.clone(), state);
                             quote! {
-                                let lhs = &context.ge(&#input.into(), &#start.into()).into();
-                                let rhs = &context.lt(&#input.into(), &#end.into()).into();
+                                let lhs = &context.#ge(&#input.into(), &#start.into()).into();
+                                let rhs = &context.#lt(&#input.into(), &#end.into()).into();
                                 context.and(
                                     lhs,
                                     rhs
@@ -775,8 +1570,7 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                         }
                         // Handle single value pattern (e.g., `5`)
                         syn::Pat::Lit(lit) => {
-                            let lit_expr =
-                                replace_expressions(syn::Expr::Lit(lit.clone()), constants);
+                            let lit_expr = replace_expressions(syn::Expr::Lit(lit.clone()), state);
                             quote! {
                                 context.eq(&#input.into(), &#lit_expr.into())
                             }
@@ -786,7 +1580,7 @@ fn replace_expressions(expr: Expr, constants: &mut Vec<proc_macro2::TokenStream>
                             // Create conditional expression for each arm
                             let cond_expr = replace_expressions(
                                 syn::parse_quote! { #match_expr == #pat },
-                                constants,
+                                state,
                             );
 
                             syn::parse_quote! {{
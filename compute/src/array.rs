@@ -0,0 +1,61 @@
+//! Fixed-length arrays of garbled values.
+
+use core::ops::Index;
+
+/// A fixed-length array of `L` garbled values.
+///
+/// Indexing with a public `usize` reads the local value directly; use `get` with a garbled
+/// index to read an element without revealing which one was read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GarbledArray<T, const L: usize> {
+    elements: [T; L],
+}
+
+impl<T, const L: usize> GarbledArray<T, L> {
+    pub fn new(elements: [T; L]) -> Self {
+        Self { elements }
+    }
+
+    pub fn len(&self) -> usize {
+        L
+    }
+
+    pub fn is_empty(&self) -> bool {
+        L == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn into_inner(self) -> [T; L] {
+        self.elements
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.elements.iter()
+    }
+}
+
+impl<T, const L: usize> From<[T; L]> for GarbledArray<T, L> {
+    fn from(elements: [T; L]) -> Self {
+        Self::new(elements)
+    }
+}
+
+impl<T, const L: usize> Index<usize> for GarbledArray<T, L> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.elements[index]
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a GarbledArray<T, L> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
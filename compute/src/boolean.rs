@@ -0,0 +1,59 @@
+//! Garbled booleans.
+
+use crate::uint::GarbledBit;
+use core::fmt::Display;
+
+/// A single garbled bit used as a truth value.
+///
+/// Unlike [`GarbledBit`], which is a one-bit [`GarbledUint`](crate::uint::GarbledUint),
+/// the logical operators on this type run a circuit of a single gate (`|` needs three, one of
+/// them an AND), so predicates can be combined cheaply. Convert to [`GarbledBit`] to use
+/// the value as the condition of a mux.
+#[derive(Debug, Clone)]
+pub struct GarbledBool {
+    bit: GarbledBit,
+}
+
+impl GarbledBool {
+    /// The underlying one-bit integer.
+    pub fn bit(&self) -> &GarbledBit {
+        &self.bit
+    }
+
+    /// Consumes the value, returning the underlying one-bit integer.
+    pub fn into_bit(self) -> GarbledBit {
+        self.bit
+    }
+}
+
+impl Display for GarbledBool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&bool::from(self.clone()), f)
+    }
+}
+
+impl From<bool> for GarbledBool {
+    fn from(value: bool) -> Self {
+        Self {
+            bit: GarbledBit::from(value),
+        }
+    }
+}
+
+impl From<GarbledBool> for bool {
+    fn from(value: GarbledBool) -> Self {
+        value.bit.to_bits_le()[0]
+    }
+}
+
+impl From<GarbledBit> for GarbledBool {
+    fn from(bit: GarbledBit) -> Self {
+        Self { bit }
+    }
+}
+
+impl From<GarbledBool> for GarbledBit {
+    fn from(value: GarbledBool) -> Self {
+        value.bit
+    }
+}
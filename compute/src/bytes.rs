@@ -0,0 +1,102 @@
+//! Fixed-length byte buffers of garbled bytes.
+
+use crate::error::LengthError;
+use crate::uint::GarbledUint8;
+use alloc::vec::Vec;
+
+/// A buffer of exactly `N` garbled bytes, for keys, hashes and other byte-oriented data.
+#[derive(Debug, Clone)]
+pub struct GarbledBytes<const N: usize> {
+    bytes: Vec<GarbledUint8>,
+}
+
+impl<const N: usize> GarbledBytes<N> {
+    /// Wraps exactly `N` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` does not hold `N` bytes.
+    pub fn new(bytes: Vec<GarbledUint8>) -> Self {
+        assert_eq!(
+            bytes.len(),
+            N,
+            "GarbledBytes<{}> needs exactly {} bytes",
+            N,
+            N
+        );
+        Self { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[GarbledUint8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<GarbledUint8> {
+        self.bytes
+    }
+
+    /// Appends `other`, giving a buffer of `N + M` bytes; `K` must be that sum, which is
+    /// checked at compile time. Only the local bytes are rearranged, so this runs no circuit.
+    ///
+    /// ```compile_fail
+    /// # use compute::bytes::GarbledBytes;
+    /// let head = GarbledBytes::from([1_u8, 2]);
+    /// let joined: GarbledBytes<4> = head.concat(&GarbledBytes::from([3_u8]));
+    /// ```
+    pub fn concat<const M: usize, const K: usize>(
+        &self,
+        other: &GarbledBytes<M>,
+    ) -> GarbledBytes<K> {
+        const { assert!(K == N + M, "concatenating N and M bytes gives N + M, not K") };
+        let mut bytes = self.bytes.clone();
+        bytes.extend_from_slice(&other.bytes);
+        GarbledBytes { bytes }
+    }
+
+    /// The `K` bytes starting at the public offset `start`. Runs no circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit in `N` bytes.
+    pub fn slice<const K: usize>(&self, start: usize) -> GarbledBytes<K> {
+        assert!(
+            start.checked_add(K).is_some_and(|end| end <= N),
+            "{} bytes at offset {} do not fit in GarbledBytes<{}>",
+            K,
+            start,
+            N
+        );
+        GarbledBytes {
+            bytes: self.bytes[start..start + K].to_vec(),
+        }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for GarbledBytes<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self::new(bytes.iter().map(|&byte| GarbledUint8::from(byte)).collect())
+    }
+}
+
+// Slices must hold exactly N bytes, like `<[u8; N]>::try_from(slice)`
+impl<const N: usize> TryFrom<&[u8]> for GarbledBytes<N> {
+    type Error = LengthError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != N {
+            return Err(LengthError {
+                expected: N,
+                found: bytes.len(),
+            });
+        }
+        Ok(Self::new(
+            bytes.iter().map(|&byte| GarbledUint8::from(byte)).collect(),
+        ))
+    }
+}
+
+impl<const N: usize> From<GarbledBytes<N>> for Vec<u8> {
+    fn from(value: GarbledBytes<N>) -> Self {
+        value.bytes.into_iter().map(u8::from).collect()
+    }
+}
@@ -0,0 +1,69 @@
+//! Garbled ASCII characters.
+
+use crate::uint::GarbledUint8;
+use core::fmt::Display;
+
+/// An ASCII character stored as a [`GarbledUint8`].
+///
+/// The classification and case-mapping circuits in
+/// [`operations::char`](crate::operations::char) treat the byte as ASCII, so text-processing
+/// gadgets such as validating an ID string can be built one character at a time.
+#[derive(Debug, Clone)]
+pub struct GarbledChar {
+    byte: GarbledUint8,
+}
+
+impl GarbledChar {
+    /// Encodes an ASCII character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` is not ASCII.
+    pub fn new(c: char) -> Self {
+        assert!(c.is_ascii(), "GarbledChar only holds ASCII, got {:?}", c);
+        Self {
+            byte: GarbledUint8::from(c as u8),
+        }
+    }
+
+    /// The underlying byte.
+    pub fn byte(&self) -> &GarbledUint8 {
+        &self.byte
+    }
+
+    /// Consumes the character, returning the underlying byte.
+    pub fn into_byte(self) -> GarbledUint8 {
+        self.byte
+    }
+}
+
+impl Display for GarbledChar {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&char::from(self.clone()), f)
+    }
+}
+
+impl From<char> for GarbledChar {
+    fn from(c: char) -> Self {
+        Self::new(c)
+    }
+}
+
+impl From<GarbledUint8> for GarbledChar {
+    fn from(byte: GarbledUint8) -> Self {
+        Self { byte }
+    }
+}
+
+impl From<GarbledChar> for GarbledUint8 {
+    fn from(value: GarbledChar) -> Self {
+        value.byte
+    }
+}
+
+// Bytes above 0x7f, which only arise from `From<GarbledUint8>`, decode as Latin-1
+impl From<GarbledChar> for char {
+    fn from(value: GarbledChar) -> Self {
+        char::from(u8::from(value.byte))
+    }
+}
@@ -0,0 +1,124 @@
+use core::fmt::{Display, Formatter};
+
+/// Errors surfaced when building or executing a circuit.
+///
+/// The `try_*` methods return this error instead of panicking, while the `std::ops` operator
+/// impls unwrap it for ergonomics. It implements `std::error::Error`, so `?` converts it into an
+/// `anyhow::Error` in callers that used to receive one from [`WRK17CircuitBuilder::execute`].
+///
+/// [`WRK17CircuitBuilder::execute`]: crate::operations::circuits::builder::WRK17CircuitBuilder::execute
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum CircuitError {
+    /// The circuit was rejected or failed during evaluation by `tandem`, e.g. because a gate
+    /// references a wire that does not exist.
+    Tandem(tandem::Error),
+    /// Any other failure reported by the executor.
+    Executor(anyhow::Error),
+}
+
+#[cfg(feature = "std")]
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CircuitError::Tandem(error) => write!(f, "circuit evaluation failed: {}", error),
+            CircuitError::Executor(error) => write!(f, "circuit execution failed: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CircuitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CircuitError::Tandem(error) => Some(error),
+            CircuitError::Executor(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<tandem::Error> for CircuitError {
+    fn from(error: tandem::Error) -> Self {
+        CircuitError::Tandem(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<anyhow::Error> for CircuitError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<tandem::Error>() {
+            Ok(error) => CircuitError::Tandem(error),
+            Err(error) => CircuitError::Executor(error),
+        }
+    }
+}
+
+/// A value does not fit the type it is converted to, returned by the fallible conversions such
+/// as [`GarbledUint::try_from_u16`](crate::uint::GarbledUint::try_from_u16) and
+/// [`GarbledUint::try_into_u8`](crate::uint::GarbledUint::try_into_u8).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionError {
+    /// The number of bits the value needs.
+    pub required: usize,
+    /// The number of bits the target type holds.
+    pub available: usize,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "value needs {} bits but the target only holds {}",
+            self.required, self.available
+        )
+    }
+}
+
+impl core::error::Error for ConversionError {}
+
+/// A byte slice does not hold the number of bytes the target type needs, returned by
+/// `GarbledBytes::try_from(&[u8])` and the `from_le_bytes`/`from_be_bytes` constructors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthError {
+    /// The number of bytes the target type holds.
+    pub expected: usize,
+    /// The number of bytes in the slice.
+    pub found: usize,
+}
+
+impl Display for LengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected {} bytes but the slice holds {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for LengthError {}
+
+/// A string is not a valid integer literal, returned by the `FromStr` impls and the
+/// `from_hex`/`from_bin` helpers of the garbled integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string holds no digits.
+    Empty,
+    /// A character is not a digit of the radix or a `_` separator.
+    InvalidDigit,
+    /// The value does not fit the target type.
+    Overflow,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseError::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseError::Overflow => write!(f, "number too large to fit in target type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
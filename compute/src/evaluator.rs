@@ -1,10 +1,12 @@
 use anyhow::Result;
-use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use std::fmt::Debug;
+use std::sync::Arc;
 use tandem::states::Evaluator as TandemEvaluator;
 use tandem::Circuit;
 
+use crate::executor::entropy_rng;
+
 pub trait Evaluator {
     fn new(circuit: &Circuit, input: &[bool]) -> Result<Self>
     where
@@ -18,20 +20,37 @@ pub trait Evaluator {
 }
 
 pub struct GatewayEvaluator {
-    evaluator: TandemEvaluator<Circuit, Vec<bool>>,
+    evaluator: TandemEvaluator<Arc<Circuit>, Vec<bool>>,
     steps_remaining: u32,
 }
 
-impl Evaluator for GatewayEvaluator {
-    fn new(circuit: &Circuit, input: &[bool]) -> Result<Self> {
-        let evaluator =
-            TandemEvaluator::new(circuit.clone(), input.to_vec(), ChaCha20Rng::from_entropy())?;
+impl GatewayEvaluator {
+    /// Creates the evaluator with the given randomness instead of the system entropy source,
+    /// which is unavailable on the WASM execution path.
+    pub fn new_with_rng(circuit: &Circuit, input: &[bool], rng: ChaCha20Rng) -> Result<Self> {
+        GatewayEvaluator::new_shared(Arc::new(circuit.clone()), input, rng)
+    }
+
+    /// Creates the evaluator on a shared circuit, so that repeated executions of a prepared
+    /// circuit do not copy its gates.
+    pub(crate) fn new_shared(
+        circuit: Arc<Circuit>,
+        input: &[bool],
+        rng: ChaCha20Rng,
+    ) -> Result<Self> {
+        let evaluator = TandemEvaluator::new(circuit, input.to_vec(), rng)?;
         let steps_remaining = evaluator.steps();
         Ok(GatewayEvaluator {
             evaluator,
             steps_remaining,
         })
     }
+}
+
+impl Evaluator for GatewayEvaluator {
+    fn new(circuit: &Circuit, input: &[bool]) -> Result<Self> {
+        GatewayEvaluator::new_with_rng(circuit, input, entropy_rng()?)
+    }
 
     fn next(self, message: &[u8]) -> Result<(Self, Vec<u8>)> {
         let (next_state, response) = self.evaluator.run(message)?;
@@ -1,14 +1,16 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use once_cell::sync::Lazy;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 use std::sync::Arc;
-use tandem::Circuit;
+use tandem::{Circuit, Gate};
 
 use crate::evaluator::{Evaluator, GatewayEvaluator};
 use crate::garbler::{Garbler, GatewayGarbler};
 
 /// A static Lazy instance for holding the singleton LocalSimulator.
 static SINGLETON_EXECUTOR: Lazy<Arc<dyn Executor + Send + Sync>> =
-    Lazy::new(|| Arc::new(LocalSimulator) as Arc<dyn Executor + Send + Sync>);
+    Lazy::new(|| Arc::new(LocalSimulator::new()) as Arc<dyn Executor + Send + Sync>);
 
 /// Provides access to the singleton Executor instance.
 pub fn get_executor() -> Arc<dyn Executor + Send + Sync> {
@@ -32,6 +34,17 @@ pub trait Executor {
         input_evaluator: &[bool],
     ) -> Result<Vec<bool>>;
 
+    /// Executes the shared circuit of a [`PreparedCircuit`]. Executors that can avoid copying
+    /// it override this; the default runs [`Executor::execute`].
+    fn execute_shared(
+        &self,
+        circuit: &Arc<Circuit>,
+        input_contributor: &[bool],
+        input_evaluator: &[bool],
+    ) -> Result<Vec<bool>> {
+        self.execute(circuit, input_contributor, input_evaluator)
+    }
+
     fn instance() -> &'static Arc<dyn Executor + Send + Sync>
     where
         Self: Sized,
@@ -40,7 +53,133 @@ pub trait Executor {
     }
 }
 
-pub struct LocalSimulator;
+impl dyn Executor + Send + Sync {
+    /// Prepares `circuit` for repeated execution, e.g. by a server evaluating the same
+    /// compiled circuit for many requests.
+    ///
+    /// The circuit is validated once and shared between executions instead of being copied
+    /// into each protocol run. Results are identical to calling [`Executor::execute`].
+    pub fn prepared(&self, circuit: &Circuit) -> Result<PreparedCircuit<'_>> {
+        circuit
+            .validate()
+            .map_err(|e| anyhow!("invalid circuit: {:?}", e))?;
+        let inputs = |kind: Gate| circuit.gates().iter().filter(|&&gate| gate == kind).count();
+
+        Ok(PreparedCircuit {
+            executor: self,
+            contributor_inputs: inputs(Gate::InContrib),
+            evaluator_inputs: inputs(Gate::InEval),
+            circuit: Arc::new(circuit.clone()),
+        })
+    }
+}
+
+/// A validated circuit held by an [`Executor`] for repeated execution, created with
+/// `get_executor().prepared(&circuit)`.
+pub struct PreparedCircuit<'a> {
+    executor: &'a (dyn Executor + Send + Sync),
+    circuit: Arc<Circuit>,
+    contributor_inputs: usize,
+    evaluator_inputs: usize,
+}
+
+impl PreparedCircuit<'_> {
+    pub fn circuit(&self) -> &Circuit {
+        &self.circuit
+    }
+
+    /// Executes the prepared circuit on one set of inputs.
+    ///
+    /// Inputs of the wrong length are rejected before the protocol starts.
+    pub fn execute_inputs(
+        &self,
+        input_contributor: &[bool],
+        input_evaluator: &[bool],
+    ) -> Result<Vec<bool>> {
+        if input_contributor.len() != self.contributor_inputs {
+            return Err(anyhow!(
+                "expected {} contributor input bits, got {}",
+                self.contributor_inputs,
+                input_contributor.len()
+            ));
+        }
+        if input_evaluator.len() != self.evaluator_inputs {
+            return Err(anyhow!(
+                "expected {} evaluator input bits, got {}",
+                self.evaluator_inputs,
+                input_evaluator.len()
+            ));
+        }
+        self.executor
+            .execute_shared(&self.circuit, input_contributor, input_evaluator)
+    }
+}
+
+/// Size limits checked before a circuit is executed.
+///
+/// A service executing circuits it did not build, e.g. compile-mode output submitted by users,
+/// can bound its memory and time use by rejecting oversized circuits up front instead of
+/// evaluating them. `None` means unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutorConfig {
+    /// Maximum number of AND gates, which dominate the cost of garbling.
+    pub max_and_gates: Option<usize>,
+    /// Maximum number of gates of any kind, including input gates.
+    pub max_total_gates: Option<usize>,
+}
+
+impl ExecutorConfig {
+    /// Returns an error naming the exceeded limit if `circuit` is over budget.
+    pub fn check(&self, circuit: &Circuit) -> Result<()> {
+        let gates = circuit.gates();
+        if let Some(max) = self.max_total_gates {
+            if gates.len() > max {
+                return Err(anyhow!(
+                    "circuit has {} gates, more than the limit of {}",
+                    gates.len(),
+                    max
+                ));
+            }
+        }
+        if let Some(max) = self.max_and_gates {
+            let and_gates = gates
+                .iter()
+                .filter(|gate| matches!(gate, Gate::And(_, _)))
+                .count();
+            if and_gates > max {
+                return Err(anyhow!(
+                    "circuit has {} AND gates, more than the limit of {}",
+                    and_gates,
+                    max
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs both parties of the protocol in one process. The shared executor from
+/// [`get_executor`] has no size limits; use [`LocalSimulator::with_config`] to set some.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalSimulator {
+    config: ExecutorConfig,
+}
+
+impl LocalSimulator {
+    /// Creates a simulator without size limits, like the shared executor.
+    pub fn new() -> Self {
+        LocalSimulator::default()
+    }
+
+    /// Creates a simulator that rejects circuits exceeding the limits of `config`.
+    pub fn with_config(config: ExecutorConfig) -> Self {
+        LocalSimulator { config }
+    }
+
+    pub fn config(&self) -> &ExecutorConfig {
+        &self.config
+    }
+}
 
 impl Executor for LocalSimulator {
     /// The Multi-Party Computation is performed using the full cryptographic protocol exposed by the
@@ -53,24 +192,86 @@ impl Executor for LocalSimulator {
         input_garbler: &[bool],
         input_evaluator: &[bool],
     ) -> Result<Vec<bool>> {
-        let (mut garbler, mut msg_for_evaluator) = GatewayGarbler::start(circuit, input_garbler)?;
+        // checked before copying the circuit, which may be the oversized part
+        self.config.check(circuit)?;
+        simulate(&Arc::new(circuit.clone()), input_garbler, input_evaluator)
+    }
+
+    fn execute_shared(
+        &self,
+        circuit: &Arc<Circuit>,
+        input_garbler: &[bool],
+        input_evaluator: &[bool],
+    ) -> Result<Vec<bool>> {
+        self.config.check(circuit)?;
+        simulate(circuit, input_garbler, input_evaluator)
+    }
+}
 
-        let mut evaluator = GatewayEvaluator::new(circuit, input_evaluator)?;
+// Protocol messages carry wire labels, so with the `zeroize` feature they are wiped on drop
+#[cfg(feature = "zeroize")]
+type Message = zeroize::Zeroizing<Vec<u8>>;
+#[cfg(not(feature = "zeroize"))]
+type Message = Vec<u8>;
 
-        assert_eq!(garbler.steps(), evaluator.steps());
-        let total_steps = garbler.steps();
+/// Runs both parties of the protocol on local message queues.
+// `Message::from` is the identity without the `zeroize` feature
+#[allow(clippy::useless_conversion)]
+fn simulate(
+    circuit: &Arc<Circuit>,
+    input_garbler: &[bool],
+    input_evaluator: &[bool],
+) -> Result<Vec<bool>> {
+    let (mut garbler, msg_for_evaluator) =
+        GatewayGarbler::start_shared(circuit.clone(), input_garbler, simulation_rng(0)?)?;
+    let mut msg_for_evaluator = Message::from(msg_for_evaluator);
 
-        for _ in 0..total_steps {
-            let (next_evaluator, msg_for_garbler) = evaluator.next(&msg_for_evaluator)?;
-            evaluator = next_evaluator;
+    let mut evaluator =
+        GatewayEvaluator::new_shared(circuit.clone(), input_evaluator, simulation_rng(1)?)?;
 
-            let (next_garbler, reply) = garbler.next(&msg_for_garbler)?;
-            garbler = next_garbler;
+    assert_eq!(garbler.steps(), evaluator.steps());
+    let total_steps = garbler.steps();
 
-            msg_for_evaluator = reply;
-        }
+    for _ in 0..total_steps {
+        let (next_evaluator, msg_for_garbler) = evaluator.next(&msg_for_evaluator)?;
+        evaluator = next_evaluator;
+        let msg_for_garbler = Message::from(msg_for_garbler);
+
+        let (next_garbler, reply) = garbler.next(&msg_for_garbler)?;
+        garbler = next_garbler;
+
+        msg_for_evaluator = Message::from(reply);
+    }
+
+    let output = evaluator.output(&msg_for_evaluator)?;
+    Ok(output)
+}
+
+/// Seeds a protocol party from the system entropy source.
+///
+/// On `wasm32` targets there is no entropy source to assume, so this fails and callers must seed
+/// the party themselves, e.g. with [`GatewayGarbler::start_with_rng`].
+pub(crate) fn entropy_rng() -> Result<ChaCha20Rng> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Ok(ChaCha20Rng::from_entropy())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(anyhow::anyhow!(
+            "no system entropy source on wasm32; seed the party explicitly"
+        ))
+    }
+}
 
-        let output = evaluator.output(&msg_for_evaluator)?;
-        Ok(output)
+/// Randomness for a party of the [`LocalSimulator`].
+///
+/// Both parties run in the same process, so on `wasm32` targets a fixed per-party seed
+/// keeps the simulation deterministic without relying on a system entropy source.
+fn simulation_rng(party: u64) -> Result<ChaCha20Rng> {
+    if cfg!(target_arch = "wasm32") {
+        Ok(ChaCha20Rng::seed_from_u64(party))
+    } else {
+        entropy_rng()
     }
 }
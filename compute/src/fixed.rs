@@ -0,0 +1,143 @@
+//! Signed fixed-point numbers on top of [`GarbledInt`].
+//!
+//! A [`GarbledFixed<N, F>`] stores `N` bits of two's complement, of which the lowest `F` are
+//! the fraction: the raw integer `r` represents `r / 2^F`. `GarbledFixed<32, 16>` therefore has
+//! 16 integer bits (including the sign) and a resolution of `2^-16`.
+
+use crate::int::GarbledInt;
+use crate::uint::GarbledUint;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+pub type GarbledFixed16 = GarbledFixed<16, 8>;
+pub type GarbledFixed32 = GarbledFixed<32, 16>;
+pub type GarbledFixed64 = GarbledFixed<64, 32>;
+
+/// A signed fixed-point number with `N` bits in total and `F` fraction bits (`F < N`).
+///
+/// Addition and subtraction are the integer circuits on the raw value. Multiplication and
+/// division rescale by `2^F` inside the circuit; see the operator impls for their rounding.
+/// Every operation wraps on overflow, like the integer types.
+#[derive(Debug, Clone)]
+pub struct GarbledFixed<const N: usize, const F: usize> {
+    raw: GarbledInt<N>,
+}
+
+impl<const N: usize, const F: usize> GarbledFixed<N, F> {
+    /// Wraps a raw two's complement value that is already scaled by `2^F`.
+    pub fn from_raw(raw: GarbledInt<N>) -> Self {
+        const {
+            assert!(
+                F < N,
+                "GarbledFixed<N, F> needs fewer fraction bits than bits"
+            )
+        };
+        // the circuits read all N bits, so a raw value storing fewer is padded first
+        Self {
+            raw: GarbledInt::new(raw.to_bits_le()),
+        }
+    }
+
+    /// The raw value, scaled by `2^F`.
+    pub fn raw(&self) -> &GarbledInt<N> {
+        &self.raw
+    }
+
+    /// Consumes the value, returning the raw value scaled by `2^F`.
+    pub fn into_raw(self) -> GarbledInt<N> {
+        self.raw
+    }
+
+    /// Converts `value`, rounding to the nearest multiple of `2^-F` (ties away from zero).
+    ///
+    /// Values outside the representable range wrap to `N` bits, and NaN converts to zero.
+    pub fn from_f64(value: f64) -> Self {
+        let raw = round(value * scale::<F>());
+        let bits: Vec<bool> = (0..N)
+            .map(|i| {
+                if i < 128 {
+                    (raw >> i) & 1 == 1
+                } else {
+                    raw < 0
+                }
+            })
+            .collect();
+        Self::from_raw(GarbledInt::new(bits))
+    }
+
+    /// Converts back to `f64`. Exact while the raw value fits in 53 bits.
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.raw.to_bits_le();
+        let mut value = 0.0;
+        for (i, &bit) in bits.iter().enumerate().rev() {
+            value *= 2.0;
+            if bit {
+                // the sign bit weighs -2^(N-1)
+                value += if i + 1 == N { -1.0 } else { 1.0 };
+            }
+        }
+        value / scale::<F>()
+    }
+}
+
+// `f64::round` lives in std, so round half away from zero by hand. `as` saturates to the i128
+// range, NaN falls through to 0, and bits past 127 of the result copy the sign.
+fn round(value: f64) -> i128 {
+    // from 2^52 on every f64 is an integer
+    if value.abs() >= 4_503_599_627_370_496.0 {
+        return value as i128;
+    }
+    let whole = value as i64;
+    // exact: both operands are within 2^52 and share the sign
+    let fraction = value - whole as f64;
+    let rounded = if fraction >= 0.5 {
+        whole + 1
+    } else if fraction <= -0.5 {
+        whole - 1
+    } else {
+        whole
+    };
+    rounded as i128
+}
+
+// 2^F as an f64, built by doubling so any F works without a shift overflowing
+fn scale<const F: usize>() -> f64 {
+    (0..F).fold(1.0, |scale, _| scale * 2.0)
+}
+
+impl<const N: usize, const F: usize> Display for GarbledFixed<N, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<const N: usize, const F: usize> From<f64> for GarbledFixed<N, F> {
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl<const N: usize, const F: usize> From<GarbledFixed<N, F>> for f64 {
+    fn from(value: GarbledFixed<N, F>) -> Self {
+        value.to_f64()
+    }
+}
+
+// Integer conversions shift the value into the integer part. Like `as` between primitives they
+// keep only the low N - F bits, so large unsigned values wrap to negative.
+impl<const N: usize, const F: usize> From<GarbledUint<N>> for GarbledFixed<N, F> {
+    fn from(value: GarbledUint<N>) -> Self {
+        let mut bits = vec![false; F];
+        bits.extend(value.to_bits_le().into_iter().take(N - F));
+        Self::from_raw(GarbledInt::new(bits))
+    }
+}
+
+impl<const N: usize, const F: usize> From<GarbledInt<N>> for GarbledFixed<N, F> {
+    fn from(value: GarbledInt<N>) -> Self {
+        let mut bits = vec![false; F];
+        bits.extend(value.to_bits_le().into_iter().take(N - F));
+        Self::from_raw(GarbledInt::new(bits))
+    }
+}
@@ -0,0 +1,74 @@
+//! IEEE 754 binary floating-point numbers.
+//!
+//! A [`GarbledFloat<N>`] holds the `N` bits of an IEEE 754 binary32 or binary64 value: the sign
+//! in the top bit, then the biased exponent, then the fraction. The arithmetic circuits in
+//! `operations::circuits::float` round to nearest, ties to even, and handle subnormals,
+//! infinities and NaN like the primitive float types.
+
+use crate::uint::GarbledUint;
+
+pub type GarbledFloat32 = GarbledFloat<32>;
+pub type GarbledFloat64 = GarbledFloat<64>;
+
+/// An IEEE 754 floating-point number of `N` bits; only 32 and 64 are supported.
+#[derive(Debug, Clone)]
+pub struct GarbledFloat<const N: usize> {
+    raw: GarbledUint<N>,
+}
+
+impl<const N: usize> GarbledFloat<N> {
+    /// Wraps the raw IEEE 754 encoding, like `f32::from_bits`. `N` must be 32 or 64, which is
+    /// checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use compute::float::GarbledFloat;
+    /// # use compute::uint::GarbledUint;
+    /// let half: GarbledFloat<16> = GarbledFloat::from_raw(GarbledUint::<16>::from(0x3800_u16));
+    /// ```
+    pub fn from_raw(raw: GarbledUint<N>) -> Self {
+        const {
+            assert!(
+                N == 32 || N == 64,
+                "GarbledFloat<N> is not an IEEE 754 format; use 32 or 64"
+            )
+        };
+        // the circuits read all N bits, so a raw value storing fewer is padded first
+        Self {
+            raw: GarbledUint::new(raw.to_bits_le()),
+        }
+    }
+
+    /// The raw IEEE 754 encoding, like `f32::to_bits`.
+    pub fn raw(&self) -> &GarbledUint<N> {
+        &self.raw
+    }
+
+    /// Consumes the value, returning the raw IEEE 754 encoding.
+    pub fn into_raw(self) -> GarbledUint<N> {
+        self.raw
+    }
+}
+
+impl From<f32> for GarbledFloat<32> {
+    fn from(value: f32) -> Self {
+        Self::from_raw(value.to_bits().into())
+    }
+}
+
+impl From<GarbledFloat<32>> for f32 {
+    fn from(value: GarbledFloat<32>) -> Self {
+        f32::from_bits(value.raw.into())
+    }
+}
+
+impl From<f64> for GarbledFloat<64> {
+    fn from(value: f64) -> Self {
+        Self::from_raw(value.to_bits().into())
+    }
+}
+
+impl From<GarbledFloat<64>> for f64 {
+    fn from(value: GarbledFloat<64>) -> Self {
+        f64::from_bits(value.raw.into())
+    }
+}
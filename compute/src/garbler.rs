@@ -1,11 +1,13 @@
 use std::fmt::Debug;
 
 use anyhow::Result;
-use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
+use std::sync::Arc;
 use tandem::states::Contributor;
 use tandem::Circuit;
 
+use crate::executor::entropy_rng;
+
 pub trait Garbler {
     fn start(circuit: &Circuit, input: &[bool]) -> Result<(Self, Vec<u8>)>
     where
@@ -18,14 +20,29 @@ pub trait Garbler {
 }
 
 pub struct GatewayGarbler {
-    contributor: Contributor<Circuit, Vec<bool>>,
+    contributor: Contributor<Arc<Circuit>, Vec<bool>>,
     steps_remaining: u32,
 }
 
-impl Garbler for GatewayGarbler {
-    fn start(circuit: &Circuit, input: &[bool]) -> Result<(Self, Vec<u8>)> {
-        let (contributor, message) =
-            Contributor::new(circuit.clone(), input.to_vec(), ChaCha20Rng::from_entropy())?;
+impl GatewayGarbler {
+    /// Starts the protocol with the given randomness instead of the system entropy source,
+    /// which is unavailable on the WASM execution path.
+    pub fn start_with_rng(
+        circuit: &Circuit,
+        input: &[bool],
+        rng: ChaCha20Rng,
+    ) -> Result<(Self, Vec<u8>)> {
+        GatewayGarbler::start_shared(Arc::new(circuit.clone()), input, rng)
+    }
+
+    /// Starts the protocol on a shared circuit, so that repeated executions of a prepared
+    /// circuit do not copy its gates.
+    pub(crate) fn start_shared(
+        circuit: Arc<Circuit>,
+        input: &[bool],
+        rng: ChaCha20Rng,
+    ) -> Result<(Self, Vec<u8>)> {
+        let (contributor, message) = Contributor::new(circuit, input.to_vec(), rng)?;
         let steps_remaining = contributor.steps();
         Ok((
             GatewayGarbler {
@@ -35,6 +52,12 @@ impl Garbler for GatewayGarbler {
             message,
         ))
     }
+}
+
+impl Garbler for GatewayGarbler {
+    fn start(circuit: &Circuit, input: &[bool]) -> Result<(Self, Vec<u8>)> {
+        GatewayGarbler::start_with_rng(circuit, input, entropy_rng()?)
+    }
 
     fn next(self, message: &[u8]) -> Result<(Self, Vec<u8>)> {
         let (next_state, response) = self.contributor.run(message)?;
@@ -1,7 +1,19 @@
-use crate::uint::GarbledUint;
-use std::convert::From;
-use std::fmt::Display;
-use std::marker::PhantomData;
+use crate::error::{ConversionError, LengthError, ParseError};
+use crate::uint::{
+    fmt_radix, magnitude_to_bits, parse_magnitude, split_radix, strip_radix_prefix, GarbledBit,
+    GarbledUint,
+};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Binary, Display, LowerHex, UpperHex};
+use core::marker::PhantomData;
+use core::ops::Index;
+use core::str::FromStr;
+
+#[cfg(feature = "packed")]
+use crate::packed::PackedBits;
 
 pub type GarbledInt1 = GarbledInt<1>;
 pub type GarbledInt2 = GarbledInt<2>;
@@ -16,57 +28,425 @@ pub type GarbledInt256 = GarbledInt<256>;
 pub type GarbledInt512 = GarbledInt<512>;
 pub type GarbledInt1024 = GarbledInt<1024>;
 
-// Define a new type GarbledInt<N>
+/// An `N`-bit two's complement integer, stored as the plaintext bits one party feeds into a
+/// circuit.
+///
+/// # Conversions
+///
+/// As with [`GarbledUint`], the `From` impls to and from the primitive integers check the width,
+/// not the value. `TryFrom` on references checks that the value fits in two's complement and
+/// returns a [`ConversionError`] otherwise, like the `try_from_iX` constructors such as
+/// [`GarbledInt::try_from_i16`] and the `try_into_iX` methods such as [`GarbledInt::try_into_i8`].
+///
+/// ```
+/// # use compute::int::GarbledInt8;
+/// assert!(GarbledInt8::try_from(&200_i16).is_err());
+/// let value = GarbledInt8::try_from(&-100_i16).unwrap();
+/// assert_eq!(i8::try_from(&value), Ok(-100));
+/// ```
 #[derive(Debug, Clone)]
 pub struct GarbledInt<const N: usize> {
-    pub(crate) bits: Vec<bool>, // Store the bits of the signed integer (in two's complement form)
+    #[cfg(not(feature = "packed"))]
+    bits: Vec<bool>, // Store the bits of the signed integer (in two's complement form)
+    #[cfg(feature = "packed")]
+    bits: PackedBits,
     _phantom: PhantomData<[bool; N]>, // PhantomData to ensure the N bit size
 }
 
+impl<const N: usize> Index<usize> for GarbledInt<N> {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(
+            index < N,
+            "bit index {} out of range for GarbledInt<{}>",
+            index,
+            N
+        );
+        // a reference to a constant, since packed storage has no `bool` to borrow
+        if self.stored_bit(index).unwrap_or(false) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    /// Parses an optionally signed magnitude in `radix` (2 to 36) without a prefix, like
+    /// [`i32::from_str_radix`]. `_` separators between digits are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let (negative, digits) = split_sign(s);
+        Self::parse_signed(negative, digits, radix)
+    }
+
+    /// Parses an optionally signed hexadecimal magnitude, with or without a `0x` prefix, e.g.
+    /// `"-0x80"`. Like `i8::from_str_radix`, `"0xff"` overflows an 8-bit value.
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        let (negative, digits) = split_sign(s);
+        Self::parse_signed(negative, strip_radix_prefix(digits, 'x'), 16)
+    }
+
+    /// Parses an optionally signed binary magnitude, with or without a `0b` prefix.
+    pub fn from_bin(s: &str) -> Result<Self, ParseError> {
+        let (negative, digits) = split_sign(s);
+        Self::parse_signed(negative, strip_radix_prefix(digits, 'b'), 2)
+    }
+
+    fn parse_signed(negative: bool, digits: &str, radix: u32) -> Result<Self, ParseError> {
+        let magnitude = parse_magnitude(digits, radix)?;
+        let mut bits = magnitude_to_bits(&magnitude, N)?;
+        let nonzero = bits.iter().any(|&bit| bit);
+
+        if negative {
+            // two's complement negation: invert, then add one
+            let mut carry = true;
+            for bit in bits.iter_mut() {
+                let inverted = !*bit;
+                *bit = inverted ^ carry;
+                carry &= inverted;
+            }
+        }
+        // a positive magnitude must leave the sign bit clear, and a negative one must set it
+        let sign = bits.last().copied().unwrap_or(false);
+        if sign != (negative && nonzero) {
+            return Err(ParseError::Overflow);
+        }
+        Ok(GarbledInt::new(bits))
+    }
+}
+
+/// Parses an optionally signed decimal literal, or a hexadecimal, octal or binary magnitude
+/// with a `0x`, `0o` or `0b` prefix after the sign, e.g. `"-0x80"`.
+impl<const N: usize> FromStr for GarbledInt<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, rest) = split_sign(s);
+        let (digits, radix) = split_radix(rest);
+        Self::parse_signed(negative, digits, radix)
+    }
+}
+
+// Splits a leading `-` or `+` off `s`, returning whether the value is negative.
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
 impl<const N: usize> Display for GarbledInt<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Convert the bits to a signed integer
-        match N {
-            8 => write!(f, "{}", i8::from(self.clone())),
-            16 => write!(f, "{}", i16::from(self.clone())),
-            32 => write!(f, "{}", i32::from(self.clone())),
-            64 => write!(f, "{}", i64::from(self.clone())),
-            128 => write!(f, "{}", i128::from(self.clone())),
-            160..=1024 => write!(f, "GarbledInt<{}>", N),
-            _ => panic!("Unsupported bit size for GarbledInt"),
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Widths that fit a primitive go through i128, sign-extending narrower values
+        if N <= 128 {
+            let mut value = i128::from(self.clone());
+            if N > 0 && N < 128 && self.bits().get(N - 1) == Some(&true) {
+                value |= -1 << N;
+            }
+            return write!(f, "{}", value);
+        }
+
+        // Wider values: take the two's complement magnitude and print it digit by digit
+        let negative = self.bits().get(N - 1) == Some(&true);
+        let mut magnitude = self.bits().into_owned();
+        if negative {
+            let mut carry = true;
+            for bit in magnitude.iter_mut() {
+                let inverted = !*bit;
+                *bit = inverted ^ carry;
+                carry &= inverted;
+            }
+        }
+
+        let digits = magnitude_to_decimal(&magnitude);
+        if negative {
+            write!(f, "-{}", digits)
+        } else {
+            write!(f, "{}", digits)
+        }
+    }
+}
+
+// Radix formatting reinterprets the two's complement bits as unsigned, like the primitives do
+impl<const N: usize> Binary for GarbledInt<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_radix(&self.bits(), f, 1, false, "0b")
+    }
+}
+
+impl<const N: usize> LowerHex for GarbledInt<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_radix(&self.bits(), f, 4, false, "0x")
+    }
+}
+
+impl<const N: usize> UpperHex for GarbledInt<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_radix(&self.bits(), f, 4, true, "0x")
+    }
+}
+
+// Convert an LSB-first bit vector into its unsigned decimal representation
+fn magnitude_to_decimal(bits: &[bool]) -> String {
+    // Little-endian base-10 digits, doubled and incremented once per bit (MSB first)
+    let mut digits: Vec<u8> = vec![0];
+    for &bit in bits.iter().rev() {
+        let mut carry = bit as u8;
+        for digit in digits.iter_mut() {
+            let doubled = *digit * 2 + carry;
+            *digit = doubled % 10;
+            carry = doubled / 10;
+        }
+        if carry > 0 {
+            digits.push(carry);
         }
     }
+
+    digits
+        .iter()
+        .rev()
+        .map(|digit| char::from(b'0' + digit))
+        .collect()
 }
 
 // Implement GarbledInt<N>
 impl<const N: usize> GarbledInt<N> {
-    // Constructor for GarbledInt<N> from a boolean vector
+    /// Creates a value from exactly `N` two's complement bits in little-endian order: `bits[0]`
+    /// is the least-significant bit and `bits[N - 1]` the sign bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` does not hold exactly `N` bits.
     pub fn new(bits: Vec<bool>) -> Self {
         assert_eq!(bits.len(), N, "The number of bits must be {}", N);
+        GarbledInt::from_stored_bits(bits)
+    }
+
+    // Like `new` without the length check, for bits converted from a `GarbledUint`. `into`
+    // packs the bits with the `packed` feature and is the identity otherwise.
+    #[allow(clippy::useless_conversion)]
+    fn from_stored_bits(bits: Vec<bool>) -> Self {
         GarbledInt {
-            bits,
+            bits: bits.into(),
             _phantom: PhantomData,
         }
     }
+
+    /// Creates `value` with all `N` two's complement bits stored. Widths above 128 bits are
+    /// sign-extended.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in `N` bits.
+    pub fn constant(value: i128) -> Self {
+        let fits = match N {
+            0 => value == 0,
+            1..128 => matches!(value >> (N - 1), 0 | -1),
+            _ => true,
+        };
+        assert!(fits, "{} does not fit in GarbledInt<{}>", value, N);
+        GarbledInt::new((0..N).map(|i| (value >> i.min(127)) & 1 == 1).collect())
+    }
+
+    /// Creates a value from its two's complement bits, least-significant bit first; the same
+    /// as [`GarbledInt::new`].
+    pub fn from_bits(bits: Vec<bool>) -> Self {
+        GarbledInt::new(bits)
+    }
+
+    /// Returns the stored two's complement bits, least-significant bit first; see
+    /// [`GarbledUint::bits`].
+    pub fn bits(&self) -> Cow<'_, [bool]> {
+        #[cfg(not(feature = "packed"))]
+        return Cow::Borrowed(&self.bits);
+        #[cfg(feature = "packed")]
+        return Cow::Owned(self.bits.to_vec());
+    }
+
+    /// Consumes the value and returns its two's complement bits, least-significant bit first.
+    // Taken rather than moved out, as the `zeroize` feature gives values a `Drop` impl
+    #[allow(unused_mut)]
+    pub fn into_bits(mut self) -> Vec<bool> {
+        #[cfg(not(feature = "packed"))]
+        return core::mem::take(&mut self.bits);
+        #[cfg(feature = "packed")]
+        return self.bits.to_vec();
+    }
+
+    /// Returns stored bit `i`, or `None` past the stored bits, without unpacking the others.
+    pub(crate) fn stored_bit(&self, i: usize) -> Option<bool> {
+        #[cfg(not(feature = "packed"))]
+        return self.bits.get(i).copied();
+        #[cfg(feature = "packed")]
+        return self.bits.get(i);
+    }
+
+    /// Creates a value from exactly `N` two's complement bits, least-significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` does not hold exactly `N` bits.
+    pub fn from_bits_le(bits: &[bool]) -> Self {
+        GarbledInt::new(bits.to_vec())
+    }
+
+    /// Creates a value from exactly `N` two's complement bits, most-significant (sign) bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` does not hold exactly `N` bits.
+    pub fn from_bits_be(bits: &[bool]) -> Self {
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        GarbledInt::new(bits)
+    }
+
+    /// Returns all `N` bits, least-significant bit first. Values that store fewer bits, such as
+    /// one converted from [`GarbledUint::zero`], are padded with zeros.
+    pub fn to_bits_le(&self) -> Vec<bool> {
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        bits
+    }
+
+    /// Returns all `N` bits, most-significant (sign) bit first.
+    pub fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits = self.to_bits_le();
+        bits.reverse();
+        bits
+    }
+
+    /// Returns the `N.div_ceil(8)` two's complement bytes, least-significant byte first, like
+    /// [`i32::to_le_bytes`]. The unused high bits of the last byte are zero, not sign bits.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        GarbledUint::from(self).to_le_bytes()
+    }
+
+    /// Returns the `N.div_ceil(8)` two's complement bytes, most-significant byte first, like
+    /// [`i32::to_be_bytes`]. The unused high bits of the first byte are zero, not sign bits.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        GarbledUint::from(self).to_be_bytes()
+    }
+
+    /// Reads a value from [`GarbledInt::to_le_bytes`]. Bits above `N` in the last byte are
+    /// ignored.
+    ///
+    /// Returns an error unless `bytes` holds exactly `N.div_ceil(8)` bytes.
+    pub fn from_le_bytes(bytes: &[u8]) -> Result<Self, LengthError> {
+        Ok(GarbledUint::<N>::from_le_bytes(bytes)?.into())
+    }
+
+    /// Reads a value from [`GarbledInt::to_be_bytes`]. Bits above `N` in the first byte are
+    /// ignored.
+    ///
+    /// Returns an error unless `bytes` holds exactly `N.div_ceil(8)` bytes.
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, LengthError> {
+        Ok(GarbledUint::<N>::from_be_bytes(bytes)?.into())
+    }
+
+    /// Widens to `M >= N` bits by repeating the sign bit, like `x as i64` from an `i32`, so the
+    /// value is unchanged. Narrowing fails to compile.
+    pub fn sign_extend<const M: usize>(&self) -> GarbledInt<M> {
+        const { assert!(M >= N, "sign_extend cannot narrow; use truncate") };
+
+        let mut bits = self.to_bits_le();
+        bits.resize(N, false);
+        let sign = bits.last().copied().unwrap_or(false);
+        bits.resize(M, sign);
+        GarbledInt::new(bits)
+    }
+
+    /// Keeps the low `M <= N` bits, like `x as i8` from an `i32`, so large values wrap.
+    /// Widening fails to compile.
+    pub fn truncate<const M: usize>(&self) -> GarbledInt<M> {
+        const { assert!(M <= N, "truncate cannot widen; use sign_extend") };
+
+        let mut bits = self.to_bits_le();
+        bits.resize(N, false);
+        bits.truncate(M);
+        GarbledInt::new(bits)
+    }
+
+    /// Sign-extends to `M >= N` bits and reinterprets the result as a [`GarbledUint`], like
+    /// `i8 as u16`: -1 becomes `2^M - 1`.
+    pub fn sign_extend_unsigned<const M: usize>(&self) -> GarbledUint<M> {
+        self.sign_extend::<M>().to_unsigned()
+    }
+
+    /// Reinterprets the `N` bits as unsigned and zero-extends them to `M >= N` bits, like
+    /// `i8 as u8 as u16`: -1 becomes `2^N - 1`.
+    pub fn zero_extend_unsigned<const M: usize>(&self) -> GarbledUint<M> {
+        GarbledUint::<N>::from(self).zero_extend::<M>()
+    }
+
+    /// Reinterprets the same `N` bits as a [`GarbledUint`], like `i8 as u8`.
+    ///
+    /// No bit changes, so the numeric value does: negative values become `2^N` plus the
+    /// value, e.g. -1 as a `GarbledInt8` is 255 as a `GarbledUint8`. Runs no circuit.
+    pub fn to_unsigned(self) -> GarbledUint<N> {
+        self.into()
+    }
+
+    /// Returns bit `i` (bit `N - 1` is the sign bit).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn test_bit(&self, i: usize) -> GarbledBit {
+        GarbledUint::from(self).test_bit(i)
+    }
+
+    /// Returns a copy with bit `i` replaced by `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn set_bit(&self, i: usize, value: &GarbledBit) -> Self {
+        GarbledUint::from(self).set_bit(i, value).into()
+    }
+
+    /// Returns a copy with bit `i` set to 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn with_bit_set(&self, i: usize) -> Self {
+        GarbledUint::from(self).with_bit_set(i).into()
+    }
+
+    /// Returns a copy with bit `i` cleared to 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn with_bit_cleared(&self, i: usize) -> Self {
+        GarbledUint::from(self).with_bit_cleared(i).into()
+    }
+
+    /// Fills all `N` two's complement bits from `rng`, e.g. for test inputs or additive masks.
+    #[cfg(all(feature = "rand", not(target_arch = "wasm32")))]
+    pub fn random(rng: &mut impl rand::Rng) -> Self {
+        GarbledInt::new((0..N).map(|_| rng.gen()).collect())
+    }
 }
 
 impl<const N: usize> From<GarbledUint<N>> for GarbledInt<N> {
     fn from(uint: GarbledUint<N>) -> Self {
         // Directly copy the bits from the unsigned Uint<N> to the signed GarbledInt<N>
-        GarbledInt {
-            bits: uint.bits,
-            _phantom: PhantomData,
-        }
+        GarbledInt::from_stored_bits(uint.into_bits())
     }
 }
 
 impl<const N: usize> From<&GarbledUint<N>> for GarbledInt<N> {
     fn from(uint: &GarbledUint<N>) -> Self {
         // Directly copy the bits from the unsigned Uint<N> to the signed GarbledInt<N>
-        GarbledInt {
-            bits: uint.bits.clone(),
-            _phantom: PhantomData,
-        }
+        GarbledInt::from_stored_bits(uint.bits().into_owned())
     }
 }
 
@@ -156,7 +536,7 @@ impl<const N: usize> From<GarbledInt<N>> for i8 {
         assert!(N <= 8, "Int<N> can only be converted to i8 if N <= 8");
 
         let mut value: i8 = 0;
-        for (i, &bit) in gint.bits.iter().enumerate() {
+        for (i, &bit) in gint.bits().iter().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -171,7 +551,7 @@ impl<const N: usize> From<GarbledInt<N>> for i16 {
         assert!(N <= 16, "Int<N> can only be converted to i16 if N <= 16");
 
         let mut value: i16 = 0;
-        for (i, &bit) in gint.bits.iter().enumerate() {
+        for (i, &bit) in gint.bits().iter().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -186,7 +566,7 @@ impl<const N: usize> From<GarbledInt<N>> for i32 {
         assert!(N <= 32, "Int<N> can only be converted to i32 if N <= 32");
 
         let mut value: i32 = 0;
-        for (i, &bit) in gint.bits.iter().enumerate() {
+        for (i, &bit) in gint.bits().iter().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -201,7 +581,7 @@ impl<const N: usize> From<GarbledInt<N>> for i64 {
         assert!(N <= 64, "Int<N> can only be converted to i64 if N <= 64");
 
         let mut value: i64 = 0;
-        for (i, &bit) in gint.bits.iter().enumerate() {
+        for (i, &bit) in gint.bits().iter().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -216,7 +596,7 @@ impl<const N: usize> From<GarbledInt<N>> for i128 {
         assert!(N <= 128, "Int<N> can only be converted to i128 if N <= 128");
 
         let mut value: i128 = 0;
-        for (i, &bit) in gint.bits.iter().enumerate() {
+        for (i, &bit) in gint.bits().iter().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -225,3 +605,127 @@ impl<const N: usize> From<GarbledInt<N>> for i128 {
         value
     }
 }
+
+// Serialized as the two's complement bytes of `to_le_bytes`, like `GarbledUint`
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for GarbledInt<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.to_le_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for GarbledInt<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        GarbledInt::from_le_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::Zeroize for GarbledInt<N> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bits);
+    }
+}
+
+// Wipes the plaintext bits on drop; see the `GarbledUint` impl
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Drop for GarbledInt<N> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::ZeroizeOnDrop for GarbledInt<N> {}
+
+// Fallible counterparts of the conversions above, checking that the value fits in two's
+// complement. See the unsigned versions for why `TryFrom` is only implemented for references.
+macro_rules! impl_try_conversions {
+    ($(($t:ty, $try_from:ident, $try_into:ident)),*) => {
+        $(
+            impl<const N: usize> GarbledInt<N> {
+                #[doc = concat!(
+                    "Converts an `",
+                    stringify!($t),
+                    "`, failing if it needs more than `N` bits."
+                )]
+                pub fn $try_from(value: $t) -> Result<Self, ConversionError> {
+                    let redundant = if value < 0 {
+                        value.leading_ones()
+                    } else {
+                        value.leading_zeros()
+                    };
+                    let required = (<$t>::BITS - redundant + 1) as usize;
+                    if required > N {
+                        return Err(ConversionError {
+                            required,
+                            available: N,
+                        });
+                    }
+
+                    // the arithmetic shift repeats the sign bit past the top of the primitive
+                    let top = <$t>::BITS as usize - 1;
+                    let bits = (0..N).map(|i| (value >> i.min(top)) & 1 == 1).collect();
+                    Ok(GarbledInt::new(bits))
+                }
+
+                #[doc = concat!(
+                    "Converts to `",
+                    stringify!($t),
+                    "`, failing if the value does not fit."
+                )]
+                pub fn $try_into(&self) -> Result<$t, ConversionError> {
+                    let mut bits = self.bits().into_owned();
+                    bits.resize(N, false);
+                    let sign = bits.last().copied().unwrap_or(false);
+                    let required =
+                        bits.iter().rposition(|&bit| bit != sign).map_or(1, |i| i + 2);
+                    if required > <$t>::BITS as usize {
+                        return Err(ConversionError {
+                            required,
+                            available: <$t>::BITS as usize,
+                        });
+                    }
+
+                    let start: $t = if sign { -1 } else { 0 };
+                    Ok(bits[..required - 1]
+                        .iter()
+                        .enumerate()
+                        .fold(start, |value, (i, &bit)| {
+                            if bit {
+                                value | (1 << i)
+                            } else {
+                                value & !(1 << i)
+                            }
+                        }))
+                }
+            }
+
+            impl<const N: usize> TryFrom<&$t> for GarbledInt<N> {
+                type Error = ConversionError;
+
+                fn try_from(value: &$t) -> Result<Self, Self::Error> {
+                    GarbledInt::$try_from(*value)
+                }
+            }
+
+            impl<const N: usize> TryFrom<&GarbledInt<N>> for $t {
+                type Error = ConversionError;
+
+                fn try_from(gint: &GarbledInt<N>) -> Result<Self, Self::Error> {
+                    gint.$try_into()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_conversions!(
+    (i8, try_from_i8, try_into_i8),
+    (i16, try_from_i16, try_into_i16),
+    (i32, try_from_i32, try_into_i32),
+    (i64, try_from_i64, try_into_i64),
+    (i128, try_from_i128, try_into_i128)
+);
@@ -1,24 +1,88 @@
+//! Garbled integer types and the circuits that operate on them.
+//!
+//! The value types only need `core` and `alloc`, so they can be used with
+//! `default-features = false` in `no_std` environments: import them from their modules, such as
+//! [`uint`], [`int`], [`fixed`], [`float`], [`bytes`] and [`error`]. The `prelude`, and building
+//! and executing circuits, require the default `std` feature.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod array;
+pub mod boolean;
+pub mod bytes;
+pub mod char;
+pub mod error;
+#[cfg(feature = "std")]
 pub mod evaluator;
+#[cfg(feature = "std")]
 pub mod executor;
+pub mod fixed;
+pub mod float;
+#[cfg(feature = "std")]
 pub mod garbler;
 pub mod int;
+#[cfg(feature = "std")]
 pub mod operations;
+pub mod option;
+#[cfg(feature = "packed")]
+mod packed;
+pub mod small;
+pub mod string;
+#[cfg(feature = "debug-trace")]
+pub mod trace;
 pub mod uint;
+pub mod vec;
 
+#[cfg(feature = "std")]
 pub mod prelude {
-    pub use crate::operations::circuits::builder::WRK17CircuitBuilder;
+    pub use crate::operations::circuits::builder::{
+        Adder, ComparisonWires, Multiplier, WRK17CircuitBuilder,
+    };
+    pub use crate::operations::comparator::Comparison;
 
-    pub use crate::executor::get_executor;
+    pub use crate::array::GarbledArray;
+    pub use crate::boolean::GarbledBool;
+    pub use crate::bytes::GarbledBytes;
+    pub use crate::char::GarbledChar;
+    pub use crate::error::{CircuitError, ConversionError, LengthError, ParseError};
+    pub use crate::executor::{
+        get_executor, Executor, ExecutorConfig, LocalSimulator, PreparedCircuit,
+    };
+    pub use crate::fixed::{GarbledFixed, GarbledFixed16, GarbledFixed32, GarbledFixed64};
+    pub use crate::float::{GarbledFloat, GarbledFloat32, GarbledFloat64};
     pub use crate::int::{
         GarbledInt, GarbledInt128, GarbledInt16, GarbledInt256, GarbledInt32, GarbledInt512,
         GarbledInt64, GarbledInt8,
     };
+    pub use crate::operations::circuits::encode::GarbledEncode;
+    pub use crate::operations::circuits::privacy::{Constant, Encoded, Plain};
+    pub use crate::operations::circuits::schedule::{
+        circuit_stats, schedule_by_depth, CircuitStats,
+    };
+    pub use crate::operations::circuits::streaming::{evaluate_streaming, StreamingEvaluation};
+    pub use crate::operations::circuits::typed::{TypedCircuitBuilder, Wires};
     pub use crate::operations::circuits::types::GateIndexVec;
+    pub use crate::operations::util::{deserialize_circuit, serialize_circuit};
+    #[cfg(feature = "serde")]
+    pub use crate::operations::util::{deserialize_compiled, serialize_compiled, CompiledCircuit};
+    pub use crate::option::GarbledOption;
+    pub use crate::small::{
+        SmallGarbledUint, SmallGarbledUint128, SmallGarbledUint16, SmallGarbledUint32,
+        SmallGarbledUint64, SmallGarbledUint8,
+    };
+    pub use crate::string::GarbledString;
+    #[cfg(feature = "debug-trace")]
+    pub use crate::trace::{simulate_with_transcript, Transcript};
+    #[allow(deprecated)]
+    pub use crate::uint::GarbledBoolean;
     pub use crate::uint::{
-        GarbledBoolean, GarbledUint, GarbledUint128, GarbledUint16, GarbledUint2, GarbledUint256,
+        GarbledBit, GarbledUint, GarbledUint128, GarbledUint16, GarbledUint2, GarbledUint256,
         GarbledUint32, GarbledUint4, GarbledUint512, GarbledUint64, GarbledUint8,
     };
-    pub use circuit_macro::encrypted;
+    pub use crate::vec::GarbledVec;
+    pub use circuit_macro::{encrypted, GarbledEncode};
     pub use tandem::{Circuit, Gate};
 
     pub use crate::evaluator::Evaluator;
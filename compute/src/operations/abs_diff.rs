@@ -0,0 +1,9 @@
+use crate::operations::circuits::builder::build_and_execute_abs_diff;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    // |self - other| without branching on which operand is larger, like `u32::abs_diff`
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        build_and_execute_abs_diff(self, other)
+    }
+}
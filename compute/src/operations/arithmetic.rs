@@ -1,10 +1,16 @@
 use crate::int::GarbledInt;
 use crate::operations::circuits::builder::{
-    build_and_execute_addition, build_and_execute_division, build_and_execute_multiplication,
-    build_and_execute_subtraction,
+    build_and_execute_addition, build_and_execute_div_rem, build_and_execute_division,
+    build_and_execute_isqrt, build_and_execute_multiplication, build_and_execute_negation,
+    build_and_execute_reduce_add, build_and_execute_signed_division,
+    build_and_execute_signed_remainder, build_and_execute_subtraction,
+    build_and_execute_widening_mul,
 };
 use crate::uint::GarbledUint;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use std::iter::Sum;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
 use super::circuits::builder::build_and_execute_remainder;
 
@@ -97,7 +103,8 @@ impl<const N: usize> MulAssign<&GarbledUint<N>> for GarbledUint<N> {
     }
 }
 
-// Implement the Div operation for GarbledUint<N> and &GarbledUint<N>
+// Implement the Div operation for GarbledUint<N> and &GarbledUint<N> with a restoring divider.
+// The divisor is secret, so dividing by zero cannot panic and yields 2^N - 1 instead.
 impl<const N: usize> Div for GarbledUint<N> {
     type Output = Self;
 
@@ -127,7 +134,8 @@ impl<const N: usize> DivAssign<&GarbledUint<N>> for GarbledUint<N> {
     }
 }
 
-// rem
+// Implement the Rem operation for GarbledUint<N> and &GarbledUint<N> on the same divider as Div.
+// Dividing by zero leaves the dividend as the remainder.
 impl<const N: usize> Rem for GarbledUint<N> {
     type Output = Self;
 
@@ -156,6 +164,42 @@ impl<const N: usize> RemAssign<&GarbledUint<N>> for GarbledUint<N> {
     }
 }
 
+impl<const N: usize> GarbledUint<N> {
+    /// Returns `(self / rhs, self % rhs)` from one circuit with a single divider, half the
+    /// cost of evaluating `/` and `%` separately.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        build_and_execute_div_rem(self, rhs, false)
+    }
+
+    /// Multiplies into the full `M = 2 * N` bit product, which never overflows, unlike `*`
+    /// which keeps only the low `N` bits. Use [`GarbledUint::slice`] to split it into halves.
+    /// A width other than `2 * N` fails to compile.
+    pub fn widening_mul<const M: usize>(&self, rhs: &Self) -> GarbledUint<M> {
+        const { assert!(M == 2 * N, "widening_mul produces exactly 2 * N bits") };
+
+        build_and_execute_widening_mul(self, rhs)
+    }
+
+    /// Returns the integer square root, rounded down like the native `isqrt`, without
+    /// revealing any intermediate value.
+    pub fn isqrt(&self) -> Self {
+        build_and_execute_isqrt(self)
+    }
+}
+
+// Sum every value in one circuit with a balanced adder tree, rather than one circuit per `+`
+impl<const N: usize> Sum for GarbledUint<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        build_and_execute_reduce_add(&iter.collect::<Vec<_>>())
+    }
+}
+
+impl<'a, const N: usize> Sum<&'a GarbledUint<N>> for GarbledUint<N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        build_and_execute_reduce_add(&iter.cloned().collect::<Vec<_>>())
+    }
+}
+
 // Implement the Add operation for GarbledInt<N> and &GarbledInt<N>
 impl<const N: usize> Add for GarbledInt<N> {
     type Output = Self;
@@ -217,6 +261,8 @@ impl<const N: usize> SubAssign<&GarbledInt<N>> for GarbledInt<N> {
 }
 
 // Implement the Mul operation for GarbledInt<N> and &GarbledInt<N>
+// The low N bits of a two's complement product equal those of the unsigned product, so the
+// unsigned multiplier already matches `wrapping_mul` for negative operands.
 impl<const N: usize> Mul for GarbledInt<N> {
     type Output = Self;
 
@@ -246,12 +292,14 @@ impl<const N: usize> MulAssign<&GarbledInt<N>> for GarbledInt<N> {
     }
 }
 
-// implement Div operation for GarbledInt<N> and &GarbledInt<N>
+// Implement the Div operation for GarbledInt<N> and &GarbledInt<N>. Like `iN::wrapping_div`
+// the quotient rounds toward zero and MIN / -1 wraps to MIN; dividing by zero yields -1 for a
+// non-negative dividend and 1 for a negative one.
 impl<const N: usize> Div for GarbledInt<N> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        build_and_execute_division(&self.into(), &rhs.into()).into()
+        build_and_execute_signed_division(&self.into(), &rhs.into()).into()
     }
 }
 
@@ -259,29 +307,30 @@ impl<const N: usize> Div for &GarbledInt<N> {
     type Output = GarbledInt<N>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        build_and_execute_division(&self.into(), &rhs.into()).into()
+        build_and_execute_signed_division(&self.into(), &rhs.into()).into()
     }
 }
 
 // Implement the DivAssign operation for GarbledInt<N> and &GarbledInt<N>
 impl<const N: usize> DivAssign for GarbledInt<N> {
     fn div_assign(&mut self, rhs: Self) {
-        *self = build_and_execute_division(&self.clone().into(), &rhs.into()).into();
+        *self = build_and_execute_signed_division(&self.clone().into(), &rhs.into()).into();
     }
 }
 
 impl<const N: usize> DivAssign<&GarbledInt<N>> for GarbledInt<N> {
     fn div_assign(&mut self, rhs: &Self) {
-        *self = build_and_execute_division(&self.clone().into(), &rhs.into()).into();
+        *self = build_and_execute_signed_division(&self.clone().into(), &rhs.into()).into();
     }
 }
 
-// Implement the Rem operation for GarbledInt<N> and &GarbledInt<N>
+// Implement the Rem operation for GarbledInt<N> and &GarbledInt<N>. The remainder takes the sign
+// of the dividend, like `iN::wrapping_rem`, and dividing by zero leaves the dividend.
 impl<const N: usize> Rem for GarbledInt<N> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        build_and_execute_remainder(&self.into(), &rhs.into()).into()
+        build_and_execute_signed_remainder(&self.into(), &rhs.into()).into()
     }
 }
 
@@ -289,19 +338,61 @@ impl<const N: usize> Rem for &GarbledInt<N> {
     type Output = GarbledInt<N>;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        build_and_execute_remainder(&self.into(), &rhs.into()).into()
+        build_and_execute_signed_remainder(&self.into(), &rhs.into()).into()
     }
 }
 
 // Implement the RemAssign operation for GarbledInt<N> and &GarbledInt<N>
 impl<const N: usize> RemAssign for GarbledInt<N> {
     fn rem_assign(&mut self, rhs: Self) {
-        *self = build_and_execute_remainder(&self.clone().into(), &rhs.into()).into();
+        *self = build_and_execute_signed_remainder(&self.clone().into(), &rhs.into()).into();
     }
 }
 
 impl<const N: usize> RemAssign<&GarbledInt<N>> for GarbledInt<N> {
     fn rem_assign(&mut self, rhs: &Self) {
-        *self = build_and_execute_remainder(&self.clone().into(), &rhs.into()).into();
+        *self = build_and_execute_signed_remainder(&self.clone().into(), &rhs.into()).into();
+    }
+}
+
+// Implement the Neg operation for GarbledInt<N> and &GarbledInt<N>. Like `iN::wrapping_neg`, the
+// minimum value negates to itself.
+impl<const N: usize> Neg for GarbledInt<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        build_and_execute_negation(&self.into()).into()
+    }
+}
+
+impl<const N: usize> Neg for &GarbledInt<N> {
+    type Output = GarbledInt<N>;
+
+    fn neg(self) -> Self::Output {
+        build_and_execute_negation(&self.into()).into()
+    }
+}
+
+// Two's complement addition is the unsigned one, so the sum wraps like `iN::wrapping_add`
+impl<const N: usize> Sum for GarbledInt<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let values: Vec<GarbledUint<N>> = iter.map(GarbledUint::from).collect();
+        build_and_execute_reduce_add(&values).into()
+    }
+}
+
+impl<'a, const N: usize> Sum<&'a GarbledInt<N>> for GarbledInt<N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let values: Vec<GarbledUint<N>> = iter.map(GarbledUint::from).collect();
+        build_and_execute_reduce_add(&values).into()
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    /// Returns `(self / rhs, self % rhs)` from one circuit with a single divider; see the `Div`
+    /// and `Rem` impls for the rounding and division by zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = build_and_execute_div_rem(&self.into(), &rhs.into(), true);
+        (quotient.into(), remainder.into())
     }
 }
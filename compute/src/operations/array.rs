@@ -0,0 +1,23 @@
+use crate::array::GarbledArray;
+use crate::int::GarbledInt;
+use crate::operations::circuits::builder::build_and_execute_mux_read;
+use crate::uint::GarbledUint;
+
+impl<const N: usize, const L: usize> GarbledArray<GarbledUint<N>, L> {
+    /// Returns the element at a secret `index`.
+    ///
+    /// Every element passes through a mux tree steered by the bits of `index`, so the circuit
+    /// is the same whichever element is read. An index of `L` or more returns zero, as do
+    /// elements past `2^M - 1`, which an `M`-bit index cannot address.
+    pub fn get<const M: usize>(&self, index: &GarbledUint<M>) -> GarbledUint<N> {
+        build_and_execute_mux_read(index, self.as_slice())
+    }
+}
+
+impl<const N: usize, const L: usize> GarbledArray<GarbledInt<N>, L> {
+    /// Returns the element at a secret `index`; see the `GarbledUint` version.
+    pub fn get<const M: usize>(&self, index: &GarbledUint<M>) -> GarbledInt<N> {
+        let values: Vec<GarbledUint<N>> = self.iter().map(GarbledUint::from).collect();
+        build_and_execute_mux_read(index, &values).into()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::operations::circuits::builder::{build_and_execute_batch, WRK17CircuitBuilder};
+use crate::operations::circuits::types::GateIndexVec;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    /// Applies a binary circuit operation to every `(lhs[i], rhs[i])` pair.
+    ///
+    /// The circuit for `op` is built once and then evaluated for each pair, spreading the
+    /// batch across the available threads. The output order matches the input order.
+    ///
+    /// ```ignore
+    /// let results = GarbledUint8::batch_apply(WRK17CircuitBuilder::and, &lhs, &rhs);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` have different lengths.
+    pub fn batch_apply<F>(op: F, lhs: &[Self], rhs: &[Self]) -> Vec<Self>
+    where
+        F: Fn(&mut WRK17CircuitBuilder, &GateIndexVec, &GateIndexVec) -> GateIndexVec,
+    {
+        build_and_execute_batch(op, lhs, rhs)
+    }
+}
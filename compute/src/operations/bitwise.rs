@@ -1,9 +1,19 @@
+//! Bitwise operations on garbled integers.
+//!
+//! The `std::ops` impls and `nand`/`nor`/`xnor` panic if the circuit fails to execute. They
+//! delegate to the same fallible core as the `try_*` methods, which return a [`CircuitError`]
+//! instead and are meant for long-running services that must not crash.
+
+use crate::error::CircuitError;
 use crate::int::GarbledInt;
 use crate::operations::circuits::builder::{
     build_and_execute_and, build_and_execute_nand, build_and_execute_nor, build_and_execute_not,
-    build_and_execute_or, build_and_execute_xnor, build_and_execute_xor,
+    build_and_execute_or, build_and_execute_rotate, build_and_execute_xnor, build_and_execute_xor,
+    build_and_execute_zero_count, try_build_and_execute_and, try_build_and_execute_nand,
+    try_build_and_execute_nor, try_build_and_execute_not, try_build_and_execute_or,
+    try_build_and_execute_xnor, try_build_and_execute_xor,
 };
-use crate::uint::GarbledUint;
+use crate::uint::{GarbledBit, GarbledUint, GarbledUint32};
 use std::ops::{
     BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
     ShrAssign,
@@ -252,13 +262,23 @@ fn shift_bits_right<const N: usize>(bits: &mut Vec<bool>, shift: usize) {
     }
 }
 
+// Arithmetic right shift: the vacated high bits are copies of the sign bit, like `>>` on `iN`
+fn shift_bits_right_arithmetic<const N: usize>(bits: &mut Vec<bool>, shift: usize) {
+    let sign = bits.last().copied().unwrap_or(false);
+    for _ in 0..shift {
+        bits.remove(0); // Remove the least significant bit
+        bits.push(sign); // Replicate the sign into the most significant bit
+    }
+}
+
 // Implement Shift Left operation for Uint<N>
 impl<const N: usize> Shl<usize> for GarbledUint<N> {
     type Output = Self;
 
-    fn shl(mut self, shift: usize) -> Self::Output {
-        shift_bits_left::<N>(&mut self.bits, shift);
-        self
+    fn shl(self, shift: usize) -> Self::Output {
+        let mut bits = self.into_bits();
+        shift_bits_left::<N>(&mut bits, shift);
+        GarbledUint::new(bits)
     }
 }
 
@@ -267,7 +287,7 @@ impl<const N: usize> Shl<usize> for &GarbledUint<N> {
     type Output = GarbledUint<N>;
 
     fn shl(self, shift: usize) -> Self::Output {
-        let mut bits = self.bits.clone();
+        let mut bits = self.bits().into_owned();
         shift_bits_left::<N>(&mut bits, shift);
         GarbledUint::new(bits)
     }
@@ -276,24 +296,45 @@ impl<const N: usize> Shl<usize> for &GarbledUint<N> {
 // Implement ShlAssign for GarbledUint<N>
 impl<const N: usize> ShlAssign<usize> for GarbledUint<N> {
     fn shl_assign(&mut self, shift: usize) {
-        shift_bits_left::<N>(&mut self.bits, shift);
+        let mut bits = self.bits().into_owned();
+        shift_bits_left::<N>(&mut bits, shift);
+        *self = Self::new(bits);
     }
 }
 
 // Implement ShlAssign for &GarbledUint<N>
 impl<const N: usize> ShlAssign<usize> for &GarbledUint<N> {
     fn shl_assign(&mut self, shift: usize) {
-        let mut bits: Vec<bool> = self.bits.clone();
+        let mut bits: Vec<bool> = self.bits().into_owned();
         shift_bits_left::<N>(&mut bits, shift);
     }
 }
 
+// Checked shift left for GarbledUint<N>
+impl<const N: usize> GarbledUint<N> {
+    /// Shifts left by a public amount like `<<`, also returning a flag that is set when a 1 bit
+    /// was shifted out, i.e. when the result no longer equals `self * 2^shift`.
+    ///
+    /// Unlike the native `checked_shl`, the flag is returned next to the result instead of as
+    /// an `Option`. Like the shift itself, it is computed from the plaintext bits without
+    /// running a circuit.
+    pub fn checked_shl(&self, shift: usize) -> (Self, GarbledBit) {
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+
+        let kept = N.saturating_sub(shift);
+        let lost = bits[kept..].iter().any(|&bit| bit);
+        shift_bits_left::<N>(&mut bits, shift.min(N));
+        (GarbledUint::new(bits), lost.into())
+    }
+}
+
 // Implement Shift Left operation for GarbledInt<N>
 impl<const N: usize> Shl<usize> for GarbledInt<N> {
     type Output = Self;
 
     fn shl(self, shift: usize) -> Self::Output {
-        let mut bits = self.bits;
+        let mut bits = self.into_bits();
         shift_bits_left::<N>(&mut bits, shift);
         GarbledInt::new(bits)
     }
@@ -304,7 +345,7 @@ impl<const N: usize> Shl<usize> for &GarbledInt<N> {
     type Output = GarbledInt<N>;
 
     fn shl(self, shift: usize) -> Self::Output {
-        let mut bits = self.bits.clone();
+        let mut bits = self.bits().into_owned();
         shift_bits_left::<N>(&mut bits, shift);
         GarbledInt::new(bits)
     }
@@ -313,14 +354,16 @@ impl<const N: usize> Shl<usize> for &GarbledInt<N> {
 // Implement ShlAssign for GarbledInt<N>
 impl<const N: usize> ShlAssign<usize> for GarbledInt<N> {
     fn shl_assign(&mut self, shift: usize) {
-        shift_bits_left::<N>(&mut self.bits, shift);
+        let mut bits = self.bits().into_owned();
+        shift_bits_left::<N>(&mut bits, shift);
+        *self = Self::new(bits);
     }
 }
 
 // Implement ShlAssign for &GarbledInt<N>
 impl<const N: usize> ShlAssign<usize> for &GarbledInt<N> {
     fn shl_assign(&mut self, shift: usize) {
-        let mut bits: Vec<bool> = self.bits.clone();
+        let mut bits: Vec<bool> = self.bits().into_owned();
         shift_bits_left::<N>(&mut bits, shift);
     }
 }
@@ -329,9 +372,10 @@ impl<const N: usize> ShlAssign<usize> for &GarbledInt<N> {
 impl<const N: usize> Shr<usize> for GarbledUint<N> {
     type Output = Self;
 
-    fn shr(mut self, shift: usize) -> Self::Output {
-        shift_bits_right::<N>(&mut self.bits, shift);
-        self
+    fn shr(self, shift: usize) -> Self::Output {
+        let mut bits = self.into_bits();
+        shift_bits_right::<N>(&mut bits, shift);
+        GarbledUint::new(bits)
     }
 }
 
@@ -340,7 +384,7 @@ impl<const N: usize> Shr<usize> for &GarbledUint<N> {
     type Output = GarbledUint<N>;
 
     fn shr(self, shift: usize) -> Self::Output {
-        let mut bits = self.bits.clone();
+        let mut bits = self.bits().into_owned();
         shift_bits_right::<N>(&mut bits, shift);
         GarbledUint::new(bits)
     }
@@ -349,25 +393,27 @@ impl<const N: usize> Shr<usize> for &GarbledUint<N> {
 // Implement ShrAssign for GarbledUint<N>
 impl<const N: usize> ShrAssign<usize> for GarbledUint<N> {
     fn shr_assign(&mut self, shift: usize) {
-        shift_bits_right::<N>(&mut self.bits, shift);
+        let mut bits = self.bits().into_owned();
+        shift_bits_right::<N>(&mut bits, shift);
+        *self = Self::new(bits);
     }
 }
 
 // Implement ShrAssign for &GarbledUint<N>
 impl<const N: usize> ShrAssign<usize> for &GarbledUint<N> {
     fn shr_assign(&mut self, shift: usize) {
-        let mut bits: Vec<bool> = self.bits.clone();
+        let mut bits: Vec<bool> = self.bits().into_owned();
         shift_bits_right::<N>(&mut bits, shift);
     }
 }
 
-// Implement Shift Right operation for GarbledInt<N>
+// Implement Shift Right operation for GarbledInt<N>, an arithmetic shift that keeps the sign
 impl<const N: usize> Shr<usize> for GarbledInt<N> {
     type Output = Self;
 
     fn shr(self, shift: usize) -> Self::Output {
-        let mut bits = self.bits;
-        shift_bits_right::<N>(&mut bits, shift);
+        let mut bits = self.into_bits();
+        shift_bits_right_arithmetic::<N>(&mut bits, shift);
         GarbledInt::new(bits)
     }
 }
@@ -377,8 +423,8 @@ impl<const N: usize> Shr<usize> for &GarbledInt<N> {
     type Output = GarbledInt<N>;
 
     fn shr(self, shift: usize) -> Self::Output {
-        let mut bits = self.bits.clone();
-        shift_bits_right::<N>(&mut bits, shift);
+        let mut bits = self.bits().into_owned();
+        shift_bits_right_arithmetic::<N>(&mut bits, shift);
         GarbledInt::new(bits)
     }
 }
@@ -386,15 +432,69 @@ impl<const N: usize> Shr<usize> for &GarbledInt<N> {
 // Implement ShrAssign for GarbledInt<N>
 impl<const N: usize> ShrAssign<usize> for GarbledInt<N> {
     fn shr_assign(&mut self, shift: usize) {
-        shift_bits_right::<N>(&mut self.bits, shift);
+        let mut bits = self.bits().into_owned();
+        shift_bits_right_arithmetic::<N>(&mut bits, shift);
+        *self = Self::new(bits);
     }
 }
 
 // Implement ShrAssign for &GarbledInt<N>
 impl<const N: usize> ShrAssign<usize> for &GarbledInt<N> {
     fn shr_assign(&mut self, shift: usize) {
-        let mut bits: Vec<bool> = self.bits.clone();
-        shift_bits_right::<N>(&mut bits, shift);
+        let mut bits: Vec<bool> = self.bits().into_owned();
+        shift_bits_right_arithmetic::<N>(&mut bits, shift);
+    }
+}
+
+// Rotations for GarbledUint<N>
+impl<const N: usize> GarbledUint<N> {
+    /// Rotates left by a public amount like the native `rotate_left`: bits shifted out at the
+    /// top come back in at the bottom. The amount is taken modulo `N`.
+    pub fn rotate_left(&self, n: usize) -> Self {
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        if N > 0 {
+            // bits are stored least-significant first, so a left rotation moves them up
+            bits.rotate_right(n % N);
+        }
+        GarbledUint::new(bits)
+    }
+
+    /// Rotates right by a public amount like the native `rotate_right`.
+    pub fn rotate_right(&self, n: usize) -> Self {
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        if N > 0 {
+            bits.rotate_left(n % N);
+        }
+        GarbledUint::new(bits)
+    }
+
+    /// Rotates left by a garbled amount, taken modulo `N`. The circuit is a barrel rotator
+    /// with one mux per bit for each bit of `amount`.
+    pub fn rotate_left_by<const M: usize>(&self, amount: &GarbledUint<M>) -> Self {
+        build_and_execute_rotate(self, amount, true)
+    }
+
+    /// Rotates right by a garbled amount, taken modulo `N`.
+    pub fn rotate_right_by<const M: usize>(&self, amount: &GarbledUint<M>) -> Self {
+        build_and_execute_rotate(self, amount, false)
+    }
+}
+
+// Zero counts for GarbledUint<N>
+impl<const N: usize> GarbledUint<N> {
+    /// Returns the number of zeros above the highest set bit, `N` for zero, as a `u32`-width
+    /// value like the native `leading_zeros`. The circuit is a binary search over the bits,
+    /// so only the count is revealed.
+    pub fn leading_zeros(&self) -> GarbledUint32 {
+        build_and_execute_zero_count(self, true)
+    }
+
+    /// Returns the number of zeros below the lowest set bit, `N` for zero, like the native
+    /// `trailing_zeros`.
+    pub fn trailing_zeros(&self) -> GarbledUint32 {
+        build_and_execute_zero_count(self, false)
     }
 }
 
@@ -427,3 +527,65 @@ impl<const N: usize> GarbledInt<N> {
         build_and_execute_xnor(&self.into(), &rhs.into()).into()
     }
 }
+
+// Non-panicking bitwise operations for GarbledUint<N>
+impl<const N: usize> GarbledUint<N> {
+    pub fn try_bitxor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        try_build_and_execute_xor(self, rhs)
+    }
+
+    pub fn try_bitand(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        try_build_and_execute_and(self, rhs)
+    }
+
+    pub fn try_bitor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        try_build_and_execute_or(self, rhs)
+    }
+
+    pub fn try_not(&self) -> Result<Self, CircuitError> {
+        try_build_and_execute_not(self)
+    }
+
+    pub fn try_nand(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        try_build_and_execute_nand(self, rhs)
+    }
+
+    pub fn try_nor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        try_build_and_execute_nor(self, rhs)
+    }
+
+    pub fn try_xnor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        try_build_and_execute_xnor(self, rhs)
+    }
+}
+
+// Non-panicking bitwise operations for GarbledInt<N>
+impl<const N: usize> GarbledInt<N> {
+    pub fn try_bitxor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_xor(&self.into(), &rhs.into())?.into())
+    }
+
+    pub fn try_bitand(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_and(&self.into(), &rhs.into())?.into())
+    }
+
+    pub fn try_bitor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_or(&self.into(), &rhs.into())?.into())
+    }
+
+    pub fn try_not(&self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_not(&self.into())?.into())
+    }
+
+    pub fn try_nand(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_nand(&self.into(), &rhs.into())?.into())
+    }
+
+    pub fn try_nor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_nor(&self.into(), &rhs.into())?.into())
+    }
+
+    pub fn try_xnor(&self, rhs: &Self) -> Result<Self, CircuitError> {
+        Ok(try_build_and_execute_xnor(&self.into(), &rhs.into())?.into())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::boolean::GarbledBool;
+use crate::operations::circuits::builder::{
+    build_and_execute_logic, build_and_execute_not, WRK17CircuitBuilder,
+};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+impl GarbledBool {
+    /// Logical AND, a single AND gate.
+    pub fn and(&self, rhs: &Self) -> Self {
+        build_and_execute_logic(self.bit(), rhs.bit(), WRK17CircuitBuilder::push_and).into()
+    }
+
+    /// Logical OR, computed as `(a ^ b) ^ (a & b)`.
+    pub fn or(&self, rhs: &Self) -> Self {
+        build_and_execute_logic(self.bit(), rhs.bit(), WRK17CircuitBuilder::push_or).into()
+    }
+
+    /// Logical XOR, a single XOR gate.
+    pub fn xor(&self, rhs: &Self) -> Self {
+        build_and_execute_logic(self.bit(), rhs.bit(), WRK17CircuitBuilder::push_xor).into()
+    }
+}
+
+// The operators delegate to the inherent methods, so each runs one small circuit
+macro_rules! impl_logic_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $logic:ident) => {
+        impl $trait for &GarbledBool {
+            type Output = GarbledBool;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                GarbledBool::$logic(self, rhs)
+            }
+        }
+
+        impl $trait for GarbledBool {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                GarbledBool::$logic(&self, &rhs)
+            }
+        }
+
+        impl $assign_trait for GarbledBool {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = GarbledBool::$logic(self, &rhs);
+            }
+        }
+
+        impl $assign_trait<&GarbledBool> for GarbledBool {
+            fn $assign_method(&mut self, rhs: &Self) {
+                *self = GarbledBool::$logic(self, rhs);
+            }
+        }
+    };
+}
+
+impl_logic_op!(BitAnd, bitand, BitAndAssign, bitand_assign, and);
+impl_logic_op!(BitOr, bitor, BitOrAssign, bitor_assign, or);
+impl_logic_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, xor);
+
+// Logical NOT, a single NOT gate
+impl Not for &GarbledBool {
+    type Output = GarbledBool;
+
+    fn not(self) -> Self::Output {
+        build_and_execute_not(self.bit()).into()
+    }
+}
+
+impl Not for GarbledBool {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
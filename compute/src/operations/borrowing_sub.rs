@@ -0,0 +1,76 @@
+//! Subtraction with an explicit borrow, assembled gate by gate.
+//!
+//! Most operations call a gadget of [`WRK17CircuitBuilder`], but circuits can also be composed
+//! directly from single gates through its public API. [`GarbledUint::borrowing_sub`] is written
+//! that way as a worked example: each bit is a full subtractor made of XOR, AND and NOT gates.
+
+use crate::executor::get_executor;
+use crate::operations::circuits::builder::WRK17CircuitBuilder;
+use crate::operations::circuits::types::GateIndexVec;
+use crate::uint::{GarbledBit, GarbledUint};
+
+impl<const N: usize> GarbledUint<N> {
+    /// Returns `self - other - borrow_in` and the borrow out of the top bit, like
+    /// `u8::borrowing_sub`.
+    ///
+    /// Chaining the borrow subtracts values split into limbs, least-significant limb first:
+    ///
+    /// ```
+    /// use compute::prelude::*;
+    ///
+    /// // 0x1200 - 0x0034 as two 8-bit limbs
+    /// let (low, borrow) = GarbledUint8::from(0x00_u8)
+    ///     .borrowing_sub(&GarbledUint8::from(0x34_u8), &false.into());
+    /// let (high, borrow) = GarbledUint8::from(0x12_u8)
+    ///     .borrowing_sub(&GarbledUint8::from(0x00_u8), &borrow);
+    ///
+    /// assert_eq!((u8::from(high), u8::from(low)), (0x11, 0xcc));
+    /// assert!(!bool::from(borrow));
+    /// ```
+    pub fn borrowing_sub(
+        &self,
+        other: &Self,
+        borrow_in: &GarbledBit,
+    ) -> (GarbledUint<N>, GarbledBit) {
+        let mut builder = WRK17CircuitBuilder::default();
+
+        // every input is declared before the first gate
+        let a = builder.input(&padded(self));
+        let b = builder.input(&padded(other));
+        let borrow_in = builder.input(&padded(borrow_in));
+
+        let mut output = GateIndexVec::with_capacity(N + 1);
+        let mut borrow = borrow_in[0];
+        for i in 0..N {
+            // difference bit: a ^ b ^ borrow
+            let a_xor_b = builder.push_xor(&a[i], &b[i]);
+            output.push(builder.push_xor(&a_xor_b, &borrow));
+
+            // borrow out: (!a & b) | (a == b & borrow); the two terms are never both set,
+            // so the OR can be an XOR, which costs no AND gate
+            let not_a = builder.push_not(&a[i]);
+            let not_a_and_b = builder.push_and(&not_a, &b[i]);
+            let a_eq_b = builder.push_not(&a_xor_b);
+            let borrow_through = builder.push_and(&a_eq_b, &borrow);
+            borrow = builder.push_xor(&not_a_and_b, &borrow_through);
+        }
+        output.push(borrow);
+
+        let circuit = builder.compile(&output);
+        let result = get_executor()
+            .execute(&circuit, builder.inputs(), &[])
+            .expect("Failed to execute borrowing subtraction circuit");
+
+        (
+            GarbledUint::new(result[..N].to_vec()),
+            GarbledUint::new(vec![result[N]]),
+        )
+    }
+}
+
+// `zero()` and `one()` store a single bit, so pad to the full width before declaring inputs
+fn padded<const N: usize>(value: &GarbledUint<N>) -> GarbledUint<N> {
+    let mut bits = value.bits().into_owned();
+    bits.resize(N, false);
+    GarbledUint::new(bits)
+}
@@ -0,0 +1,35 @@
+use crate::bytes::GarbledBytes;
+use crate::operations::circuits::builder::{
+    build_and_execute_bytes_predicate, build_and_execute_bytes_xor,
+};
+use crate::uint::GarbledBit;
+use std::ops::BitXor;
+
+impl<const N: usize> GarbledBytes<N> {
+    /// Returns whether both buffers hold the same bytes, compared in a single circuit.
+    pub fn equals(&self, other: &Self) -> GarbledBit {
+        if N == 0 {
+            return true.into();
+        }
+        build_and_execute_bytes_predicate(self.as_bytes(), other.as_bytes(), |builder, a, b| {
+            builder.bytes_eq(a, b)
+        })
+    }
+}
+
+// XORs every byte in one circuit execution, e.g. to apply a one-time pad or combine key shares
+impl<const N: usize> BitXor for &GarbledBytes<N> {
+    type Output = GarbledBytes<N>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        GarbledBytes::new(build_and_execute_bytes_xor(self.as_bytes(), rhs.as_bytes()))
+    }
+}
+
+impl<const N: usize> BitXor for GarbledBytes<N> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        &self ^ &rhs
+    }
+}
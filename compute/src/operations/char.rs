@@ -0,0 +1,23 @@
+use crate::boolean::GarbledBool;
+use crate::char::GarbledChar;
+use crate::operations::circuits::builder::{
+    build_and_execute_byte_map, build_and_execute_byte_predicate, WRK17CircuitBuilder,
+};
+
+impl GarbledChar {
+    /// Returns whether the character is an ASCII digit `0-9`, like `char::is_ascii_digit`.
+    pub fn is_digit(&self) -> GarbledBool {
+        build_and_execute_byte_predicate(self.byte(), WRK17CircuitBuilder::ascii_is_digit).into()
+    }
+
+    /// Returns whether the character is an ASCII letter, like `char::is_ascii_alphabetic`.
+    pub fn is_alpha(&self) -> GarbledBool {
+        build_and_execute_byte_predicate(self.byte(), WRK17CircuitBuilder::ascii_is_alpha).into()
+    }
+
+    /// Maps `A-Z` to `a-z` and leaves every other character as it is, like
+    /// `char::to_ascii_lowercase`.
+    pub fn to_lowercase(&self) -> Self {
+        build_and_execute_byte_map(self.byte(), WRK17CircuitBuilder::ascii_lowercase).into()
+    }
+}
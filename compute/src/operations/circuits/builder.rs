@@ -1,17 +1,81 @@
+use crate::error::CircuitError;
 use crate::operations::circuits::traits::CircuitExecutor;
 use crate::operations::circuits::types::GateIndexVec;
-use crate::uint::GarbledUint;
-use crate::{executor::get_executor, uint::GarbledBoolean};
+use crate::uint::{GarbledUint, GarbledUint8};
+use crate::{executor::get_executor, uint::GarbledBit};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use tandem::{Circuit, Gate};
 
 pub type GateIndex = u32;
 
+/// Outcome wires of one shared comparator; exactly one of them is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComparisonWires {
+    pub lt: GateIndex,
+    pub eq: GateIndex,
+    pub gt: GateIndex,
+}
+
+/// Adder circuit used for `add` and everything built on it, such as `mul`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Adder {
+    /// Ripple-carry adder: the fewest gates, but AND-depth grows linearly with the width.
+    #[default]
+    RippleCarry,
+    /// Sklansky parallel-prefix carry-lookahead adder: about `log2(n)` AND-depth at the cost
+    /// of roughly `n * log2(n) / 2` extra AND gates, with some carries fanning out to half the
+    /// bits.
+    CarryLookahead,
+    /// Kogge-Stone parallel-prefix adder: `log2(n)` AND-depth with every carry feeding at most
+    /// two combines, at the cost of about twice the extra AND gates of `CarryLookahead`.
+    KoggeStone,
+    /// Brent-Kung parallel-prefix adder: about `2 * log2(n)` AND-depth for only about `2 * n`
+    /// extra AND gates, between ripple-carry and the other prefix adders.
+    BrentKung,
+}
+
+/// Multiplier circuit used for `mul`, `widening_mul` and everything built on them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplier {
+    /// Shift-and-add: the partial products are added one row after another, so AND-depth
+    /// grows with the width times the depth of the adder.
+    #[default]
+    ShiftAndAdd,
+    /// Wallace tree: carry-save adders reduce the partial products three rows to two until a
+    /// single add is left, for about `log1.5(n)` levels of AND-depth before that adder. Partial
+    /// product bits that are always zero are never built, so it also needs fewer AND gates.
+    Wallace,
+    /// Karatsuba: operands wider than `threshold` bits are split in halves and the product is
+    /// rebuilt from three half-width products instead of four, recursing until the halves are
+    /// at most `threshold` bits (and never below 4), which use the Wallace tree. The extra
+    /// adders only pay off for wide operands; see [`Multiplier::karatsuba`].
+    Karatsuba { threshold: usize },
+}
+
+impl Multiplier {
+    /// Karatsuba with a crossover of 24 bits, which gives about the fewest AND gates for
+    /// 64- and 128-bit operands.
+    pub const fn karatsuba() -> Self {
+        Multiplier::Karatsuba { threshold: 24 }
+    }
+}
+
 #[derive(Default)]
 pub struct WRK17CircuitBuilder {
     inputs: Vec<bool>,
     gates: Vec<Gate>,
+    // shared 0 and 1 wires every public constant is built from
+    constant_wires: Option<(GateIndex, GateIndex)>,
+    adder: Adder,
+    multiplier: Multiplier,
+    // overflow bits recorded by checked arithmetic, combined by `overflowed`
+    overflow_flags: Vec<GateIndex>,
+    // wires of inputs declared with `keyed_input`, with the bits they were declared with
+    keyed_inputs: HashMap<String, (Vec<bool>, GateIndexVec)>,
+    // number of input wires the evaluator supplies at execution; only their positions are known
+    evaluator_inputs: usize,
 }
 
 impl Debug for WRK17CircuitBuilder {
@@ -32,13 +96,41 @@ impl Debug for WRK17CircuitBuilder {
 }
 
 impl WRK17CircuitBuilder {
+    /// Selects the adder used by `add`; both produce identical results.
+    pub fn with_adder(mut self, adder: Adder) -> Self {
+        self.adder = adder;
+        self
+    }
+
+    /// Selects the multiplier used by `mul` and `widening_mul`; both produce identical results.
+    pub fn with_multiplier(mut self, multiplier: Multiplier) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
     pub fn input<const R: usize>(&mut self, input: &GarbledUint<R>) -> GateIndexVec {
+        self.input_bits(&input.bits())
+    }
+
+    /// Adds one input wire per bit of `bits`, in order.
+    pub fn input_bits(&mut self, bits: &[bool]) -> GateIndexVec {
         // get the cumulative size of all inputs in input_labels
         //let input_offset = self.input_labels.iter().map(|x| x.len()).sum::<usize>();
 
+        // inputs are prepended to the gate list, which would shift the constant wires
+        assert!(
+            self.constant_wires.is_none(),
+            "inputs must be added before any constant"
+        );
+        // ... and the evaluator's input wires
+        assert!(
+            self.evaluator_inputs == 0,
+            "garbler inputs must be added before evaluator inputs"
+        );
+
         let input_offset = self.inputs.len();
         let mut input_label = GateIndexVec::default();
-        for (i, bool_value) in input.bits.iter().enumerate() {
+        for (i, bool_value) in bits.iter().enumerate() {
             self.gates.insert(0, Gate::InContrib);
 
             self.inputs.push(*bool_value);
@@ -47,6 +139,91 @@ impl WRK17CircuitBuilder {
         input_label
     }
 
+    /// Adds `width` input wires that the evaluator supplies when the circuit runs. Only their
+    /// positions are recorded; the bits are passed to
+    /// [`WRK17CircuitBuilder::execute_with_evaluator_inputs`] in the order the wires were added.
+    pub fn evaluator_input_bits(&mut self, width: usize) -> GateIndexVec {
+        assert!(
+            self.constant_wires.is_none(),
+            "inputs must be added before any constant"
+        );
+
+        // evaluator inputs follow the garbler's, ahead of every other gate
+        let input_offset = self.inputs.len() + self.evaluator_inputs;
+        let input_label: Vec<GateIndex> = (input_offset..input_offset + width)
+            .map(|index| {
+                self.gates.insert(index, Gate::InEval);
+                index as GateIndex
+            })
+            .collect();
+        self.evaluator_inputs += width;
+        input_label.into()
+    }
+
+    /// The number of input wires added with [`WRK17CircuitBuilder::evaluator_input_bits`].
+    pub fn evaluator_inputs(&self) -> usize {
+        self.evaluator_inputs
+    }
+
+    /// Like [`WRK17CircuitBuilder::input`], but declares the input wires only once per `key`,
+    /// e.g. the source variable name. Later calls with the same key return the same wires, so
+    /// a value used several times is sent over the protocol once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` was already declared with a different value.
+    pub fn keyed_input<const R: usize>(
+        &mut self,
+        key: &str,
+        input: &GarbledUint<R>,
+    ) -> GateIndexVec {
+        if let Some((bits, wires)) = self.keyed_inputs.get(key) {
+            assert_eq!(
+                bits.as_slice(),
+                &*input.bits(),
+                "input `{}` was already declared with a different value",
+                key
+            );
+            return wires.clone();
+        }
+
+        let wires = self.input(input);
+        self.keyed_inputs
+            .insert(key.to_string(), (input.bits().into_owned(), wires.clone()));
+        wires
+    }
+
+    /// Returns wires carrying the public constant `value` without adding any inputs.
+    ///
+    /// All constants share a single 0 wire (`w ^ w` of the first input) and its negation, so
+    /// they add at most two gates per circuit. Constants must be created after all inputs.
+    pub fn constant<const R: usize>(&mut self, value: &GarbledUint<R>) -> GateIndexVec {
+        let (zero, one) = self.constant_wires();
+        let wires: Vec<GateIndex> = (0..R)
+            .map(|i| match value.stored_bit(i) {
+                Some(true) => one,
+                _ => zero,
+            })
+            .collect();
+        wires.into()
+    }
+
+    pub(crate) fn constant_wires(&mut self) -> (GateIndex, GateIndex) {
+        if let Some(wires) = self.constant_wires {
+            return wires;
+        }
+
+        // wire 0 is the first input, the garbler's or else the evaluator's
+        assert!(
+            !self.inputs.is_empty() || self.evaluator_inputs > 0,
+            "constants are derived from an input wire, so add an input first"
+        );
+        let zero = self.push_xor(&0, &0);
+        let one = self.push_not(&zero);
+        self.constant_wires = Some((zero, one));
+        (zero, one)
+    }
+
     pub fn len(&self) -> GateIndex {
         self.gates.len() as u32
     }
@@ -66,7 +243,7 @@ impl WRK17CircuitBuilder {
         xor_index
     }
 
-    // Add an Aa.len()D gate between two inputs and return the index
+    // Add an AND gate between two inputs and return the index
     pub fn push_and(&mut self, a: &GateIndex, b: &GateIndex) -> GateIndex {
         let and_index = self.gates.len() as u32;
         self.gates.push(Gate::And(*a, *b));
@@ -87,7 +264,7 @@ impl WRK17CircuitBuilder {
         self.push_xor(&xor_gate, &and_gate)
     }
 
-    // Add a a.len()Aa.len()D gate: a.len()Aa.len()D(a, b) = a.len()OT(a & b)
+    // Add a NAND gate: NAND(a, b) = NOT(a & b)
     pub fn push_nand(&mut self, a: &GateIndex, b: &GateIndex) -> GateIndex {
         let and_gate = self.push_and(a, b);
         self.push_not(&and_gate)
@@ -98,7 +275,7 @@ impl WRK17CircuitBuilder {
         self.push_not(&or_gate)
     }
 
-    // Add an Xa.len()OR gate: Xa.len()OR(a, b) = a.len()OT(a ⊕ b)
+    // Add an XNOR gate: XNOR(a, b) = NOT(a ⊕ b)
     pub fn push_xnor(&mut self, a: &GateIndex, b: &GateIndex) -> GateIndex {
         let xor_gate = self.push_xor(a, b);
         self.push_not(&xor_gate)
@@ -125,471 +302,2847 @@ impl WRK17CircuitBuilder {
         self.push_or(&and_a_not_s, &and_b_s)
     }
 
-    fn div_inner(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> (GateIndexVec, GateIndexVec) {
-        let n = a.len();
-        let mut quotient = GateIndexVec::default();
-        let mut remainder = GateIndexVec::default();
+    // Two's complement order equals unsigned order once both sign bits are flipped,
+    // so signed comparisons reuse the unsigned comparator on the adjusted operands
+    fn flip_sign_bit(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let msb = self.push_not(&a[a.len() - 1]);
+        let mut flipped = a.clone();
+        flipped.truncate(a.len() - 1);
+        flipped.push(msb);
+        flipped
+    }
 
-        // Initialize remainder with 0
-        for _ in 0..n {
-            remainder.push(GateIndex::default()); // Zero initialize
-        }
+    pub fn signed_compare(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> (GateIndex, GateIndex) {
+        let a = self.flip_sign_bit(a);
+        let b = self.flip_sign_bit(b);
+        self.compare(&a, &b)
+    }
 
-        // Iterate through each bit, starting from the most significant
-        for i in (0..n).rev() {
-            // Shift remainder left by 1 (equivalent to adding a bit)
-            remainder.insert(0, a[i]);
-            if remainder.len() > n {
-                remainder.truncate(n); // Ensure remainder does not exceed bit width
-            }
+    pub fn signed_lt(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let (lt, _eq) = self.signed_compare(a, b);
+        lt
+    }
+
+    pub fn signed_gt(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let (lt, eq) = self.signed_compare(a, b);
+        let or_gate = self.push_or(&lt, &eq);
+        self.push_not(&or_gate)
+    }
 
-            // Check if remainder is greater than or equal to divisor
-            let greater_or_equal = self.ge(&remainder, b);
+    pub fn signed_ge(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let lt = self.signed_lt(a, b);
+        self.push_not(&lt)
+    }
 
-            // If remainder is greater than or equal to divisor, set quotient bit to 1 and subtract divisor from remainder
-            if greater_or_equal != GateIndex::default() {
-                // Subtract divisor from remainder if it’s greater than or equal
-                let new_remainder = self.sub(&remainder, b);
-                remainder = self.mux(&greater_or_equal, &new_remainder, &remainder);
+    pub fn signed_le(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let gt = self.signed_gt(a, b);
+        self.push_not(&gt)
+    }
 
-                // Set quotient bit to 1
-                quotient.insert(0, greater_or_equal);
+    /// Tests `a` for equality with a public constant given LSB first.
+    ///
+    /// Each constant bit decides at build time whether `a[i]` or its negation joins the AND
+    /// chain, so this needs no constant inputs and only `a.len() - 1` AND gates. Constant bits
+    /// beyond `a.len()` are ignored, so callers must range-check the constant first.
+    pub fn eq_const(&mut self, a: &GateIndexVec, value: &[bool]) -> GateIndex {
+        let mut eq: Option<GateIndex> = None;
+        for i in (0..a.len()).rev() {
+            let bit_eq = if value.get(i).copied().unwrap_or(false) {
+                a[i]
             } else {
-                // Set quotient bit to 0
-                quotient.insert(0, GateIndex::default());
-            }
+                self.push_not(&a[i])
+            };
+            eq = Some(match eq {
+                Some(eq) => self.push_and(&eq, &bit_eq),
+                None => bit_eq,
+            });
+        }
+
+        match eq {
+            Some(eq) => eq,
+            None => self.constant_wires().1,
+        }
+    }
 
-            if quotient.len() > n {
-                quotient.truncate(n); // Ensure quotient does not exceed bit width
+    /// Returns a wire that is set when `a` equals any of the public constants in `values`, each
+    /// given LSB first. Every constant gets its own [`WRK17CircuitBuilder::eq_const`] chain and
+    /// the results are ORed; an empty set yields the constant false.
+    pub fn is_one_of(&mut self, a: &GateIndexVec, values: &[Vec<bool>]) -> GateIndex {
+        let matches: Vec<GateIndex> = values.iter().map(|value| self.eq_const(a, value)).collect();
+        self.any(&matches)
+    }
+
+    /// Compares `a` against a public constant given LSB first, returning `(a < value, a == value)`.
+    ///
+    /// Like [`WRK17CircuitBuilder::eq_const`], bits of the constant are folded in at build time:
+    /// a 0 bit can never make `a` smaller, so only the 1 bits extend the less-than chain.
+    pub fn compare_const(&mut self, a: &GateIndexVec, value: &[bool]) -> (GateIndex, GateIndex) {
+        // `None` stands for a known result: all higher bits are equal and `a` is not smaller
+        let mut eq: Option<GateIndex> = None;
+        let mut lt: Option<GateIndex> = None;
+
+        for i in (0..a.len()).rev() {
+            let (bit_eq, below) = if value.get(i).copied().unwrap_or(false) {
+                let below = self.push_not(&a[i]);
+                let below = match eq {
+                    Some(eq) => self.push_and(&eq, &below),
+                    None => below,
+                };
+                (a[i], Some(below))
+            } else {
+                (self.push_not(&a[i]), None)
+            };
+
+            if let Some(below) = below {
+                lt = Some(match lt {
+                    Some(lt) => self.push_or(&lt, &below),
+                    None => below,
+                });
             }
+            eq = Some(match eq {
+                Some(eq) => self.push_and(&eq, &bit_eq),
+                None => bit_eq,
+            });
         }
 
-        (quotient, remainder)
+        let lt = match lt {
+            Some(lt) => lt,
+            None => self.constant_wires().0,
+        };
+        let eq = match eq {
+            Some(eq) => eq,
+            None => self.constant_wires().1,
+        };
+        (lt, eq)
     }
 
-    pub fn compile(&self, output_indices: &GateIndexVec) -> Circuit {
-        Circuit::new(self.gates.clone(), output_indices.clone().into())
+    /// Signed variant of [`WRK17CircuitBuilder::compare_const`]; `value` holds the constant's
+    /// `a.len()` two's complement bits.
+    pub fn signed_compare_const(
+        &mut self,
+        a: &GateIndexVec,
+        value: &[bool],
+    ) -> (GateIndex, GateIndex) {
+        let a = self.flip_sign_bit(a);
+        let mut value = value[..a.len()].to_vec();
+        let sign = a.len() - 1;
+        value[sign] = !value[sign];
+        self.compare_const(&a, &value)
     }
 
-    pub fn execute<const N: usize>(&self, circuit: &Circuit) -> anyhow::Result<GarbledUint<N>> {
-        let result = get_executor().execute(circuit, &self.inputs, &[])?;
-        Ok(GarbledUint::new(result))
+    /// Computes `a < b`, `a == b` and `a > b` from a single comparator. `lt` and `eq` are never
+    /// both set, so `gt` is their XNOR and adds no AND gates.
+    pub fn comparison(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> ComparisonWires {
+        let (lt, eq) = self.compare(a, b);
+        let gt = self.push_xnor(&lt, &eq);
+        ComparisonWires { lt, eq, gt }
     }
 
-    // Simulate the circuit using the provided input values
-    pub fn compile_and_execute<const N: usize>(
-        &self,
-        output_indices: &GateIndexVec,
-    ) -> anyhow::Result<GarbledUint<N>> {
-        let circuit = self.compile(output_indices);
-        let result = get_executor().execute(&circuit, &self.inputs, &[])?;
-        Ok(GarbledUint::new(result))
+    /// Signed variant of [`WRK17CircuitBuilder::comparison`].
+    pub fn signed_comparison(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> ComparisonWires {
+        let (lt, eq) = self.signed_compare(a, b);
+        let gt = self.push_xnor(&lt, &eq);
+        ComparisonWires { lt, eq, gt }
     }
-}
 
-impl CircuitExecutor for WRK17CircuitBuilder {
-    type Type = GateIndex;
-    type TypeVec = GateIndexVec;
+    /// Picks the smaller of `a` and `b` from an existing comparison of `a` against `b`,
+    /// adding only a mux.
+    pub fn select_min(
+        &mut self,
+        comparison: &ComparisonWires,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> GateIndexVec {
+        self.mux(&comparison.lt, a, b)
+    }
 
-    fn xor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let xor = self.push_xor(&a[i], &b[i]);
-            output.push(xor);
-        }
-        output
+    /// Picks the larger of `a` and `b` from an existing comparison of `a` against `b`,
+    /// adding only a mux.
+    pub fn select_max(
+        &mut self,
+        comparison: &ComparisonWires,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> GateIndexVec {
+        self.mux(&comparison.lt, b, a)
     }
 
-    fn and(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let and = self.push_and(&a[i], &b[i]);
-            output.push(and);
-        }
-        output
+    pub fn max(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let a_lt_b = self.lt(a, b);
+        self.mux(&a_lt_b, b, a)
     }
 
-    fn land(&mut self, a: &GateIndex, b: &GateIndex) -> GateIndex {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        let and = self.push_and(a, b);
-        output.push(and);
-        output.into()
+    pub fn min(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let b_lt_a = self.lt(b, a);
+        self.mux(&b_lt_a, b, a)
     }
 
-    fn not(&mut self, a: &GateIndexVec) -> GateIndexVec {
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let not_gate = self.push_not(&a[i]);
-            output.push(not_gate);
-        }
-        output
+    // Both differences are always computed and the comparison only drives a mux, so the
+    // circuit is the same whichever operand is larger
+    pub fn abs_diff(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let a_lt_b = self.lt(a, b);
+        let a_minus_b = self.sub(a, b);
+        let b_minus_a = self.sub(b, a);
+        self.mux(&a_lt_b, &b_minus_a, &a_minus_b)
     }
 
-    fn or(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let or_gate = self.push_or(&a[i], &b[i]);
-            output.push(or_gate);
-        }
-        output
+    pub fn signed_max(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let a_lt_b = self.signed_lt(a, b);
+        self.mux(&a_lt_b, b, a)
     }
 
-    fn lor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let output = self.or(a, b);
-        output.into()
+    pub fn signed_min(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let b_lt_a = self.signed_lt(b, a);
+        self.mux(&b_lt_a, b, a)
     }
 
-    fn nand(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let nand = self.push_nand(&a[i], &b[i]);
-            output.push(nand);
-        }
-        output
+    // max(value, lo) followed by min(.., hi), so hi wins when lo > hi
+    pub fn clamp(
+        &mut self,
+        value: &GateIndexVec,
+        lo: &GateIndexVec,
+        hi: &GateIndexVec,
+    ) -> GateIndexVec {
+        let at_least_lo = self.max(value, lo);
+        self.min(&at_least_lo, hi)
     }
 
-    fn nor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let nor = self.push_nor(&a[i], &b[i]);
-            output.push(nor);
-        }
-        output
+    pub fn signed_clamp(
+        &mut self,
+        value: &GateIndexVec,
+        lo: &GateIndexVec,
+        hi: &GateIndexVec,
+    ) -> GateIndexVec {
+        let at_least_lo = self.signed_max(value, lo);
+        self.signed_min(&at_least_lo, hi)
     }
 
-    fn xnor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let xnor = self.push_xnor(&a[i], &b[i]);
-            output.push(xnor);
+    // Restoring division, one quotient bit per dividend bit from the most significant. Each
+    // step shifts the next dividend bit into the partial remainder and subtracts the divisor one
+    // bit wider, so the top bit of the difference is the borrow: the divisor fits exactly when
+    // it is clear, which makes it the quotient bit and selects the difference as the new
+    // remainder. Either way the remainder stays below the divisor, so `n` bits hold it.
+    // Dividing by zero gives an all-ones quotient and the dividend as remainder.
+    fn div_inner(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> (GateIndexVec, GateIndexVec) {
+        let n = a.len();
+        if n == 0 {
+            return (GateIndexVec::default(), GateIndexVec::default());
         }
-        output
+
+        let (zero, _) = self.constant_wires();
+        let divisor = zero_extend(b, n + 1, zero);
+        let mut remainder = vec![zero; n];
+        let mut quotient = vec![zero; n];
+
+        for i in (0..n).rev() {
+            let mut shifted = remainder.clone();
+            shifted.insert(0, a[i]);
+            let shifted: GateIndexVec = shifted.into();
+
+            let difference = self.sub(&shifted, &divisor);
+            let fits = self.push_not(&difference[n]);
+            quotient[i] = fits;
+
+            // The shifted value only reaches bit n when the divisor fits, so the low n bits
+            // of both candidates are enough
+            let low = |bits: &GateIndexVec| -> GateIndexVec {
+                bits.iter().take(n).copied().collect::<Vec<_>>().into()
+            };
+            remainder = self
+                .mux(&fits, &low(&difference), &low(&shifted))
+                .iter()
+                .copied()
+                .collect();
+        }
+
+        (quotient.into(), remainder.into())
     }
 
-    fn mux(&mut self, s: &GateIndex, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        // repeat with output_indices
-        let mut output = GateIndexVec::default();
-        for i in 0..a.len() {
-            let mux = self.push_mux(s, &b[i], &a[i]);
-            output.push(mux);
+    // Fixed-point product of two n-bit two's complement values with frac fraction bits. Both
+    // operands are sign-extended to 2n bits so the low 2n bits of the product are the exact
+    // signed product, then bits frac..frac + n are kept: an arithmetic shift right, rounding
+    // toward negative infinity.
+    pub fn fixed_mul(&mut self, a: &GateIndexVec, b: &GateIndexVec, frac: usize) -> GateIndexVec {
+        let n = a.len();
+        let a = sign_extend(a, 2 * n);
+        let b = sign_extend(b, 2 * n);
+        let product = self.mul(&a, &b);
+        product
+            .iter()
+            .skip(frac)
+            .take(n)
+            .copied()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    // Fixed-point quotient of two n-bit two's complement values with frac fraction bits:
+    // (|a| << frac) / |b| on n + frac bit magnitudes, negated when the signs differ, so it
+    // rounds toward zero. Division by zero yields an unspecified value.
+    pub fn fixed_div(&mut self, a: &GateIndexVec, b: &GateIndexVec, frac: usize) -> GateIndexVec {
+        let n = a.len();
+        let negative = self.push_xor(&a[n - 1], &b[n - 1]);
+        let a = self.abs(a);
+        let b = self.abs(b);
+
+        let (zero, _) = self.constant_wires();
+        let mut numerator = GateIndexVec::new(vec![zero; frac]);
+        numerator.push_all(&a);
+        let denominator = zero_extend(&b, n + frac, zero);
+        let quotient = self.div(&numerator, &denominator);
+
+        let quotient: GateIndexVec = quotient.iter().take(n).copied().collect::<Vec<_>>().into();
+        self.negate_if(&negative, &quotient)
+    }
+
+    // Binary (Stein's) GCD unrolled to a fixed 2 * N iterations so the trip count is public.
+    // Every step updates the operands obliviously through MUX gates.
+    pub fn gcd(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let n = a.len();
+
+        // Constant wires: x ^ x is always 0 and its negation is always 1
+        let zero = self.push_xor(&a[0], &a[0]);
+        let one = self.push_not(&zero);
+        let zeros = GateIndexVec::new(vec![zero; n]);
+
+        let mut a = a.clone();
+        let mut b = b.clone();
+
+        // Power of two accumulating the common factors of 2 that were shifted out
+        let mut scale_bits = vec![zero; n];
+        scale_bits[0] = one;
+        let mut scale = GateIndexVec::new(scale_bits);
+
+        for _ in 0..2 * n {
+            let a_zero = self.eq(&a, &zeros);
+            let b_zero = self.eq(&b, &zeros);
+            let done = self.push_or(&a_zero, &b_zero);
+            let not_done = self.push_not(&done);
+
+            let a_even = self.push_not(&a[0]);
+            let b_even = self.push_not(&b[0]);
+            let shift_a = self.push_and(&a_even, &not_done);
+            let shift_b = self.push_and(&b_even, &not_done);
+            let shift_both = self.push_and(&shift_a, &shift_b);
+
+            // Both odd: replace the larger operand with half their difference
+            let odd_pair = self.push_and(&a[0], &b[0]);
+            let subtract = self.push_and(&odd_pair, &not_done);
+            let a_ge_b = self.ge(&a, &b);
+            let diff_ab = self.sub(&a, &b);
+            let diff_ba = self.sub(&b, &a);
+            let half_diff_ab = shift_right_one(&diff_ab, zero);
+            let half_diff_ba = shift_right_one(&diff_ba, zero);
+            let odd_a = self.mux(&a_ge_b, &half_diff_ab, &a);
+            let odd_b = self.mux(&a_ge_b, &b, &half_diff_ba);
+
+            let half_a = shift_right_one(&a, zero);
+            let half_b = shift_right_one(&b, zero);
+            let keep_a = self.mux(&subtract, &odd_a, &a);
+            let keep_b = self.mux(&subtract, &odd_b, &b);
+            a = self.mux(&shift_a, &half_a, &keep_a);
+            b = self.mux(&shift_b, &half_b, &keep_b);
+
+            let double_scale = shift_left_one(&scale, zero);
+            scale = self.mux(&shift_both, &double_scale, &scale);
         }
-        output
+
+        // One operand is now zero, so OR-ing them yields the odd part of the GCD
+        let odd_part = self.or(&a, &b);
+        self.mul(&odd_part, &scale)
     }
 
-    fn add(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        let mut carry = None;
-        let mut output_indices = GateIndexVec::default();
-        for i in 0..a.len() {
-            let (sum, new_carry) = full_adder(self, a[i], b[i], carry);
-            output_indices.push(sum);
-            carry = new_carry;
+    // (a * b) mod m, reducing the full double-width product so nothing overflows before the
+    // reduction
+    pub fn mul_mod(
+        &mut self,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+        m: &GateIndexVec,
+    ) -> GateIndexVec {
+        let n = a.len();
+        let zero = self.push_xor(&a[0], &a[0]);
+
+        let product = self.widening_mul(a, b);
+        let mut reduced = self.rem(&product, &zero_extend(m, 2 * n, zero));
+        reduced.truncate(n);
+        reduced
+    }
+
+    // Right-to-left square-and-multiply unrolled over all N exponent bits, so the trip count
+    // does not depend on the exponent. A MUX decides whether each step multiplies.
+    pub fn pow_mod(
+        &mut self,
+        base: &GateIndexVec,
+        exponent: &GateIndexVec,
+        modulus: &GateIndexVec,
+    ) -> GateIndexVec {
+        let n = base.len();
+
+        // Constant wires: x ^ x is always 0 and its negation is always 1
+        let zero = self.push_xor(&base[0], &base[0]);
+        let one = self.push_not(&zero);
+        let mut one_bits = vec![zero; n];
+        one_bits[0] = one;
+
+        // Start from 1 mod m so that modulus 1 yields 0
+        let mut result = self.rem(&GateIndexVec::new(one_bits), modulus);
+        let mut power = self.rem(base, modulus);
+
+        for i in 0..n {
+            let multiplied = self.mul_mod(&result, &power, modulus);
+            result = self.mux(&exponent[i], &multiplied, &result);
+            power = self.mul_mod(&power, &power, modulus);
         }
-        output_indices
+        result
     }
 
-    fn sub(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        let mut borrow = None;
-        let mut output_indices = GateIndexVec::default();
+    // Swaps a and b when s is set. Each bit costs one AND gate: d = (a ^ b) & s, then a ^ d, b ^ d.
+    pub fn conditional_swap(
+        &mut self,
+        s: &GateIndex,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> (GateIndexVec, GateIndexVec) {
+        let mut swapped_a = GateIndexVec::with_capacity(a.len());
+        let mut swapped_b = GateIndexVec::with_capacity(b.len());
         for i in 0..a.len() {
-            let (diff, new_borrow) = full_subtractor(self, &a[i], &b[i], &borrow);
-            output_indices.push(diff);
-            borrow = new_borrow;
+            let diff = self.push_xor(&a[i], &b[i]);
+            let d = self.push_and(&diff, s);
+            swapped_a.push(self.push_xor(&a[i], &d));
+            swapped_b.push(self.push_xor(&b[i], &d));
         }
-        output_indices
+        (swapped_a, swapped_b)
     }
 
-    fn mul(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        let mut partial_products: Vec<GateIndexVec> = Vec::with_capacity(a.len());
+    /// Adds like `add` and also returns the carry out of the top bit, which is set when the
+    /// unsigned sum does not fit.
+    pub fn add_with_carry(
+        &mut self,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> (GateIndexVec, GateIndex) {
+        let (zero, _) = self.constant_wires();
+        let width = a.len();
+        let mut sum = self.add(
+            &zero_extend(a, width + 1, zero),
+            &zero_extend(b, width + 1, zero),
+        );
+        let carry = sum[width];
+        sum.truncate(width);
+        (sum, carry)
+    }
 
-        // Generate partial products
-        for i in 0..a.len() {
-            let shifted_product = partial_product_shift(self, a, b, i);
-            partial_products.push(shifted_product);
-        }
+    /// Subtracts like `sub` and also returns the borrow out of the top bit, which is set when
+    /// `a < b` as unsigned values.
+    pub fn sub_with_borrow(
+        &mut self,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> (GateIndexVec, GateIndex) {
+        let (zero, _) = self.constant_wires();
+        let width = a.len();
+        let mut difference = self.sub(
+            &zero_extend(a, width + 1, zero),
+            &zero_extend(b, width + 1, zero),
+        );
+        let borrow = difference[width];
+        difference.truncate(width);
+        (difference, borrow)
+    }
 
-        // Sum up all partial products
-        let mut result = partial_products[0].clone();
-        for partial_product in partial_products.iter().take(a.len()).skip(1) {
-            result = self.add(&result, partial_product);
+    /// Returns the unsigned quotient and remainder of `a / b` from a single divider, so asking
+    /// for both costs no more gates than either. Dividing by zero gives an all-ones quotient and
+    /// `a` as remainder.
+    pub fn div_rem(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> (GateIndexVec, GateIndexVec) {
+        self.div_inner(a, b)
+    }
+
+    /// Signed variant of [`WRK17CircuitBuilder::div_rem`] with the semantics of
+    /// `iN::wrapping_div` and `iN::wrapping_rem`: the quotient rounds toward zero and the
+    /// remainder takes the sign of `a`, so `MIN / -1` wraps to `MIN`. The magnitudes go through
+    /// the unsigned divider and the signs are applied afterwards. Dividing by zero gives -1 for
+    /// a non-negative `a`, 1 for a negative one, and `a` as remainder.
+    pub fn signed_div_rem(
+        &mut self,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> (GateIndexVec, GateIndexVec) {
+        let n = a.len();
+        if n == 0 {
+            return (GateIndexVec::default(), GateIndexVec::default());
         }
 
-        result
+        let a_negative = a[n - 1];
+        let b_negative = b[n - 1];
+        let a_magnitude = self.negate_if(&a_negative, a);
+        let b_magnitude = self.negate_if(&b_negative, b);
+        let (quotient, remainder) = self.div_inner(&a_magnitude, &b_magnitude);
+
+        let signs_differ = self.push_xor(&a_negative, &b_negative);
+        (
+            self.negate_if(&signs_differ, &quotient),
+            self.negate_if(&a_negative, &remainder),
+        )
     }
 
-    fn div(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        self.div_inner(a, b).0
+    pub fn signed_div(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        self.signed_div_rem(a, b).0
     }
 
-    fn rem(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
-        self.div_inner(a, b).1
+    pub fn signed_rem(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        self.signed_div_rem(a, b).1
     }
 
-    fn eq(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let mut eq_list = vec![0; a.len()];
+    /// Returns the unsigned integer square root `floor(sqrt(a))`, zero-extended to the width of
+    /// `a`. The restoring digit-by-digit method brings down two bits of `a` per step and
+    /// subtracts `4 * root + 1`, keeping the difference when it does not borrow, so each of
+    /// the `ceil(n / 2)` steps costs one subtractor and one mux no wider than the root so far.
+    pub fn isqrt(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let n = a.len();
+        if n == 0 {
+            return GateIndexVec::default();
+        }
+        let (zero, one) = self.constant_wires();
 
-        let i = a.len() - 1;
-        let eq_i = self.push_xnor(&a[i], &b[i]);
-        eq_list[i] = eq_i;
+        let steps = n.div_ceil(2);
+        let mut remainder = GateIndexVec::default();
+        let mut root: Vec<GateIndex> = Vec::with_capacity(steps);
+        for i in (0..steps).rev() {
+            // with j root bits known, the remainder is at most 2 * root < 2^(j + 1), so the
+            // shifted remainder and the trial value both fit in j + 3 bits
+            let width = root.len() + 3;
+            let high = if 2 * i + 1 < n { a[2 * i + 1] } else { zero };
+            let mut shifted = vec![a[2 * i], high];
+            shifted.extend(remainder.iter().copied());
+            let shifted = resize_bits(&shifted.into(), width + 1, zero);
+
+            let mut trial = vec![one, zero];
+            trial.extend(root.iter().copied());
+            let trial = zero_extend(&trial.into(), width + 1, zero);
+
+            let difference = self.sub(&shifted, &trial);
+            let fits = self.push_not(&difference[width]);
+            root.insert(0, fits);
+
+            let low = |bits: &GateIndexVec| resize_bits(bits, width, zero);
+            remainder = self.mux(&fits, &low(&difference), &low(&shifted));
+        }
 
-        for idx in (0..i).rev() {
-            let xn = self.push_xnor(&a[idx], &b[idx]);
-            let eq_i = self.push_and(&eq_list[idx + 1], &xn);
-            eq_list[idx] = eq_i;
+        zero_extend(&root.into(), n, zero)
+    }
+
+    /// Multiplies like `mul` and also returns a bit that is set when the unsigned product does
+    /// not fit. That is the case exactly when a partial product bit falls above the top bit or
+    /// one of the partial sums carries out.
+    pub fn mul_with_overflow(
+        &mut self,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> (GateIndexVec, GateIndex) {
+        let width = a.len();
+        let mut flags = Vec::new();
+        for i in 1..width {
+            for j in (width - i)..width {
+                flags.push(self.push_and(&a[i], &b[j]));
+            }
         }
 
-        eq_list[0]
+        let mut product = partial_product_shift(self, a, b, 0);
+        for shift in 1..width {
+            let partial_product = partial_product_shift(self, a, b, shift);
+            let (sum, carry) = self.add_with_carry(&product, &partial_product);
+            flags.push(carry);
+            product = sum;
+        }
+
+        let overflow = self.any(&flags);
+        (product, overflow)
     }
 
-    fn ne(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let eq = self.eq(a, b);
-        self.push_not(&eq)
+    /// Multiplies two `n`-bit values into their full `2n`-bit product, least-significant half
+    /// first, so nothing is truncated. Each row of partial products is added to the running
+    /// upper half, whose lowest bit is then final, so every adder is only `n + 1` bits wide.
+    pub fn widening_mul(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let n = a.len();
+        if n == 0 {
+            return GateIndexVec::default();
+        }
+        match self.multiplier {
+            Multiplier::ShiftAndAdd => {}
+            Multiplier::Wallace => return wallace_mul(self, a, b, 2 * n),
+            Multiplier::Karatsuba { threshold } => {
+                return karatsuba_widening_mul(self, a, b, threshold)
+            }
+        }
+        let (zero, _) = self.constant_wires();
+
+        let mut product = GateIndexVec::with_capacity(2 * n);
+        let mut upper = self.mask(&b[0], a);
+        let mut carry = zero;
+        for i in 1..n {
+            product.push(upper[0]);
+            let mut shifted: GateIndexVec =
+                upper.iter().skip(1).copied().collect::<Vec<_>>().into();
+            shifted.push(carry);
+
+            let row = self.mask(&b[i], a);
+            let mut sum = self.add(
+                &zero_extend(&shifted, n + 1, zero),
+                &zero_extend(&row, n + 1, zero),
+            );
+            carry = sum[n];
+            sum.truncate(n);
+            upper = sum;
+        }
+        product.push_all(&upper);
+        product.push(carry);
+        product
     }
 
-    fn gt(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let (lt, eq) = self.compare(a, b);
-        let or_gate = self.push_or(&lt, &eq);
-        self.push_not(&or_gate)
+    /// Adds all `values` together, wrapping at their common width, with a balanced tree of
+    /// adders. With a parallel-prefix [`Adder`] the AND-depth grows with `log2` of the count
+    /// rather than with every value; chained ripple-carry adders already overlap. An empty
+    /// slice yields no wires.
+    pub fn reduce_add(&mut self, values: &[GateIndexVec]) -> GateIndexVec {
+        let mut level = values.to_vec();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] => self.add(lhs, rhs),
+                    [value] => value.clone(),
+                    _ => unreachable!("chunks(2) yields one or two values"),
+                })
+                .collect();
+        }
+        level.pop().unwrap_or_default()
     }
 
-    fn ge(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let lt = self.lt(a, b);
-        self.push_not(&lt)
+    /// ORs all `bits` together; an empty slice yields a constant 0 wire.
+    pub fn any(&mut self, bits: &[GateIndex]) -> GateIndex {
+        match bits.split_first() {
+            Some((first, rest)) => rest.iter().fold(*first, |acc, bit| self.push_or(&acc, bit)),
+            None => self.constant_wires().0,
+        }
     }
 
-    fn lt(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let (lt, _eq) = self.compare(a, b);
-        lt
+    /// Records an overflow bit from checked arithmetic, to be combined by
+    /// [`WRK17CircuitBuilder::overflowed`].
+    pub fn flag_overflow(&mut self, flag: GateIndex) {
+        self.overflow_flags.push(flag);
     }
 
-    fn le(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
-        let gt = self.gt(a, b);
-        self.push_not(&gt)
+    /// Returns a wire that is set when any bit recorded by
+    /// [`WRK17CircuitBuilder::flag_overflow`] is set.
+    pub fn overflowed(&mut self) -> GateIndex {
+        let flags = self.overflow_flags.clone();
+        self.any(&flags)
     }
 
-    fn compare(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> (GateIndex, GateIndex) {
-        let mut eq_list = vec![0; a.len()];
-        let mut lt_list = vec![0; a.len()];
+    // Two's complement negation when cond is set: (x ^ mask) + cond, where every bit of mask
+    // is cond. When cond is clear both steps are the identity.
+    pub fn negate_if(&mut self, cond: &GateIndex, x: &GateIndexVec) -> GateIndexVec {
+        let flipped: GateIndexVec = x
+            .iter()
+            .map(|bit| self.push_xor(bit, cond))
+            .collect::<Vec<_>>()
+            .into();
+
+        let (zero, _) = self.constant_wires();
+        let mut increment = vec![zero; x.len()];
+        increment[0] = *cond;
+        self.add(&flipped, &GateIndexVec::new(increment))
+    }
 
-        let i = a.len() - 1;
-        let eq_i = self.push_xnor(&a[i], &b[i]);
-        eq_list[i] = eq_i;
+    // a + 1, wrapping. Adding the constant 1 only needs a half-adder chain: bit i flips when
+    // every lower bit is set, so each bit costs one XOR and one AND.
+    pub fn increment(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let mut output_indices = GateIndexVec::with_capacity(a.len());
+        let mut carry: Option<GateIndex> = None;
+        for bit in a.iter() {
+            match carry {
+                None => {
+                    output_indices.push(self.push_not(bit));
+                    carry = Some(*bit);
+                }
+                Some(c) => {
+                    output_indices.push(self.push_xor(bit, &c));
+                    carry = Some(self.push_and(bit, &c));
+                }
+            }
+        }
+        output_indices
+    }
 
-        let nt = self.push_not(&a[i]);
-        let lt_i = self.push_and(&nt, &b[i]);
-        lt_list[i] = lt_i;
+    // a - 1, wrapping. The mirror image of increment: bit i flips when every lower bit is
+    // clear, so the borrow propagates through the inverted bits.
+    pub fn decrement(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let mut output_indices = GateIndexVec::with_capacity(a.len());
+        let mut borrow: Option<GateIndex> = None;
+        for bit in a.iter() {
+            let inverted = self.push_not(bit);
+            match borrow {
+                None => {
+                    output_indices.push(inverted);
+                    borrow = Some(inverted);
+                }
+                Some(b) => {
+                    output_indices.push(self.push_xor(bit, &b));
+                    borrow = Some(self.push_and(&inverted, &b));
+                }
+            }
+        }
+        output_indices
+    }
+
+    // -a in two's complement, wrapping so that the minimum value negates to itself. Bit i flips
+    // exactly when a lower bit is set, so a running OR of the lower bits is the only
+    // non-linear gate per bit, the same cost as increment without inverting first.
+    pub fn neg(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let mut output_indices = GateIndexVec::with_capacity(a.len());
+        let mut any_lower: Option<GateIndex> = None;
+        for (i, bit) in a.iter().enumerate() {
+            let Some(lower) = any_lower else {
+                output_indices.push(*bit);
+                any_lower = Some(*bit);
+                continue;
+            };
+            output_indices.push(self.push_xor(bit, &lower));
+            if i + 1 < a.len() {
+                any_lower = Some(self.push_or(&lower, bit));
+            }
+        }
+        output_indices
+    }
+
+    // Reflected binary code of a, a ^ (a >> 1); the top bit is kept as is. XOR gates only.
+    pub fn to_gray(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let mut output_indices = GateIndexVec::with_capacity(a.len());
+        for i in 0..a.len() {
+            if i + 1 < a.len() {
+                output_indices.push(self.push_xor(&a[i], &a[i + 1]));
+            } else {
+                output_indices.push(a[i]);
+            }
+        }
+        output_indices
+    }
+
+    // Inverse of to_gray: each binary bit is the XOR of all Gray bits at or above it, computed
+    // as a running XOR from the top bit down.
+    pub fn from_gray(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let mut bits: Vec<GateIndex> = Vec::with_capacity(a.len());
+        let mut prefix: Option<GateIndex> = None;
+        for i in (0..a.len()).rev() {
+            let bit = match prefix {
+                Some(higher) => self.push_xor(&a[i], &higher),
+                None => a[i],
+            };
+            bits.push(bit);
+            prefix = Some(bit);
+        }
+        bits.reverse();
+        bits.into()
+    }
+
+    // |x| for two's complement x, negating when the sign bit is set; MIN stays MIN, as with
+    // `wrapping_abs`
+    pub fn abs(&mut self, x: &GateIndexVec) -> GateIndexVec {
+        let sign = x[x.len() - 1];
+        self.negate_if(&sign, x)
+    }
+
+    // Counts the zeros above the highest set bit, n for zero, in floor(log2(n)) + 1 bits. a is
+    // padded below with ones up to a power of two above n, which stops the count at n, then
+    // a binary search from the largest power of two sets count bit k and shifts the window
+    // left by 2^k whenever its top 2^k bits are all zero.
+    pub fn leading_zeros(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let n = a.len();
+        if n == 0 {
+            return GateIndexVec::default();
+        }
+        let (zero, one) = self.constant_wires();
+
+        let width = (n + 1).next_power_of_two();
+        let mut window = vec![one; width - n];
+        window.extend(a.iter().copied());
+
+        let mut count = Vec::new();
+        let mut step = width / 2;
+        while step > 0 {
+            let high = self.any(&window[width - step..]);
+            let take = self.push_not(&high);
+            count.push(take);
+            if step > 1 {
+                let shifted: GateIndexVec = (0..width)
+                    .map(|i| if i >= step { window[i - step] } else { zero })
+                    .collect::<Vec<_>>()
+                    .into();
+                window = self
+                    .mux(&take, &shifted, &window.clone().into())
+                    .iter()
+                    .copied()
+                    .collect();
+            }
+            step /= 2;
+        }
+        // found from the largest step down, so the most significant bit came first
+        count.reverse();
+        count.into()
+    }
+
+    // Counts the zeros below the lowest set bit, n for zero: the leading zeros of the bits in
+    // reverse order, which costs no extra gates
+    pub fn trailing_zeros(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let reversed: Vec<GateIndex> = a.iter().rev().copied().collect();
+        self.leading_zeros(&reversed.into())
+    }
+
+    // Number of set bits in a, as a count just wide enough for a.len(). Counts of the two
+    // halves are added recursively, each adder one bit wider than its operands.
+    pub fn popcount(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        match a.len() {
+            0 => GateIndexVec::new(vec![self.constant_wires().0]),
+            1 => a.clone(),
+            len => {
+                let bits: Vec<GateIndex> = a.iter().copied().collect();
+                let (low, high) = bits.split_at(len / 2);
+                let low = self.popcount(&low.to_vec().into());
+                let high = self.popcount(&high.to_vec().into());
+                widening_add(self, &high, &low)
+            }
+        }
+    }
+
+    // Whether a and b differ in at most k bits, comparing their Hamming distance against the
+    // public k without revealing it
+    pub fn within_hamming(&mut self, a: &GateIndexVec, b: &GateIndexVec, k: usize) -> GateIndex {
+        if k >= a.len() {
+            return self.constant_wires().1;
+        }
+
+        let difference = self.xor(a, b);
+        let distance = self.popcount(&difference);
+        let k_bits: Vec<bool> = (0..distance.len()).map(|i| (k >> i) & 1 == 1).collect();
+        let (lt, eq) = self.compare_const(&distance, &k_bits);
+        self.push_xor(&lt, &eq)
+    }
+
+    // Counts the elements of a that also occur in b as a `width`-bit number. Every pair is
+    // compared, so the gate structure depends only on the array sizes and hides which
+    // elements matched.
+    pub fn intersection_size(
+        &mut self,
+        a: &[GateIndexVec],
+        b: &[GateIndexVec],
+        width: usize,
+    ) -> GateIndexVec {
+        assert!(
+            !a.is_empty() && !b.is_empty(),
+            "intersection_size requires non-empty sets"
+        );
+
+        // Constant 0 wire: x ^ x
+        let zero = self.push_xor(&a[0][0], &a[0][0]);
+        let mut count = GateIndexVec::new(vec![zero; width]);
+
+        for a_value in a {
+            // OR over b, so duplicates in b still count each element of a at most once
+            let mut found = self.eq(a_value, &b[0]);
+            for b_value in &b[1..] {
+                let eq = self.eq(a_value, b_value);
+                found = self.push_or(&found, &eq);
+            }
+
+            let mut increment = vec![zero; width];
+            increment[0] = found;
+            count = self.add(&count, &GateIndexVec::new(increment));
+        }
+        count
+    }
+
+    // Sums a[i] * b[i] as a `width`-bit number. The partial products of every multiplier go
+    // into a single carry-save tree of 3:2 compressors, so only the final two rows need a
+    // carry-propagating adder.
+    pub fn dot_product(
+        &mut self,
+        a: &[GateIndexVec],
+        b: &[GateIndexVec],
+        width: usize,
+    ) -> GateIndexVec {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "dot_product requires equal-length vectors"
+        );
+        assert!(!a.is_empty(), "dot_product requires non-empty vectors");
+
+        let (zero, _) = self.constant_wires();
+        let mut rows: Vec<GateIndexVec> = Vec::new();
+        for (a_value, b_value) in a.iter().zip(b) {
+            for (shift, b_bit) in b_value.iter().enumerate().take(width) {
+                rows.push(partial_product_row(
+                    self, a_value, b_bit, shift, width, zero,
+                ));
+            }
+        }
+        wallace_sum(self, rows, width, zero)
+    }
+
+    // Writes new_value into values[index] for a secret index. Every position gets its own
+    // equality test against its public position and a mux, so the circuit does not depend on
+    // which slot is written. Positions the index cannot represent are left as they are.
+    pub fn mux_store(
+        &mut self,
+        index: &GateIndexVec,
+        values: &[GateIndexVec],
+        new_value: &GateIndexVec,
+    ) -> Vec<GateIndexVec> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(position, value)| {
+                if index.len() < usize::BITS as usize && position >> index.len() != 0 {
+                    return value.clone();
+                }
+                let position_bits: Vec<bool> = (0..index.len())
+                    .map(|i| i < usize::BITS as usize && (position >> i) & 1 == 1)
+                    .collect();
+                let selected = self.eq_const(index, &position_bits);
+                self.mux(&selected, new_value, value)
+            })
+            .collect()
+    }
+
+    // Equality of two byte strings of the same length, as one comparator over all their bits
+    pub fn bytes_eq(&mut self, a: &[GateIndexVec], b: &[GateIndexVec]) -> GateIndex {
+        let mut lhs = GateIndexVec::default();
+        let mut rhs = GateIndexVec::default();
+        for (a, b) in a.iter().zip(b) {
+            lhs.push_all(a);
+            rhs.push_all(b);
+        }
+        if lhs.is_empty() {
+            return self.constant_wires().1;
+        }
+        self.eq(&lhs, &rhs)
+    }
+
+    // Whether zero-padded `prefix` is a prefix of `a`: every byte of `prefix` is either padding
+    // (zero) or equal to the byte of `a` in the same position
+    pub fn has_prefix(&mut self, a: &[GateIndexVec], prefix: &[GateIndexVec]) -> GateIndex {
+        let mut result: Option<GateIndex> = None;
+        for (a, p) in a.iter().zip(prefix) {
+            let padding = self.eq_const(p, &[]);
+            let same = self.eq(a, p);
+            let matches = self.push_or(&padding, &same);
+            result = Some(match result {
+                Some(result) => self.push_and(&result, &matches),
+                None => matches,
+            });
+        }
+        result.unwrap_or_else(|| self.constant_wires().1)
+    }
+
+    // Whether `first <= byte <= last`, from two comparisons against public constants
+    fn byte_in_range(&mut self, byte: &GateIndexVec, first: u8, last: u8) -> GateIndex {
+        let (below_first, _) = self.compare_const(byte, &bits_of(first));
+        let (below_end, _) = self.compare_const(byte, &bits_of(last + 1));
+        let not_below_first = self.push_not(&below_first);
+        self.push_and(&not_below_first, &below_end)
+    }
+
+    // Whether a byte is one of the ASCII digits 0-9
+    pub fn ascii_is_digit(&mut self, byte: &GateIndexVec) -> GateIndex {
+        self.byte_in_range(byte, b'0', b'9')
+    }
+
+    // Whether a byte is an ASCII letter. Setting bit 5 maps A-Z onto a-z and leaves no other
+    // byte in that range, so one range check covers both cases.
+    pub fn ascii_is_alpha(&mut self, byte: &GateIndexVec) -> GateIndex {
+        let (_, one) = self.constant_wires();
+        let folded: GateIndexVec = byte
+            .iter()
+            .enumerate()
+            .map(|(i, &bit)| if i == 5 { one } else { bit })
+            .collect::<Vec<_>>()
+            .into();
+        self.byte_in_range(&folded, b'a', b'z')
+    }
+
+    // Maps the ASCII letters A-Z of a byte to a-z by setting bit 5; other bytes pass through
+    pub fn ascii_lowercase(&mut self, byte: &GateIndexVec) -> GateIndexVec {
+        let upper = self.byte_in_range(byte, b'A', b'Z');
+
+        let mut lower = byte.clone();
+        let case_bit = self.push_or(&byte[5], &upper);
+        lower.truncate(5);
+        lower.push(case_bit);
+        for i in 6..byte.len() {
+            lower.push(byte[i]);
+        }
+        lower
+    }
+
+    // One wire per slot of a padded collection, set when the slot lies below the secret length:
+    // slot i is live when !(len < i + 1). Slots the length cannot reach are constant false.
+    pub fn length_mask(&mut self, len: &GateIndexVec, capacity: usize) -> Vec<GateIndex> {
+        (0..capacity)
+            .map(|slot| {
+                let bound = slot + 1;
+                if len.len() < usize::BITS as usize && bound >> len.len() != 0 {
+                    return self.constant_wires().0;
+                }
+                let bound_bits: Vec<bool> = (0..len.len())
+                    .map(|i| i < usize::BITS as usize && (bound >> i) & 1 == 1)
+                    .collect();
+                let (below, _) = self.compare_const(len, &bound_bits);
+                self.push_not(&below)
+            })
+            .collect()
+    }
+
+    // Reads values[index] for a secret index with a mux tree: level k pairs up the survivors
+    // of level k - 1 and picks one of each pair with index bit k, so a read costs one mux per
+    // bit per value whatever the index. Missing leaves and index bits past the tree read as
+    // zero, so an out-of-range index yields zero.
+    pub fn mux_read(&mut self, index: &GateIndexVec, values: &[GateIndexVec]) -> GateIndexVec {
+        let Some(first) = values.first() else {
+            return GateIndexVec::default();
+        };
+        let (zero, _) = self.constant_wires();
+        let zeros = GateIndexVec::new(vec![zero; first.len()]);
+
+        let mut level = values.to_vec();
+        let mut depth = 0;
+        // values the index has too few bits to reach are dropped with the rest of the level
+        while level.len() > 1 && depth < index.len() {
+            let bit = index[depth];
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [low, high] => self.mux(&bit, high, low),
+                    [low] => self.mux(&bit, &zeros, low),
+                    _ => unreachable!("chunks(2) yields one or two values"),
+                })
+                .collect();
+            depth += 1;
+        }
+        let selected = level.swap_remove(0);
+
+        let high_bits: Vec<GateIndex> = index.iter().skip(depth).copied().collect();
+        if high_bits.is_empty() {
+            return selected;
+        }
+        let out_of_range = self.any(&high_bits);
+        let in_range = self.push_not(&out_of_range);
+        self.mask(&in_range, &selected)
+    }
+
+    // Rotates a towards its high end by a public amount, wrapping the top bits around to the
+    // bottom like the native `rotate_left`. Only reorders wires, so it adds no gates.
+    pub fn rotate_left(&self, a: &GateIndexVec, amount: usize) -> GateIndexVec {
+        let mut bits: Vec<GateIndex> = a.iter().copied().collect();
+        if !bits.is_empty() {
+            let len = bits.len();
+            bits.rotate_right(amount % len);
+        }
+        bits.into()
+    }
+
+    // Rotates a towards its low end by a public amount; the mirror of rotate_left
+    pub fn rotate_right(&self, a: &GateIndexVec, amount: usize) -> GateIndexVec {
+        let mut bits: Vec<GateIndex> = a.iter().copied().collect();
+        if !bits.is_empty() {
+            let len = bits.len();
+            bits.rotate_left(amount % len);
+        }
+        bits.into()
+    }
+
+    // Rotates a left by a secret amount with a barrel rotator: stage k rewires a rotation by
+    // 2^k and keeps it when amount bit k is set, so each stage costs one mux per bit. The
+    // amount is taken modulo the width, which also holds for widths that are not powers of
+    // two; stages whose rotation is a whole turn are skipped.
+    pub fn rotate_left_by(&mut self, a: &GateIndexVec, amount: &GateIndexVec) -> GateIndexVec {
+        self.barrel_rotate(a, amount, true)
+    }
+
+    pub fn rotate_right_by(&mut self, a: &GateIndexVec, amount: &GateIndexVec) -> GateIndexVec {
+        self.barrel_rotate(a, amount, false)
+    }
+
+    fn barrel_rotate(
+        &mut self,
+        a: &GateIndexVec,
+        amount: &GateIndexVec,
+        left: bool,
+    ) -> GateIndexVec {
+        let mut current = a.clone();
+        if a.is_empty() {
+            return current;
+        }
+        // 2^k mod the width, kept reduced so wide amounts cannot overflow
+        let mut step = 1 % a.len();
+        for bit in amount.iter() {
+            if step != 0 {
+                let rotated = if left {
+                    self.rotate_left(&current, step)
+                } else {
+                    self.rotate_right(&current, step)
+                };
+                current = self.mux(bit, &rotated, &current);
+            }
+            step = step * 2 % a.len();
+        }
+        current
+    }
+
+    // Repeats a single wire across `width` lanes. Adds no gates; this is how a one-bit
+    // condition reaches every bit of a multi-bit mux or mask.
+    pub fn broadcast(&self, bit: &GateIndex, width: usize) -> GateIndexVec {
+        GateIndexVec::new(vec![*bit; width])
+    }
+
+    // Returns x when cond is set and zero otherwise: one AND per bit against the broadcast
+    // condition, with no constant wire and no mux.
+    pub fn mask(&mut self, cond: &GateIndex, x: &GateIndexVec) -> GateIndexVec {
+        let cond = self.broadcast(cond, x.len());
+        self.and(x, &cond)
+    }
+
+    // Selects values[i] for the set bit i of a one-hot mask by ANDing every value with its
+    // mask bit and ORing the results: one AND per value bit and no comparators. With several
+    // mask bits set the result is the OR of the selected values, with none it is zero.
+    pub fn select_one_hot(&mut self, mask: &GateIndexVec, values: &[GateIndexVec]) -> GateIndexVec {
+        let mut selected: Option<GateIndexVec> = None;
+        for (bit, value) in mask.iter().zip(values) {
+            let masked: GateIndexVec = value
+                .iter()
+                .map(|value_bit| self.push_and(value_bit, bit))
+                .collect::<Vec<_>>()
+                .into();
+            selected = Some(match selected {
+                Some(acc) => self.or(&acc, &masked),
+                None => masked,
+            });
+        }
+        selected.unwrap_or_else(|| {
+            let width = values.first().map_or(0, |value| value.len());
+            let (zero, _) = self.constant_wires();
+            GateIndexVec::new(vec![zero; width])
+        })
+    }
+
+    // Bitonic sorting network in ascending order over a power-of-two number of values. The
+    // compare-and-swap steps depend only on the number of values, never on their contents.
+    pub fn bitonic_sort(&mut self, values: &[GateIndexVec]) -> Vec<GateIndexVec> {
+        let n = values.len();
+        assert!(
+            n.is_power_of_two(),
+            "bitonic sort requires a power-of-two number of values, got {}",
+            n
+        );
+
+        let mut values = values.to_vec();
+        let mut k = 2;
+        while k <= n {
+            let mut j = k / 2;
+            while j > 0 {
+                for i in 0..n {
+                    let l = i ^ j;
+                    if l <= i {
+                        continue;
+                    }
+
+                    // Alternate the direction per block of size k to build bitonic sequences
+                    let swap = if i & k == 0 {
+                        self.gt(&values[i], &values[l])
+                    } else {
+                        self.lt(&values[i], &values[l])
+                    };
+                    let (low, high) = self.conditional_swap(&swap, &values[i], &values[l]);
+                    values[i] = low;
+                    values[l] = high;
+                }
+                j /= 2;
+            }
+            k *= 2;
+        }
+        values
+    }
+
+    pub fn compile(&self, output_indices: &GateIndexVec) -> Circuit {
+        Circuit::new(self.gates.clone(), output_indices.clone().into())
+    }
+
+    /// Executes a compiled circuit, returning an error instead of panicking when the executor
+    /// rejects the circuit or fails during evaluation.
+    pub fn execute<const N: usize>(
+        &self,
+        circuit: &Circuit,
+    ) -> Result<GarbledUint<N>, CircuitError> {
+        self.execute_with_evaluator_inputs(circuit, &[])
+    }
+
+    /// Like [`WRK17CircuitBuilder::execute`], with the bits for the wires added by
+    /// [`WRK17CircuitBuilder::evaluator_input_bits`].
+    pub fn execute_with_evaluator_inputs<const N: usize>(
+        &self,
+        circuit: &Circuit,
+        evaluator_inputs: &[bool],
+    ) -> Result<GarbledUint<N>, CircuitError> {
+        let result = get_executor().execute(circuit, &self.inputs, evaluator_inputs)?;
+        Ok(GarbledUint::new(result))
+    }
+
+    /// Compiles and simulates the circuit, recording the plaintext value of every gate.
+    ///
+    /// Debugging only: see [`crate::trace`] for why this breaks the privacy model.
+    #[cfg(feature = "debug-trace")]
+    pub fn compile_and_trace(
+        &self,
+        output_indices: &GateIndexVec,
+    ) -> Result<crate::trace::Transcript, CircuitError> {
+        let circuit = self.compile(output_indices);
+        Ok(crate::trace::simulate_with_transcript(
+            &circuit,
+            &self.inputs,
+            &[],
+        )?)
+    }
+
+    pub fn compile_and_execute<const N: usize>(
+        &self,
+        output_indices: &GateIndexVec,
+    ) -> Result<GarbledUint<N>, CircuitError> {
+        let circuit = self.compile(output_indices);
+        self.execute(&circuit)
+    }
+}
+
+impl CircuitExecutor for WRK17CircuitBuilder {
+    type Type = GateIndex;
+    type TypeVec = GateIndexVec;
+
+    fn xor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let xor = self.push_xor(&a[i], &b[i]);
+            output.push(xor);
+        }
+        output
+    }
+
+    fn and(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let and = self.push_and(&a[i], &b[i]);
+            output.push(and);
+        }
+        output
+    }
+
+    fn land(&mut self, a: &GateIndex, b: &GateIndex) -> GateIndex {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        let and = self.push_and(a, b);
+        output.push(and);
+        output.into()
+    }
+
+    fn not(&mut self, a: &GateIndexVec) -> GateIndexVec {
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let not_gate = self.push_not(&a[i]);
+            output.push(not_gate);
+        }
+        output
+    }
+
+    fn or(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let or_gate = self.push_or(&a[i], &b[i]);
+            output.push(or_gate);
+        }
+        output
+    }
+
+    fn lor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let output = self.or(a, b);
+        output.into()
+    }
+
+    fn nand(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let nand = self.push_nand(&a[i], &b[i]);
+            output.push(nand);
+        }
+        output
+    }
+
+    fn nor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let nor = self.push_nor(&a[i], &b[i]);
+            output.push(nor);
+        }
+        output
+    }
+
+    fn xnor(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let xnor = self.push_xnor(&a[i], &b[i]);
+            output.push(xnor);
+        }
+        output
+    }
+
+    fn mux(&mut self, s: &GateIndex, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        // repeat with output_indices
+        let mut output = GateIndexVec::default();
+        for i in 0..a.len() {
+            let mux = self.push_mux(s, &b[i], &a[i]);
+            output.push(mux);
+        }
+        output
+    }
+
+    fn add(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        match self.adder {
+            Adder::RippleCarry => ripple_carry_add(self, a, b),
+            Adder::CarryLookahead => parallel_prefix_add(self, a, b, sklansky_prefix),
+            Adder::KoggeStone => parallel_prefix_add(self, a, b, kogge_stone_prefix),
+            Adder::BrentKung => parallel_prefix_add(self, a, b, brent_kung_prefix),
+        }
+    }
+
+    fn sub(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let mut borrow = None;
+        let mut output_indices = GateIndexVec::default();
+        for i in 0..a.len() {
+            let (diff, new_borrow) = full_subtractor(self, &a[i], &b[i], &borrow);
+            output_indices.push(diff);
+            borrow = new_borrow;
+        }
+        output_indices
+    }
+
+    fn mul(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        match self.multiplier {
+            Multiplier::ShiftAndAdd => {}
+            Multiplier::Wallace => return wallace_mul(self, a, b, a.len()),
+            Multiplier::Karatsuba { threshold } => return karatsuba_mul(self, a, b, threshold),
+        }
+
+        let mut partial_products: Vec<GateIndexVec> = Vec::with_capacity(a.len());
+
+        // Generate partial products
+        for i in 0..a.len() {
+            let shifted_product = partial_product_shift(self, a, b, i);
+            partial_products.push(shifted_product);
+        }
+
+        // Sum up all partial products
+        let mut result = partial_products[0].clone();
+        for partial_product in partial_products.iter().take(a.len()).skip(1) {
+            result = self.add(&result, partial_product);
+        }
+
+        result
+    }
+
+    fn div(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        self.div_inner(a, b).0
+    }
+
+    fn rem(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        self.div_inner(a, b).1
+    }
+
+    fn eq(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let mut eq_list = vec![0; a.len()];
+
+        let i = a.len() - 1;
+        let eq_i = self.push_xnor(&a[i], &b[i]);
+        eq_list[i] = eq_i;
+
+        for idx in (0..i).rev() {
+            let xn = self.push_xnor(&a[idx], &b[idx]);
+            let eq_i = self.push_and(&eq_list[idx + 1], &xn);
+            eq_list[idx] = eq_i;
+        }
+
+        eq_list[0]
+    }
+
+    fn ne(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let eq = self.eq(a, b);
+        self.push_not(&eq)
+    }
+
+    fn gt(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let (lt, eq) = self.compare(a, b);
+        let or_gate = self.push_or(&lt, &eq);
+        self.push_not(&or_gate)
+    }
+
+    fn ge(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let lt = self.lt(a, b);
+        self.push_not(&lt)
+    }
+
+    fn lt(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let (lt, _eq) = self.compare(a, b);
+        lt
+    }
+
+    fn le(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndex {
+        let gt = self.gt(a, b);
+        self.push_not(&gt)
+    }
+
+    fn compare(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> (GateIndex, GateIndex) {
+        let mut eq_list = vec![0; a.len()];
+        let mut lt_list = vec![0; a.len()];
+
+        let i = a.len() - 1;
+        let eq_i = self.push_xnor(&a[i], &b[i]);
+        eq_list[i] = eq_i;
+
+        let nt = self.push_not(&a[i]);
+        let lt_i = self.push_and(&nt, &b[i]);
+        lt_list[i] = lt_i;
+
+        for idx in (0..i).rev() {
+            let xn = self.push_xnor(&a[idx], &b[idx]);
+            let eq_i = self.push_and(&eq_list[idx + 1], &xn);
+            eq_list[idx] = eq_i;
+
+            let nt = self.push_not(&a[idx]);
+            let aa = self.push_and(&nt, &b[idx]);
+            let temp_lt = self.push_and(&eq_list[idx + 1], &aa);
+            lt_list[idx] = self.push_or(&lt_list[idx + 1], &temp_lt);
+        }
+
+        (lt_list[0], eq_list[0])
+    }
+}
+
+macro_rules! build_and_execute {
+    // generates a fallible helper and a panicking wrapper that delegates to it
+    ($fn_name:ident, $try_fn_name:ident, $op:ident) => {
+        pub(crate) fn $try_fn_name<const N: usize>(
+            lhs: &GarbledUint<N>,
+            rhs: &GarbledUint<N>,
+        ) -> Result<GarbledUint<N>, CircuitError> {
+            let mut builder = WRK17CircuitBuilder::default();
+            let a = builder.input(lhs);
+            let b = builder.input(rhs);
+
+            let output = builder.$op(&a, &b);
+            let circuit = builder.compile(&output);
+
+            builder.execute(&circuit)
+        }
+
+        pub(crate) fn $fn_name<const N: usize>(
+            lhs: &GarbledUint<N>,
+            rhs: &GarbledUint<N>,
+        ) -> GarbledUint<N> {
+            $try_fn_name(lhs, rhs).expect("Failed to execute circuit")
+        }
+    };
+    ($fn_name:ident, $op:ident) => {
+        pub(crate) fn $fn_name<const N: usize>(
+            lhs: &GarbledUint<N>,
+            rhs: &GarbledUint<N>,
+        ) -> GarbledUint<N> {
+            let mut builder = WRK17CircuitBuilder::default();
+            // Access the global CircuitBuilder instance
+            //let mut builder = WRK17CircuitBuilder::instance().lock().unwrap();
+
+            let a = builder.input(lhs);
+            let b = builder.input(rhs);
+
+            let output = builder.$op(&a, &b);
+            let circuit = builder.compile(&output);
+
+            // Execute the circuit
+            builder
+                .execute(&circuit)
+                .expect("Failed to execute circuit")
+        }
+    };
+}
+
+build_and_execute!(build_and_execute_xor, try_build_and_execute_xor, xor);
+build_and_execute!(build_and_execute_and, try_build_and_execute_and, and);
+build_and_execute!(build_and_execute_or, try_build_and_execute_or, or);
+build_and_execute!(build_and_execute_nand, try_build_and_execute_nand, nand);
+build_and_execute!(build_and_execute_nor, try_build_and_execute_nor, nor);
+build_and_execute!(build_and_execute_xnor, try_build_and_execute_xnor, xnor);
+build_and_execute!(build_and_execute_addition, add);
+build_and_execute!(build_and_execute_subtraction, sub);
+build_and_execute!(build_and_execute_multiplication, mul);
+build_and_execute!(build_and_execute_division, div);
+build_and_execute!(build_and_execute_remainder, rem);
+build_and_execute!(build_and_execute_signed_division, signed_div);
+build_and_execute!(build_and_execute_signed_remainder, signed_rem);
+build_and_execute!(build_and_execute_gcd, gcd);
+build_and_execute!(build_and_execute_abs_diff, abs_diff);
+build_and_execute!(build_and_execute_min, min);
+build_and_execute!(build_and_execute_max, max);
+build_and_execute!(build_and_execute_signed_min, signed_min);
+build_and_execute!(build_and_execute_signed_max, signed_max);
+build_and_execute!(build_and_execute_float_add, float_add);
+build_and_execute!(build_and_execute_float_sub, float_sub);
+build_and_execute!(build_and_execute_float_mul, float_mul);
+
+pub(crate) fn build_and_execute_batch<const N: usize, F>(
+    op: F,
+    lhs: &[GarbledUint<N>],
+    rhs: &[GarbledUint<N>],
+) -> Vec<GarbledUint<N>>
+where
+    F: Fn(&mut WRK17CircuitBuilder, &GateIndexVec, &GateIndexVec) -> GateIndexVec,
+{
+    assert_eq!(
+        lhs.len(),
+        rhs.len(),
+        "batch operands must have the same length"
+    );
+    if lhs.is_empty() {
+        return Vec::new();
+    }
+
+    // The circuit only depends on the operand widths, so build it once from the first pair
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&lhs[0]);
+    let b = builder.input(&rhs[0]);
+    let output = op(&mut builder, &a, &b);
+    let circuit = builder.compile(&output);
+
+    let executor = get_executor();
+    let evaluate = |lhs: &GarbledUint<N>, rhs: &GarbledUint<N>| {
+        let mut inputs = lhs.bits().into_owned();
+        inputs.extend_from_slice(&rhs.bits());
+        let result = executor
+            .execute(&circuit, &inputs, &[])
+            .expect("Failed to execute batch circuit");
+        GarbledUint::new(result)
+    };
+
+    // WASM has no threads, so the batch is evaluated in order on the calling thread
+    if cfg!(target_arch = "wasm32") {
+        return lhs
+            .iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| evaluate(lhs, rhs))
+            .collect();
+    }
+
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let chunk_size = lhs.len().div_ceil(threads);
+
+    // Evaluate chunks of the batch in parallel, preserving the input order
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = lhs
+            .chunks(chunk_size)
+            .zip(rhs.chunks(chunk_size))
+            .map(|(lhs_chunk, rhs_chunk)| {
+                let evaluate = &evaluate;
+                scope.spawn(move || {
+                    lhs_chunk
+                        .iter()
+                        .zip(rhs_chunk)
+                        .map(|(lhs, rhs)| evaluate(lhs, rhs))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Batch worker panicked"))
+            .collect()
+    })
+}
+
+fn bits_of(byte: u8) -> [bool; 8] {
+    core::array::from_fn(|i| (byte >> i) & 1 == 1)
+}
+
+fn shift_right_one(bits: &GateIndexVec, zero: GateIndex) -> GateIndexVec {
+    let mut shifted: GateIndexVec = bits.iter().skip(1).copied().collect::<Vec<_>>().into();
+    shifted.push(zero);
+    shifted
+}
+
+fn zero_extend(bits: &GateIndexVec, width: usize, zero: GateIndex) -> GateIndexVec {
+    let mut extended = bits.clone();
+    for _ in bits.len()..width {
+        extended.push(zero);
+    }
+    extended
+}
+
+// Widens a two's complement value by repeating its sign wire; adds no gates
+fn sign_extend(bits: &GateIndexVec, width: usize) -> GateIndexVec {
+    let mut extended = bits.clone();
+    let sign = bits[bits.len() - 1];
+    for _ in bits.len()..width {
+        extended.push(sign);
+    }
+    extended
+}
+
+fn shift_left_one(bits: &GateIndexVec, zero: GateIndex) -> GateIndexVec {
+    let mut shifted = GateIndexVec::with_capacity(bits.len());
+    shifted.push(zero);
+    for bit in bits.iter().take(bits.len() - 1) {
+        shifted.push(*bit);
+    }
+    shifted
+}
+
+// Adds two counts, a at least as wide as b, into a result one bit wider than a
+fn widening_add(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+) -> GateIndexVec {
+    let mut carry = None;
+    let mut output_indices = GateIndexVec::with_capacity(a.len() + 1);
+    for i in 0..a.len() {
+        let (sum, new_carry) = if i < b.len() {
+            full_adder(builder, a[i], b[i], carry)
+        } else if let Some(c) = carry {
+            (
+                builder.push_xor(&a[i], &c),
+                Some(builder.push_and(&a[i], &c)),
+            )
+        } else {
+            (a[i], None)
+        };
+        output_indices.push(sum);
+        carry = new_carry;
+    }
+    if let Some(c) = carry {
+        output_indices.push(c);
+    }
+    output_indices
+}
+
+fn ripple_carry_add(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+) -> GateIndexVec {
+    let mut carry = None;
+    let mut output_indices = GateIndexVec::default();
+    for i in 0..a.len() {
+        let (sum, new_carry) = full_adder(builder, a[i], b[i], carry);
+        output_indices.push(sum);
+        carry = new_carry;
+    }
+    output_indices
+}
+
+// Parallel-prefix adder. The prefix network combines the per-bit generate and propagate
+// signals of the low n - 1 bits until generate[i] is the carry out of bits 0..=i; the carry
+// out of the top bit is discarded, so it is never computed. A group's generate and propagate
+// are never both set, so combining generates needs an XOR instead of an OR, and groups that
+// reach bit 0 no longer need a propagate.
+fn parallel_prefix_add(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+    prefix: fn(&mut WRK17CircuitBuilder, &mut [GateIndex], &mut [GateIndex]),
+) -> GateIndexVec {
+    let n = a.len();
+    let half_sums: Vec<GateIndex> = (0..n).map(|i| builder.push_xor(&a[i], &b[i])).collect();
+
+    let m = n.saturating_sub(1);
+    let mut generate: Vec<GateIndex> = (0..m).map(|i| builder.push_and(&a[i], &b[i])).collect();
+    let mut propagate = half_sums[..m].to_vec();
+    prefix(builder, &mut generate, &mut propagate);
+
+    let mut output_indices = GateIndexVec::with_capacity(n);
+    for i in 0..n {
+        if i == 0 {
+            output_indices.push(half_sums[0]);
+        } else {
+            output_indices.push(builder.push_xor(&half_sums[i], &generate[i - 1]));
+        }
+    }
+    output_indices
+}
+
+// Extends group i with the adjacent lower group j; the propagate is only kept when asked for
+fn combine_prefix(
+    builder: &mut WRK17CircuitBuilder,
+    generate: &mut [GateIndex],
+    propagate: &mut [GateIndex],
+    i: usize,
+    j: usize,
+    keep_propagate: bool,
+) {
+    let carried = builder.push_and(&propagate[i], &generate[j]);
+    generate[i] = builder.push_xor(&generate[i], &carried);
+    if keep_propagate {
+        propagate[i] = builder.push_and(&propagate[i], &propagate[j]);
+    }
+}
+
+// Sklansky: after the level with span s, group i covers bits (i & !(2s - 1))..=i, each upper
+// half combining with the last group of the lower half
+fn sklansky_prefix(
+    builder: &mut WRK17CircuitBuilder,
+    generate: &mut [GateIndex],
+    propagate: &mut [GateIndex],
+) {
+    let m = generate.len();
+    let mut span = 1;
+    while span < m {
+        for i in (0..m).filter(|i| i & span != 0) {
+            let j = (i & !(span - 1)) - 1;
+            let keep = i & !(2 * span - 1) != 0;
+            combine_prefix(builder, generate, propagate, i, j, keep);
+        }
+        span <<= 1;
+    }
+}
+
+// Kogge-Stone: after the level with span s, group i covers the 2s bits ending at i. Going
+// down from the top keeps group i - s at its value from the previous level.
+fn kogge_stone_prefix(
+    builder: &mut WRK17CircuitBuilder,
+    generate: &mut [GateIndex],
+    propagate: &mut [GateIndex],
+) {
+    let m = generate.len();
+    let mut span = 1;
+    while span < m {
+        for i in (span..m).rev() {
+            combine_prefix(builder, generate, propagate, i, i - span, i >= 2 * span);
+        }
+        span <<= 1;
+    }
+}
+
+// Brent-Kung: an up-sweep builds groups of 2s bits ending at every i with i + 1 a multiple
+// of 2s, which completes the prefixes at i = 2^k - 1; a down-sweep then completes every i
+// with i + 1 an odd multiple of s from the finished prefix just below it.
+fn brent_kung_prefix(
+    builder: &mut WRK17CircuitBuilder,
+    generate: &mut [GateIndex],
+    propagate: &mut [GateIndex],
+) {
+    let m = generate.len();
+    let mut span = 1;
+    while 2 * span <= m {
+        for i in (2 * span - 1..m).step_by(2 * span) {
+            combine_prefix(builder, generate, propagate, i, i - span, i >= 2 * span);
+        }
+        span <<= 1;
+    }
+    while span > 1 {
+        span >>= 1;
+        for i in (3 * span - 1..m).step_by(2 * span) {
+            combine_prefix(builder, generate, propagate, i, i - span, false);
+        }
+    }
+}
+
+fn full_adder(
+    builder: &mut WRK17CircuitBuilder,
+    a: GateIndex,
+    b: GateIndex,
+    carry: Option<GateIndex>,
+) -> (GateIndex, Option<GateIndex>) {
+    let xor_ab = builder.len();
+    builder.gates.push(Gate::Xor(a, b));
+
+    let sum = if let Some(c) = carry {
+        let sum_with_carry = builder.len();
+        builder.gates.push(Gate::Xor(xor_ab, c));
+        sum_with_carry
+    } else {
+        xor_ab
+    };
+
+    let and_ab = builder.len();
+    builder.gates.push(Gate::And(a, b));
+
+    let new_carry = if let Some(c) = carry {
+        let and_axorb_c = builder.len();
+        builder.gates.push(Gate::And(xor_ab, c));
+
+        let or_gate = builder.len();
+        builder.gates.push(Gate::Xor(and_ab, and_axorb_c));
+        Some(or_gate)
+    } else {
+        Some(and_ab)
+    };
+
+    (sum, new_carry)
+}
+
+// The row a * b_bit * 2^shift cut to `width` bits. Bits below the shift or above a are the
+// `zero` wire rather than AND gates, so the carry-save adders can skip them.
+fn partial_product_row(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b_bit: &GateIndex,
+    shift: usize,
+    width: usize,
+    zero: GateIndex,
+) -> GateIndexVec {
+    let row: Vec<GateIndex> = (0..width)
+        .map(|k| match k.checked_sub(shift).filter(|&i| i < a.len()) {
+            Some(i) => builder.push_and(&a[i], b_bit),
+            None => zero,
+        })
+        .collect();
+    row.into()
+}
+
+// Sums `width`-bit rows with a Wallace tree: each level turns every three rows into two with
+// carry-save adders, and the last two rows share one add
+fn wallace_sum(
+    builder: &mut WRK17CircuitBuilder,
+    mut rows: Vec<GateIndexVec>,
+    width: usize,
+    zero: GateIndex,
+) -> GateIndexVec {
+    while rows.len() > 2 {
+        let mut reduced = Vec::with_capacity(rows.len() * 2 / 3 + 2);
+        let mut chunks = rows.chunks_exact(3);
+        for chunk in &mut chunks {
+            let (sum, carry) = carry_save_add(builder, &chunk[0], &chunk[1], &chunk[2], zero);
+            reduced.push(sum);
+            reduced.push(carry);
+        }
+        reduced.extend_from_slice(chunks.remainder());
+        rows = reduced;
+    }
+
+    match rows.as_slice() {
+        [row] => row.clone(),
+        [lhs, rhs] => builder.add(lhs, rhs),
+        _ => GateIndexVec::new(vec![zero; width]),
+    }
+}
+
+// The low `width` bits of a * b, summing only the rows that reach them
+fn wallace_mul(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+    width: usize,
+) -> GateIndexVec {
+    if a.is_empty() {
+        return GateIndexVec::default();
+    }
+    let (zero, _) = builder.constant_wires();
+    let rows = b
+        .iter()
+        .enumerate()
+        .take(width)
+        .map(|(shift, b_bit)| partial_product_row(builder, a, b_bit, shift, width, zero))
+        .collect();
+    wallace_sum(builder, rows, width, zero)
+}
+
+// Karatsuba needs at least 4 bits to split, or the middle product would be as wide as the
+// operands
+fn karatsuba_base_case(n: usize, threshold: usize) -> bool {
+    n <= threshold.max(3)
+}
+
+// Cuts or zero-extends bits to exactly `width`
+fn resize_bits(bits: &GateIndexVec, width: usize, zero: GateIndex) -> GateIndexVec {
+    let mut resized = bits.clone();
+    resized.truncate(width);
+    zero_extend(&resized, width, zero)
+}
+
+fn split_bits(bits: &GateIndexVec, at: usize) -> (GateIndexVec, GateIndexVec) {
+    let low: Vec<GateIndex> = bits.iter().take(at).copied().collect();
+    let high: Vec<GateIndex> = bits.iter().skip(at).copied().collect();
+    (low.into(), high.into())
+}
+
+// The full 2n-bit product by Karatsuba. With a = a1 * 2^h + a0 and likewise for b, the
+// product is z2 * 2^2h + z1 * 2^h + z0 where z0 = a0 * b0, z2 = a1 * b1 and
+// z1 = (a0 + a1) * (b0 + b1) - z0 - z2. z0 and z2 do not overlap, so only z1 is added.
+fn karatsuba_widening_mul(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+    threshold: usize,
+) -> GateIndexVec {
+    let n = a.len();
+    if karatsuba_base_case(n, threshold) {
+        return wallace_mul(builder, a, b, 2 * n);
+    }
+    let (zero, _) = builder.constant_wires();
+
+    // the low halves take the extra bit of an odd width
+    let h = n - n / 2;
+    let (a0, a1) = split_bits(a, h);
+    let (b0, b1) = split_bits(b, h);
+
+    let z0 = karatsuba_widening_mul(builder, &a0, &b0, threshold);
+    let z2 = karatsuba_widening_mul(builder, &a1, &b1, threshold);
+
+    let a_sum = builder.add(
+        &zero_extend(&a0, h + 1, zero),
+        &zero_extend(&a1, h + 1, zero),
+    );
+    let b_sum = builder.add(
+        &zero_extend(&b0, h + 1, zero),
+        &zero_extend(&b1, h + 1, zero),
+    );
+    let middle = karatsuba_widening_mul(builder, &a_sum, &b_sum, threshold);
+
+    // z1 = a0 * b1 + a1 * b0 < 2^(n + 1), which fits in the 2n - h bits above bit h
+    let width = 2 * n - h;
+    let middle = resize_bits(&middle, width, zero);
+    let z1 = builder.sub(&middle, &resize_bits(&z0, width, zero));
+    let z1 = builder.sub(&z1, &resize_bits(&z2, width, zero));
+
+    let mut product = z0;
+    product.push_all(&z2);
+    let (low, high) = split_bits(&product, h);
+    let mut product = low;
+    product.push_all(&builder.add(&high, &z1));
+    product
+}
+
+// The low n bits of a * b by Karatsuba: z0 = a0 * b0 is needed in full, but the cross terms
+// only below bit n, so a1 * b0 and a0 * b1 are truncated products of the high halves' width
+fn karatsuba_mul(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+    threshold: usize,
+) -> GateIndexVec {
+    let n = a.len();
+    if karatsuba_base_case(n, threshold) {
+        return wallace_mul(builder, a, b, n);
+    }
+    let (zero, _) = builder.constant_wires();
+
+    let h = n - n / 2;
+    let (a0, a1) = split_bits(a, h);
+    let (b0, b1) = split_bits(b, h);
+    let (a0_low, _) = split_bits(&a0, n - h);
+    let (b0_low, _) = split_bits(&b0, n - h);
+
+    let z0 = karatsuba_widening_mul(builder, &a0, &b0, threshold);
+    let cross_a1 = karatsuba_mul(builder, &a1, &b0_low, threshold);
+    let cross_b1 = karatsuba_mul(builder, &a0_low, &b1, threshold);
+    let cross = builder.add(&cross_a1, &cross_b1);
+
+    let z0 = resize_bits(&z0, n, zero);
+    let (low, high) = split_bits(&z0, h);
+    let mut product = low;
+    product.push_all(&builder.add(&high, &cross));
+    product
+}
+
+// 3:2 compressor over whole rows: a + b + c == sum + carry, truncated to the row width. Inputs
+// that are the known `zero` wire are dropped, which keeps the sparse partial-product rows cheap.
+fn carry_save_add(
+    builder: &mut WRK17CircuitBuilder,
+    a: &GateIndexVec,
+    b: &GateIndexVec,
+    c: &GateIndexVec,
+    zero: GateIndex,
+) -> (GateIndexVec, GateIndexVec) {
+    let width = a.len();
+    let mut sum = GateIndexVec::with_capacity(width);
+    let mut carry = GateIndexVec::with_capacity(width);
+    carry.push(zero);
+
+    for i in 0..width {
+        let bits: Vec<GateIndex> = [a[i], b[i], c[i]]
+            .into_iter()
+            .filter(|&bit| bit != zero)
+            .collect();
+        let (bit_sum, bit_carry) = match bits.as_slice() {
+            [] => (zero, zero),
+            [x] => (*x, zero),
+            [x, y] => (builder.push_xor(x, y), builder.push_and(x, y)),
+            [x, y, z] => {
+                let (bit_sum, bit_carry) = full_adder(builder, *x, *y, Some(*z));
+                (
+                    bit_sum,
+                    bit_carry.expect("full adder with a carry-in yields a carry"),
+                )
+            }
+            _ => unreachable!(),
+        };
+        sum.push(bit_sum);
+        if i + 1 < width {
+            carry.push(bit_carry);
+        }
+    }
+
+    (sum, carry)
+}
+
+fn full_subtractor(
+    builder: &mut WRK17CircuitBuilder,
+    a: &u32,
+    b: &u32,
+    borrow: &Option<u32>,
+) -> (u32, Option<u32>) {
+    // XOR gate for difference bit (a ⊕ b)
+    let xor_ab = builder.push_xor(a, b);
+
+    // If borrow exists, XOR the result of the previous XOR with the borrow
+    let diff = if let Some(borrow) = borrow {
+        builder.push_xor(&xor_ab, borrow)
+    } else {
+        xor_ab
+    };
+
+    // Compute the new borrow: (!a & b) | (a & borrow) | (!b & borrow)
+    let not_a = builder.push_not(a);
+    let and_not_a_b = builder.push_and(&not_a, b);
+
+    let new_borrow = if let Some(borrow) = borrow {
+        let and_a_borrow = builder.push_and(a, borrow);
+        let not_b = builder.push_not(b);
+        let and_not_b_borrow = builder.push_and(&not_b, borrow);
+
+        // Combine borrow parts using XOR and AND to simulate OR
+        let xor_borrow_parts = builder.push_xor(&and_not_a_b, &and_a_borrow);
+        builder.push_xor(&xor_borrow_parts, &and_not_b_borrow)
+    } else {
+        and_not_a_b
+    };
+
+    (diff, Some(new_borrow))
+}
+
+fn partial_product_shift(
+    builder: &mut WRK17CircuitBuilder,
+    lhs: &GateIndexVec,
+    rhs: &GateIndexVec,
+    shift: usize,
+) -> GateIndexVec {
+    let mut shifted = GateIndexVec::default();
+
+    for i in 0..lhs.len() {
+        if i < shift {
+            // For the lower bits, we push a constant 0.
+            let zero_bit = builder.push_not(&rhs[0]);
+            let and_gate = builder.push_and(&rhs[0], &zero_bit); // Constant 0
+            shifted.push(and_gate);
+        } else {
+            let lhs_bit = lhs[i - shift];
+            let and_gate = builder.push_and(&lhs_bit, &(rhs[shift]));
+            // Shift the bit from the input array
+            shifted.push(and_gate);
+        }
+    }
+
+    shifted
+}
+
+pub(crate) fn build_and_execute_comparator<const N: usize>(
+    lhs: &GarbledUint<N>,
+    rhs: &GarbledUint<N>,
+) -> Ordering {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+    let b = builder.input(rhs);
+
+    let (lt_output, eq_output) = builder.compare(&a, &b);
+
+    let result = builder
+        .compile_and_execute::<2>(&vec![lt_output, eq_output].into())
+        .expect("Failed to execute equality circuit");
+
+    let bits = result.bits();
+    let (lt, eq) = (bits[0], bits[1]);
+
+    if lt {
+        Ordering::Less
+    } else if eq {
+        Ordering::Equal
+    } else {
+        Ordering::Greater
+    }
+}
+
+/// Reveals all three outcomes of one comparison as `[lt, eq, gt]`.
+pub(crate) fn build_and_execute_comparison<const N: usize>(
+    lhs: &GarbledUint<N>,
+    rhs: &GarbledUint<N>,
+    signed: bool,
+) -> [bool; 3] {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+    let b = builder.input(rhs);
+
+    let comparison = if signed {
+        builder.signed_comparison(&a, &b)
+    } else {
+        builder.comparison(&a, &b)
+    };
+
+    let result = builder
+        .compile_and_execute::<3>(&vec![comparison.lt, comparison.eq, comparison.gt].into())
+        .expect("Failed to execute comparison circuit");
+    let bits = result.bits();
+    [bits[0], bits[1], bits[2]]
+}
+
+pub(crate) fn build_and_execute_float_comparison<const N: usize>(
+    lhs: &GarbledUint<N>,
+    rhs: &GarbledUint<N>,
+) -> [bool; 3] {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+    let b = builder.input(rhs);
+
+    let (lt, eq, gt) = builder.float_comparison(&a, &b);
+    let result = builder
+        .compile_and_execute::<3>(&vec![lt, eq, gt].into())
+        .expect("Failed to execute float comparison circuit");
+    let bits = result.bits();
+    [bits[0], bits[1], bits[2]]
+}
+
+/// Compares `lhs` against the public constant `value` (exactly `N` bits, LSB first) and reveals
+/// only whether `lhs.cmp(value)` is one of the `accepted` orderings.
+pub(crate) fn build_and_execute_const_comparison<const N: usize>(
+    lhs: &GarbledUint<N>,
+    value: &[bool],
+    signed: bool,
+    accepted: &[Ordering],
+) -> bool {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+
+    let less = accepted.contains(&Ordering::Less);
+    let equal = accepted.contains(&Ordering::Equal);
+    let greater = accepted.contains(&Ordering::Greater);
+
+    let output = if less == greater && less != equal {
+        // (in)equality needs no less-than chain and is the same for signed values
+        let eq = builder.eq_const(&a, value);
+        if equal {
+            eq
+        } else {
+            builder.push_not(&eq)
+        }
+    } else {
+        let (lt, eq) = if signed {
+            builder.signed_compare_const(&a, value)
+        } else {
+            builder.compare_const(&a, value)
+        };
+        match (less, equal, greater) {
+            (true, false, false) => lt,
+            (false, true, false) => eq,
+            (true, true, false) => builder.push_or(&lt, &eq),
+            (false, false, true) => {
+                let le = builder.push_or(&lt, &eq);
+                builder.push_not(&le)
+            }
+            (false, true, true) => builder.push_not(&lt),
+            (true, false, true) => builder.push_not(&eq),
+            _ => panic!("accepted orderings must be a non-empty strict subset"),
+        }
+    };
+
+    let result = builder
+        .compile_and_execute::<1>(&vec![output].into())
+        .expect("Failed to execute constant comparison circuit");
+    result.into()
+}
+
+pub(crate) fn build_and_execute_is_one_of<const N: usize>(
+    lhs: &GarbledUint<N>,
+    values: &[Vec<bool>],
+) -> GarbledBit {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+
+    let output = builder.is_one_of(&a, values);
+    builder
+        .compile_and_execute(&output.into())
+        .expect("Failed to execute membership circuit")
+}
+
+pub(crate) fn build_and_execute_not<const N: usize>(input: &GarbledUint<N>) -> GarbledUint<N> {
+    try_build_and_execute_not(input).expect("Failed to execute NOT circuit")
+}
+
+pub(crate) fn try_build_and_execute_not<const N: usize>(
+    input: &GarbledUint<N>,
+) -> Result<GarbledUint<N>, CircuitError> {
+    let mut builder = WRK17CircuitBuilder::default();
+    builder.input(input);
+
+    let mut output_indices = GateIndexVec::default();
+
+    let n = N as u32;
+    for i in 0..n {
+        let not_gate = builder.push_not(&i);
+        output_indices.push(not_gate);
+    }
+
+    builder.compile_and_execute(&output_indices)
+}
+
+/// Runs `gate`, e.g. [`WRK17CircuitBuilder::push_and`], on two single-bit inputs.
+pub(crate) fn build_and_execute_logic(
+    lhs: &GarbledBit,
+    rhs: &GarbledBit,
+    gate: fn(&mut WRK17CircuitBuilder, &GateIndex, &GateIndex) -> GateIndex,
+) -> GarbledBit {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledBit::new(lhs.to_bits_le()));
+    let b = builder.input(&GarbledBit::new(rhs.to_bits_le()));
+
+    let output = gate(&mut builder, &a[0], &b[0]);
+    builder
+        .compile_and_execute(&output.into())
+        .expect("Failed to execute logic circuit")
+}
+
+pub(crate) fn build_and_execute_conditional_swap<const N: usize>(
+    condition: &GarbledBit,
+    a: &GarbledUint<N>,
+    b: &GarbledUint<N>,
+) -> (GarbledUint<N>, GarbledUint<N>) {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(a);
+    let b = builder.input(b);
+    let s = builder.input(condition);
+
+    let (mut output, swapped_b) = builder.conditional_swap(&s[0], &a, &b);
+    output.push_all(&swapped_b);
+
+    let circuit = builder.compile(&output);
+    let result = get_executor()
+        .execute(&circuit, &builder.inputs, &[])
+        .expect("Failed to execute conditional swap circuit");
+    let (a, b) = result.split_at(a.len());
+    (GarbledUint::new(a.to_vec()), GarbledUint::new(b.to_vec()))
+}
+
+pub(crate) fn build_and_execute_widening_mul<const N: usize, const M: usize>(
+    lhs: &GarbledUint<N>,
+    rhs: &GarbledUint<N>,
+) -> GarbledUint<M> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+    let b = builder.input(rhs);
+
+    let output = builder.widening_mul(&a, &b);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute widening multiplication circuit")
+}
+
+pub(crate) fn build_and_execute_reduce_add<const N: usize>(
+    values: &[GarbledUint<N>],
+) -> GarbledUint<N> {
+    if values.is_empty() {
+        // all `N` bits, so the sum can be an operand of further `N`-bit circuits
+        return GarbledUint::constant(0);
+    }
+    let mut builder = WRK17CircuitBuilder::default();
+    let inputs: Vec<GateIndexVec> = values
+        .iter()
+        .map(|value| {
+            let mut bits = value.bits().into_owned();
+            bits.resize(N, false);
+            builder.input(&GarbledUint::<N>::new(bits))
+        })
+        .collect();
+
+    let output = builder.reduce_add(&inputs);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute sum circuit")
+}
+
+pub(crate) fn build_and_execute_div_rem<const N: usize>(
+    lhs: &GarbledUint<N>,
+    rhs: &GarbledUint<N>,
+    signed: bool,
+) -> (GarbledUint<N>, GarbledUint<N>) {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(lhs);
+    let b = builder.input(rhs);
+
+    let (mut output, remainder) = if signed {
+        builder.signed_div_rem(&a, &b)
+    } else {
+        builder.div_rem(&a, &b)
+    };
+    output.push_all(&remainder);
+
+    let circuit = builder.compile(&output);
+    let result = get_executor()
+        .execute(&circuit, &builder.inputs, &[])
+        .expect("Failed to execute division circuit");
+    let (quotient, remainder) = result.split_at(a.len());
+    (
+        GarbledUint::new(quotient.to_vec()),
+        GarbledUint::new(remainder.to_vec()),
+    )
+}
+
+pub(crate) fn build_and_execute_bitonic_sort<const N: usize>(
+    values: &[GarbledUint<N>],
+) -> Vec<GarbledUint<N>> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+
+    // Pad to the next power of two with all-ones sentinels, which sort to the end
+    let sentinel = GarbledUint::<N>::new(vec![true; N]);
+    let padding = values.len().next_power_of_two() - values.len();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let inputs: Vec<GateIndexVec> = values
+        .iter()
+        .chain(std::iter::repeat_n(&sentinel, padding))
+        .map(|value| {
+            let mut bits = value.bits().into_owned();
+            bits.resize(N, false);
+            builder.input(&GarbledUint::<N>::new(bits))
+        })
+        .collect();
+
+    let sorted = builder.bitonic_sort(&inputs);
+    let mut output = GateIndexVec::default();
+    for value in sorted.iter().take(values.len()) {
+        output.push_all(value);
+    }
+
+    let circuit = builder.compile(&output);
+    let result = get_executor()
+        .execute(&circuit, &builder.inputs, &[])
+        .expect("Failed to execute sorting circuit");
+    result
+        .chunks(N)
+        .map(|bits| GarbledUint::new(bits.to_vec()))
+        .collect()
+}
+
+pub(crate) fn build_and_execute_mux_store<const N: usize, const M: usize>(
+    index: &GarbledUint<M>,
+    values: &[GarbledUint<N>],
+    new_value: &GarbledUint<N>,
+) -> Vec<GarbledUint<N>> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut input = |value: &GarbledUint<N>| {
+        let mut bits = value.bits().into_owned();
+        bits.resize(N, false);
+        builder.input(&GarbledUint::<N>::new(bits))
+    };
+    let inputs: Vec<GateIndexVec> = values.iter().map(&mut input).collect();
+    let new_value = input(new_value);
+    let mut index_bits = index.bits().into_owned();
+    index_bits.resize(M, false);
+    let index = builder.input(&GarbledUint::<M>::new(index_bits));
+
+    let mut output = GateIndexVec::default();
+    for value in builder.mux_store(&index, &inputs, &new_value) {
+        output.push_all(&value);
+    }
+
+    let circuit = builder.compile(&output);
+    let result = get_executor()
+        .execute(&circuit, &builder.inputs, &[])
+        .expect("Failed to execute oblivious store circuit");
+    result
+        .chunks(N)
+        .map(|bits| GarbledUint::new(bits.to_vec()))
+        .collect()
+}
+
+pub(crate) fn build_and_execute_bytes_predicate<F>(
+    lhs: &[GarbledUint8],
+    rhs: &[GarbledUint8],
+    predicate: F,
+) -> GarbledBit
+where
+    F: FnOnce(&mut WRK17CircuitBuilder, &[GateIndexVec], &[GateIndexVec]) -> GateIndex,
+{
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut input = |byte: &GarbledUint8| {
+        let mut bits = byte.bits().into_owned();
+        bits.resize(8, false);
+        builder.input(&GarbledUint8::new(bits))
+    };
+    let a: Vec<GateIndexVec> = lhs.iter().map(&mut input).collect();
+    let b: Vec<GateIndexVec> = rhs.iter().map(&mut input).collect();
+
+    let result = predicate(&mut builder, &a, &b);
+    builder
+        .compile_and_execute(&vec![result].into())
+        .expect("Failed to execute byte string circuit")
+}
+
+pub(crate) fn build_and_execute_byte_predicate(
+    byte: &GarbledUint8,
+    predicate: fn(&mut WRK17CircuitBuilder, &GateIndexVec) -> GateIndex,
+) -> GarbledBit {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(byte);
+
+    let result = predicate(&mut builder, &a);
+    builder
+        .compile_and_execute(&vec![result].into())
+        .expect("Failed to execute byte predicate circuit")
+}
+
+pub(crate) fn build_and_execute_byte_map(
+    byte: &GarbledUint8,
+    map: fn(&mut WRK17CircuitBuilder, &GateIndexVec) -> GateIndexVec,
+) -> GarbledUint8 {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(byte);
+
+    let output = map(&mut builder, &a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute byte map circuit")
+}
 
-        for idx in (0..i).rev() {
-            let xn = self.push_xnor(&a[idx], &b[idx]);
-            let eq_i = self.push_and(&eq_list[idx + 1], &xn);
-            eq_list[idx] = eq_i;
+pub(crate) fn build_and_execute_bytes_xor(
+    lhs: &[GarbledUint8],
+    rhs: &[GarbledUint8],
+) -> Vec<GarbledUint8> {
+    if lhs.is_empty() {
+        return Vec::new();
+    }
 
-            let nt = self.push_not(&a[idx]);
-            let aa = self.push_and(&nt, &b[idx]);
-            let temp_lt = self.push_and(&eq_list[idx + 1], &aa);
-            lt_list[idx] = self.push_or(&lt_list[idx + 1], &temp_lt);
-        }
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut input = |byte: &GarbledUint8| {
+        let mut bits = byte.bits().into_owned();
+        bits.resize(8, false);
+        builder.input(&GarbledUint8::new(bits))
+    };
+    let a: Vec<GateIndexVec> = lhs.iter().map(&mut input).collect();
+    let b: Vec<GateIndexVec> = rhs.iter().map(&mut input).collect();
 
-        (lt_list[0], eq_list[0])
+    // every byte goes through the same circuit, so one execution covers the whole buffer
+    let mut output = GateIndexVec::default();
+    for (a, b) in a.iter().zip(&b) {
+        output.push_all(&builder.xor(a, b));
     }
+    let circuit = builder.compile(&output);
+    let result = get_executor()
+        .execute(&circuit, &builder.inputs, &[])
+        .expect("Failed to execute byte XOR circuit");
+    result
+        .chunks(8)
+        .map(|bits| GarbledUint8::new(bits.to_vec()))
+        .collect()
 }
 
-macro_rules! build_and_execute {
-    ($fn_name:ident, $op:ident) => {
-        pub(crate) fn $fn_name<const N: usize>(
-            lhs: &GarbledUint<N>,
-            rhs: &GarbledUint<N>,
-        ) -> GarbledUint<N> {
-            let mut builder = WRK17CircuitBuilder::default();
-            // Access the global CircuitBuilder instance
-            //let mut builder = WRK17CircuitBuilder::instance().lock().unwrap();
+pub(crate) fn build_and_execute_length_mask<const N: usize>(
+    len: &GarbledUint<N>,
+    capacity: usize,
+) -> Vec<GarbledBit> {
+    if capacity == 0 {
+        return Vec::new();
+    }
 
-            let a = builder.input(lhs);
-            let b = builder.input(rhs);
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut len_bits = len.bits().into_owned();
+    len_bits.resize(N, false);
+    let len = builder.input(&GarbledUint::<N>::new(len_bits));
+
+    let output = builder.length_mask(&len, capacity);
+    let circuit = builder.compile(&output.into());
+    let result = get_executor()
+        .execute(&circuit, &builder.inputs, &[])
+        .expect("Failed to execute length mask circuit");
+    result
+        .into_iter()
+        .map(|live| GarbledBit::new(vec![live]))
+        .collect()
+}
 
-            let output = builder.$op(&a, &b);
-            let circuit = builder.compile(&output);
+pub(crate) fn build_and_execute_mux_read<const N: usize, const M: usize>(
+    index: &GarbledUint<M>,
+    values: &[GarbledUint<N>],
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let inputs: Vec<GateIndexVec> = values
+        .iter()
+        .map(|value| {
+            let mut bits = value.bits().into_owned();
+            bits.resize(N, false);
+            builder.input(&GarbledUint::<N>::new(bits))
+        })
+        .collect();
+    let mut index_bits = index.bits().into_owned();
+    index_bits.resize(M, false);
+    let index = builder.input(&GarbledUint::<M>::new(index_bits));
+
+    let mut output = builder.mux_read(&index, &inputs);
+    if output.is_empty() {
+        let (zero, _) = builder.constant_wires();
+        output = GateIndexVec::new(vec![zero; N]);
+    }
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute oblivious read circuit")
+}
 
-            // Execute the circuit
-            builder
-                .execute(&circuit)
-                .expect("Failed to execute circuit")
-        }
+pub(crate) fn build_and_execute_rotate<const N: usize, const M: usize>(
+    value: &GarbledUint<N>,
+    amount: &GarbledUint<M>,
+    left: bool,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut value_bits = value.bits().into_owned();
+    value_bits.resize(N, false);
+    let value = builder.input(&GarbledUint::<N>::new(value_bits));
+    let mut amount_bits = amount.bits().into_owned();
+    amount_bits.resize(M, false);
+    let amount = builder.input(&GarbledUint::<M>::new(amount_bits));
+
+    let output = if left {
+        builder.rotate_left_by(&value, &amount)
+    } else {
+        builder.rotate_right_by(&value, &amount)
     };
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute rotation circuit")
 }
 
-build_and_execute!(build_and_execute_xor, xor);
-build_and_execute!(build_and_execute_and, and);
-build_and_execute!(build_and_execute_or, or);
-build_and_execute!(build_and_execute_nand, nand);
-build_and_execute!(build_and_execute_nor, nor);
-build_and_execute!(build_and_execute_xnor, xnor);
-build_and_execute!(build_and_execute_addition, add);
-build_and_execute!(build_and_execute_subtraction, sub);
-build_and_execute!(build_and_execute_multiplication, mul);
-build_and_execute!(build_and_execute_division, div);
-build_and_execute!(build_and_execute_remainder, rem);
+pub(crate) fn build_and_execute_select_one_hot<const N: usize, const K: usize>(
+    mask: &GarbledUint<K>,
+    values: &[GarbledUint<N>],
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let inputs: Vec<GateIndexVec> = values
+        .iter()
+        .map(|value| {
+            let mut bits = value.bits().into_owned();
+            bits.resize(N, false);
+            builder.input(&GarbledUint::<N>::new(bits))
+        })
+        .collect();
+    let mut mask_bits = mask.bits().into_owned();
+    mask_bits.resize(K, false);
+    let mask = builder.input(&GarbledUint::<K>::new(mask_bits));
+
+    let mut output = builder.select_one_hot(&mask, &inputs);
+    if output.is_empty() {
+        let (zero, _) = builder.constant_wires();
+        output = GateIndexVec::new(vec![zero; N]);
+    }
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute one-hot select circuit")
+}
 
-fn full_adder(
-    builder: &mut WRK17CircuitBuilder,
-    a: GateIndex,
-    b: GateIndex,
-    carry: Option<GateIndex>,
-) -> (GateIndex, Option<GateIndex>) {
-    let xor_ab = builder.len();
-    builder.gates.push(Gate::Xor(a, b));
+pub(crate) fn build_and_execute_broadcast<const N: usize>(bit: &GarbledBit) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut bit_bits = bit.bits().into_owned();
+    bit_bits.resize(1, false);
+    let bit = builder.input(&GarbledBit::new(bit_bits));
 
-    let sum = if let Some(c) = carry {
-        let sum_with_carry = builder.len();
-        builder.gates.push(Gate::Xor(xor_ab, c));
-        sum_with_carry
-    } else {
-        xor_ab
-    };
+    let output = builder.broadcast(&bit[0], N);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute broadcast circuit")
+}
 
-    let and_ab = builder.len();
-    builder.gates.push(Gate::And(a, b));
+pub(crate) fn build_and_execute_mask<const N: usize>(
+    cond: &GarbledBit,
+    x: &GarbledUint<N>,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut x_bits = x.bits().into_owned();
+    x_bits.resize(N, false);
+    let x = builder.input(&GarbledUint::<N>::new(x_bits));
+    let mut cond_bits = cond.bits().into_owned();
+    cond_bits.resize(1, false);
+    let cond = builder.input(&GarbledBit::new(cond_bits));
+
+    let output = builder.mask(&cond[0], &x);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute mask circuit")
+}
 
-    let new_carry = if let Some(c) = carry {
-        let and_axorb_c = builder.len();
-        builder.gates.push(Gate::And(xor_ab, c));
+pub(crate) fn build_and_execute_fixed_mul<const N: usize>(
+    a: &GarbledUint<N>,
+    b: &GarbledUint<N>,
+    frac: usize,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(a);
+    let b = builder.input(b);
 
-        let or_gate = builder.len();
-        builder.gates.push(Gate::Xor(and_ab, and_axorb_c));
-        Some(or_gate)
-    } else {
-        Some(and_ab)
+    let output = builder.fixed_mul(&a, &b, frac);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute fixed-point multiplication circuit")
+}
+
+pub(crate) fn build_and_execute_fixed_div<const N: usize>(
+    a: &GarbledUint<N>,
+    b: &GarbledUint<N>,
+    frac: usize,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(a);
+    let b = builder.input(b);
+
+    let output = builder.fixed_div(&a, &b, frac);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute fixed-point division circuit")
+}
+
+pub(crate) fn build_and_execute_intersection_size<const N: usize, const M: usize>(
+    a: &[GarbledUint<N>],
+    b: &[GarbledUint<N>],
+) -> GarbledUint<M> {
+    if a.is_empty() || b.is_empty() {
+        return GarbledUint::new(vec![false; M]);
+    }
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut input = |value: &GarbledUint<N>| {
+        let mut bits = value.bits().into_owned();
+        bits.resize(N, false);
+        builder.input(&GarbledUint::<N>::new(bits))
     };
+    let a: Vec<GateIndexVec> = a.iter().map(&mut input).collect();
+    let b: Vec<GateIndexVec> = b.iter().map(&mut input).collect();
 
-    (sum, new_carry)
+    let count = builder.intersection_size(&a, &b, M);
+    builder
+        .compile_and_execute(&count)
+        .expect("Failed to execute intersection size circuit")
 }
 
-fn full_subtractor(
-    builder: &mut WRK17CircuitBuilder,
-    a: &u32,
-    b: &u32,
-    borrow: &Option<u32>,
-) -> (u32, Option<u32>) {
-    // XOR gate for difference bit (a ⊕ b)
-    let xor_ab = builder.push_xor(a, b);
+pub(crate) fn build_and_execute_dot_product<const N: usize, const M: usize>(
+    a: &[GarbledUint<N>],
+    b: &[GarbledUint<N>],
+) -> GarbledUint<M> {
+    if a.is_empty() {
+        return GarbledUint::new(vec![false; M]);
+    }
 
-    // If borrow exists, XOR the result of the previous XOR with the borrow
-    let diff = if let Some(borrow) = borrow {
-        builder.push_xor(&xor_ab, borrow)
-    } else {
-        xor_ab
+    let mut builder = WRK17CircuitBuilder::default();
+    let mut input = |value: &GarbledUint<N>| {
+        let mut bits = value.bits().into_owned();
+        bits.resize(N, false);
+        builder.input(&GarbledUint::<N>::new(bits))
     };
+    let a: Vec<GateIndexVec> = a.iter().map(&mut input).collect();
+    let b: Vec<GateIndexVec> = b.iter().map(&mut input).collect();
 
-    // Compute the new borrow: (!a & b) | (a & borrow) | (!b & borrow)
-    let not_a = builder.push_not(a);
-    let and_not_a_b = builder.push_and(&not_a, b);
+    let output = builder.dot_product(&a, &b, M);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute dot product circuit")
+}
 
-    let new_borrow = if let Some(borrow) = borrow {
-        let and_a_borrow = builder.push_and(a, borrow);
-        let not_b = builder.push_not(b);
-        let and_not_b_borrow = builder.push_and(&not_b, borrow);
+pub(crate) fn build_and_execute_pow_mod<const N: usize>(
+    base: &GarbledUint<N>,
+    exponent: &GarbledUint<N>,
+    modulus: &GarbledUint<N>,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let base = builder.input(base);
+    let exponent = builder.input(exponent);
+    let modulus = builder.input(modulus);
 
-        // Combine borrow parts using XOR and Aa.len()D to simulate OR
-        let xor_borrow_parts = builder.push_xor(&and_not_a_b, &and_a_borrow);
-        builder.push_xor(&xor_borrow_parts, &and_not_b_borrow)
+    let output = builder.pow_mod(&base, &exponent, &modulus);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute modular exponentiation circuit")
+}
+
+pub(crate) fn build_and_execute_clamp<const N: usize>(
+    value: &GarbledUint<N>,
+    lo: &GarbledUint<N>,
+    hi: &GarbledUint<N>,
+    signed: bool,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let value = builder.input(value);
+    let lo = builder.input(lo);
+    let hi = builder.input(hi);
+
+    let output = if signed {
+        builder.signed_clamp(&value, &lo, &hi)
     } else {
-        and_not_a_b
+        builder.clamp(&value, &lo, &hi)
     };
 
-    (diff, Some(new_borrow))
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute clamp circuit")
 }
 
-fn partial_product_shift(
-    builder: &mut WRK17CircuitBuilder,
-    lhs: &GateIndexVec,
-    rhs: &GateIndexVec,
-    shift: usize,
-) -> GateIndexVec {
-    let mut shifted = GateIndexVec::default();
+pub(crate) fn build_and_execute_count_ones<const N: usize>(
+    input: &GarbledUint<N>,
+) -> GarbledUint<32> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(input);
 
-    for i in 0..lhs.len() {
-        if i < shift {
-            // For the lower bits, we push a constant 0.
-            let zero_bit = builder.push_not(&rhs[0]);
-            let and_gate = builder.push_and(&rhs[0], &zero_bit); // Constant 0
-            shifted.push(and_gate);
-        } else {
-            let lhs_bit = lhs[i - shift];
-            let and_gate = builder.push_and(&lhs_bit, &(rhs[shift]));
-            // Shift the bit from the input array
-            shifted.push(and_gate);
-        }
-    }
+    let count = builder.popcount(&a);
+    let (zero, _) = builder.constant_wires();
+    builder
+        .compile_and_execute(&zero_extend(&count, 32, zero))
+        .expect("Failed to execute popcount circuit")
+}
 
-    shifted
+pub(crate) fn build_and_execute_zero_count<const N: usize>(
+    input: &GarbledUint<N>,
+    leading: bool,
+) -> GarbledUint<32> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(input);
+
+    let count = if leading {
+        builder.leading_zeros(&a)
+    } else {
+        builder.trailing_zeros(&a)
+    };
+    let (zero, _) = builder.constant_wires();
+    builder
+        .compile_and_execute(&zero_extend(&count, 32, zero))
+        .expect("Failed to execute zero count circuit")
 }
 
-pub(crate) fn build_and_execute_equality<const N: usize>(
+pub(crate) fn build_and_execute_within_hamming<const N: usize>(
     lhs: &GarbledUint<N>,
     rhs: &GarbledUint<N>,
-) -> bool {
+    k: usize,
+) -> GarbledBit {
     let mut builder = WRK17CircuitBuilder::default();
     let a = builder.input(lhs);
     let b = builder.input(rhs);
 
-    let result = builder.eq(&a, &b);
-    let result = builder
-        .compile_and_execute::<1>(&vec![result].into())
-        .expect("Failed to execute equality circuit");
-    result.into()
+    let within = builder.within_hamming(&a, &b, k);
+    builder
+        .compile_and_execute(&within.into())
+        .expect("Failed to execute Hamming distance circuit")
 }
 
-pub(crate) fn build_and_execute_comparator<const N: usize>(
-    lhs: &GarbledUint<N>,
-    rhs: &GarbledUint<N>,
-) -> Ordering {
+pub(crate) fn build_and_execute_increment<const N: usize>(
+    input: &GarbledUint<N>,
+) -> GarbledUint<N> {
     let mut builder = WRK17CircuitBuilder::default();
-    let a = builder.input(lhs);
-    let b = builder.input(rhs);
+    let a = builder.input(input);
 
-    let (lt_output, eq_output) = builder.compare(&a, &b);
+    let output = builder.increment(&a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute increment circuit")
+}
 
-    let result = builder
-        .compile_and_execute::<2>(&vec![lt_output, eq_output].into())
-        .expect("Failed to execute equality circuit");
+pub(crate) fn build_and_execute_decrement<const N: usize>(
+    input: &GarbledUint<N>,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(input);
 
-    let lt = result.bits[0];
-    let eq = result.bits[1];
+    let output = builder.decrement(&a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute decrement circuit")
+}
 
-    if lt {
-        Ordering::Less
-    } else if eq {
-        Ordering::Equal
-    } else {
-        Ordering::Greater
-    }
+pub(crate) fn build_and_execute_negation<const N: usize>(input: &GarbledUint<N>) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(input);
+
+    let output = builder.neg(&a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute negation circuit")
 }
 
-pub(crate) fn build_and_execute_not<const N: usize>(input: &GarbledUint<N>) -> GarbledUint<N> {
+pub(crate) fn build_and_execute_isqrt<const N: usize>(input: &GarbledUint<N>) -> GarbledUint<N> {
     let mut builder = WRK17CircuitBuilder::default();
-    builder.input(input);
+    let a = builder.input(input);
 
-    let mut output_indices = GateIndexVec::default();
+    let output = builder.isqrt(&a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute square root circuit")
+}
 
-    let n = N as u32;
-    for i in 0..n {
-        let not_gate = builder.push_not(&i);
-        output_indices.push(not_gate);
-    }
+pub(crate) fn build_and_execute_to_gray<const N: usize>(input: &GarbledUint<N>) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(input);
+
+    let output = builder.to_gray(&a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute Gray encoding circuit")
+}
+
+pub(crate) fn build_and_execute_from_gray<const N: usize>(
+    input: &GarbledUint<N>,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(input);
+
+    let output = builder.from_gray(&a);
+    builder
+        .compile_and_execute(&output)
+        .expect("Failed to execute Gray decoding circuit")
+}
+
+pub(crate) fn build_and_execute_negate_if<const N: usize>(
+    condition: &GarbledBit,
+    x: &GarbledUint<N>,
+) -> GarbledUint<N> {
+    let mut builder = WRK17CircuitBuilder::default();
+    let x = builder.input(x);
+    let s = builder.input(condition);
 
+    let output = builder.negate_if(&s[0], &x);
     builder
-        .compile_and_execute(&output_indices)
-        .expect("Failed to execute a.len()OT circuit")
+        .compile_and_execute(&output)
+        .expect("Failed to execute conditional negate circuit")
 }
 
 pub(crate) fn build_and_execute_mux<const N: usize>(
-    condition: &GarbledBoolean,
+    condition: &GarbledBit,
     if_true: &GarbledUint<N>,
     if_false: &GarbledUint<N>,
 ) -> GarbledUint<N> {
@@ -678,7 +3231,7 @@ mod tests {
 
     #[test]
     fn test_build_and_execute_mux32() {
-        let s: GarbledBoolean = true.into();
+        let s: GarbledBit = true.into();
         let a: GarbledUint32 = 28347823_u32.into();
         let b: GarbledUint32 = 8932849_u32.into();
 
@@ -691,7 +3244,7 @@ mod tests {
 
     #[test]
     fn test_build_and_execute_mux64() {
-        let s: GarbledBoolean = true.into();
+        let s: GarbledBit = true.into();
         let a: GarbledUint64 = 23948323290804923_u64.into();
         let b: GarbledUint64 = 834289823983634323_u64.into();
 
@@ -894,6 +3447,40 @@ mod tests {
         assert_eq!(result_value, 2 + 5 + 3);
     }
 
+    #[test]
+    fn test_keyed_input_declared_once() {
+        let mut builder = WRK17CircuitBuilder::default();
+        let a: GarbledUint8 = 7_u8.into();
+        let b: GarbledUint8 = 5_u8.into();
+        let c: GarbledUint8 = 3_u8.into();
+
+        // a * b + a * c + a, looking `a` up by name at every use
+        let a_wires = builder.keyed_input("a", &a);
+        let b = builder.keyed_input("b", &b);
+        let c = builder.keyed_input("c", &c);
+        let a1 = builder.keyed_input("a", &a);
+        let ab = builder.mul(&a1, &b);
+        let a2 = builder.keyed_input("a", &a);
+        let ac = builder.mul(&a2, &c);
+        let sum = builder.add(&ab, &ac);
+        let a3 = builder.keyed_input("a", &a);
+        let output = builder.add(&sum, &a3);
+
+        assert_eq!(builder.keyed_input("a", &a), a_wires);
+        assert_eq!(builder.inputs().len(), 24);
+
+        let result: u8 = builder.compile_and_execute::<8>(&output).unwrap().into();
+        assert_eq!(result, 7 * 5 + 7 * 3 + 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "input `a` was already declared with a different value")]
+    fn test_keyed_input_rejects_different_value() {
+        let mut builder = WRK17CircuitBuilder::default();
+        builder.keyed_input("a", &GarbledUint8::from(1_u8));
+        builder.keyed_input("a", &GarbledUint8::from(2_u8));
+    }
+
     #[test]
     fn test_embedded_if_else() {
         let mut builder = WRK17CircuitBuilder::default();
@@ -903,7 +3490,7 @@ mod tests {
         let b: GarbledUint8 = 5_u8.into();
         let b = builder.input(&b);
 
-        let s: GarbledBoolean = false.into();
+        let s: GarbledBit = false.into();
         let s: GateIndexVec = builder.input(&s);
 
         // fails with 'cannot borrow `builder` as mutable more than once at a time'
@@ -0,0 +1,264 @@
+//! Typed encoding of circuit inputs and outputs.
+//!
+//! [`GarbledUint`] and [`GarbledInt`] encode as their `N` bits, and tuples of up to four
+//! encodable values encode as their elements one after another, so a circuit can return several
+//! values of different widths:
+//!
+//! ```
+//! use compute::prelude::*;
+//!
+//! let mut builder = WRK17CircuitBuilder::default();
+//! let a = builder.input(&GarbledUint8::from(200_u8));
+//! let b = builder.input(&GarbledUint8::from(100_u8));
+//! let (sum, carry) = builder.add_with_carry(&a, &b);
+//!
+//! let (sum, carry): (GarbledUint8, GarbledBit) =
+//!     builder.compile_and_decode(&(sum, carry.into())).unwrap();
+//! assert_eq!((u8::from(sum), bool::from(carry)), (44, true));
+//! ```
+//!
+//! `#[derive(GarbledEncode)]` implements [`GarbledEncode`] for a struct with named fields of
+//! primitive integer or `bool` type. The fields are laid out one after another in declaration
+//! order, each least-significant bit first, and the derive also declares a `<Name>Wires` struct
+//! with one [`GateIndexVec`] per field, so a circuit can work on `employee.salary` instead of a
+//! flat list of inputs.
+//!
+//! ```
+//! use compute::prelude::*;
+//!
+//! #[derive(GarbledEncode, Debug, PartialEq)]
+//! struct Employee {
+//!     salary: u32,
+//!     age: u8,
+//! }
+//!
+//! let mut builder = WRK17CircuitBuilder::default();
+//! let employee = builder.input_encoded(&Employee { salary: 5000, age: 41 });
+//! let bonus = builder.constant(&GarbledUint32::from(250_u32));
+//! let one = builder.constant(&GarbledUint8::one());
+//!
+//! let raised = EmployeeWires {
+//!     salary: builder.add(&employee.salary, &bonus),
+//!     age: builder.add(&employee.age, &one),
+//! };
+//! let result: Employee = builder.compile_and_decode(&raised).unwrap();
+//! assert_eq!(result, Employee { salary: 5250, age: 42 });
+//! ```
+
+use crate::error::CircuitError;
+use crate::executor::get_executor;
+use crate::int::GarbledInt;
+use crate::operations::circuits::builder::WRK17CircuitBuilder;
+use crate::operations::circuits::types::GateIndexVec;
+use crate::small::SmallGarbledUint;
+use crate::uint::GarbledUint;
+
+/// A value with a fixed-width bit encoding that can enter and leave a circuit.
+pub trait GarbledEncode: Sized {
+    /// The wires of an encoded value inside a circuit.
+    type Wires;
+
+    /// The number of bits in the encoding.
+    const WIDTH: usize;
+
+    /// Returns the `WIDTH` bits of the value.
+    fn encode(&self) -> Vec<bool>;
+
+    /// Rebuilds a value from its encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` does not hold exactly `WIDTH` bits.
+    fn decode(bits: &[bool]) -> Self;
+
+    /// Groups `WIDTH` wires, laid out like [`GarbledEncode::encode`], into `Self::Wires`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` does not hold exactly `WIDTH` wires.
+    fn wires(indices: &GateIndexVec) -> Self::Wires;
+
+    /// Flattens `Self::Wires` back into the layout of [`GarbledEncode::encode`].
+    fn indices(wires: &Self::Wires) -> GateIndexVec;
+}
+
+impl WRK17CircuitBuilder {
+    /// Adds the encoding of `value` as input wires.
+    pub fn input_encoded<T: GarbledEncode>(&mut self, value: &T) -> T::Wires {
+        let indices = self.input_bits(&value.encode());
+        T::wires(&indices)
+    }
+
+    /// Adds `T::WIDTH` input wires for a value the evaluator supplies when the circuit runs; see
+    /// [`WRK17CircuitBuilder::evaluator_input_bits`].
+    pub fn evaluator_input_encoded<T: GarbledEncode>(&mut self) -> T::Wires {
+        let indices = self.evaluator_input_bits(T::WIDTH);
+        T::wires(&indices)
+    }
+
+    /// Folds the encoding of the public `value` into the shared constant wires, without adding
+    /// inputs; see [`WRK17CircuitBuilder::constant`].
+    pub fn constant_encoded<T: GarbledEncode>(&mut self, value: &T) -> T::Wires {
+        let (zero, one) = self.constant_wires();
+        let indices: Vec<_> = value
+            .encode()
+            .into_iter()
+            .map(|bit| if bit { one } else { zero })
+            .collect();
+        T::wires(&indices.into())
+    }
+
+    /// Compiles a circuit with `wires` as its outputs, executes it and decodes the result.
+    pub fn compile_and_decode<T: GarbledEncode>(
+        &self,
+        wires: &T::Wires,
+    ) -> Result<T, CircuitError> {
+        self.compile_and_decode_with_evaluator_inputs(wires, &[])
+    }
+
+    /// Like [`WRK17CircuitBuilder::compile_and_decode`], with the bits for the wires added by
+    /// [`WRK17CircuitBuilder::evaluator_input_bits`].
+    pub fn compile_and_decode_with_evaluator_inputs<T: GarbledEncode>(
+        &self,
+        wires: &T::Wires,
+        evaluator_inputs: &[bool],
+    ) -> Result<T, CircuitError> {
+        let circuit = self.compile(&T::indices(wires));
+        let result = get_executor().execute(&circuit, self.inputs(), evaluator_inputs)?;
+        Ok(T::decode(&result))
+    }
+}
+
+impl<const N: usize> GarbledEncode for GarbledUint<N> {
+    type Wires = GateIndexVec;
+
+    const WIDTH: usize = N;
+
+    fn encode(&self) -> Vec<bool> {
+        self.to_bits_le()
+    }
+
+    fn decode(bits: &[bool]) -> Self {
+        assert_width(bits.len(), N, "bits");
+        GarbledUint::new(bits.to_vec())
+    }
+
+    fn wires(indices: &GateIndexVec) -> Self::Wires {
+        assert_width(indices.len(), N, "wires");
+        indices.clone()
+    }
+
+    fn indices(wires: &Self::Wires) -> GateIndexVec {
+        wires.clone()
+    }
+}
+
+impl<const N: usize> GarbledEncode for SmallGarbledUint<N> {
+    type Wires = GateIndexVec;
+
+    const WIDTH: usize = N;
+
+    fn encode(&self) -> Vec<bool> {
+        self.iter_bits().collect()
+    }
+
+    fn decode(bits: &[bool]) -> Self {
+        assert_width(bits.len(), N, "bits");
+        SmallGarbledUint::from_word(
+            bits.iter()
+                .enumerate()
+                .fold(0, |word, (i, &bit)| word | ((bit as u128) << i)),
+        )
+    }
+
+    fn wires(indices: &GateIndexVec) -> Self::Wires {
+        assert_width(indices.len(), N, "wires");
+        indices.clone()
+    }
+
+    fn indices(wires: &Self::Wires) -> GateIndexVec {
+        wires.clone()
+    }
+}
+
+impl<const N: usize> GarbledEncode for GarbledInt<N> {
+    type Wires = GateIndexVec;
+
+    const WIDTH: usize = N;
+
+    fn encode(&self) -> Vec<bool> {
+        self.to_bits_le()
+    }
+
+    fn decode(bits: &[bool]) -> Self {
+        assert_width(bits.len(), N, "bits");
+        GarbledInt::new(bits.to_vec())
+    }
+
+    fn wires(indices: &GateIndexVec) -> Self::Wires {
+        assert_width(indices.len(), N, "wires");
+        indices.clone()
+    }
+
+    fn indices(wires: &Self::Wires) -> GateIndexVec {
+        wires.clone()
+    }
+}
+
+// Tuples encode their elements in order; each element checks its own share of the width
+macro_rules! impl_tuple_encode {
+    ($($element:ident),+) => {
+        impl<$($element: GarbledEncode),+> GarbledEncode for ($($element,)+) {
+            type Wires = ($($element::Wires,)+);
+
+            const WIDTH: usize = 0 $(+ $element::WIDTH)+;
+
+            #[allow(non_snake_case)]
+            fn encode(&self) -> Vec<bool> {
+                let ($($element,)+) = self;
+                let mut bits = Vec::with_capacity(Self::WIDTH);
+                $(bits.extend($element.encode());)+
+                bits
+            }
+
+            fn decode(bits: &[bool]) -> Self {
+                assert_width(bits.len(), Self::WIDTH, "bits");
+                let mut offset = 0;
+                ($({
+                    offset += $element::WIDTH;
+                    $element::decode(&bits[offset - $element::WIDTH..offset])
+                },)+)
+            }
+
+            fn wires(indices: &GateIndexVec) -> Self::Wires {
+                assert_width(indices.len(), Self::WIDTH, "wires");
+                let mut offset = 0;
+                ($({
+                    offset += $element::WIDTH;
+                    let range = offset - $element::WIDTH..offset;
+                    $element::wires(&range.map(|i| indices[i]).collect::<Vec<_>>().into())
+                },)+)
+            }
+
+            #[allow(non_snake_case)]
+            fn indices(wires: &Self::Wires) -> GateIndexVec {
+                let ($($element,)+) = wires;
+                let mut indices = GateIndexVec::with_capacity(Self::WIDTH);
+                $(indices.push_all(&$element::indices($element));)+
+                indices
+            }
+        }
+    };
+}
+
+impl_tuple_encode!(A, B);
+impl_tuple_encode!(A, B, C);
+impl_tuple_encode!(A, B, C, D);
+
+fn assert_width(actual: usize, expected: usize, what: &str) {
+    assert_eq!(
+        actual, expected,
+        "expected {} {}, got {}",
+        expected, what, actual
+    );
+}
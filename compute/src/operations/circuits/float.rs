@@ -0,0 +1,438 @@
+//! IEEE 754 floating-point gadgets for [`WRK17CircuitBuilder`].
+//!
+//! Operands are unpacked into a sign, an exponent and a significand, combined with the integer
+//! gadgets, and packed again by `round_pack`, which follows Berkeley SoftFloat's
+//! `roundPackTo*`: the significand carries a few extra round bits below its last place, and the
+//! final rounding, underflow to subnormals and overflow to infinity all happen in one place.
+//! Every step is computed for every input and selected with muxes, so the circuit never
+//! depends on the values.
+
+use crate::operations::circuits::builder::{GateIndex, WRK17CircuitBuilder};
+use crate::operations::circuits::traits::CircuitExecutor;
+use crate::operations::circuits::types::GateIndexVec;
+
+// Bits kept below the significand's last place until rounding: guard, round and sticky
+const ROUND_BITS: usize = 3;
+
+type Bits = Vec<GateIndex>;
+
+// The `(exponent, fraction)` widths of the IEEE 754 format with `width` bits
+fn layout(width: usize) -> (usize, usize) {
+    match width {
+        32 => (8, 23),
+        64 => (11, 52),
+        _ => panic!(
+            "GarbledFloat<{}> is not an IEEE 754 format; use 32 or 64",
+            width
+        ),
+    }
+}
+
+// One operand split into its fields. `exp` is the biased exponent widened to the signed
+// working width, with subnormals (and zero) given exponent 1 and no implicit bit.
+struct Unpacked {
+    sign: GateIndex,
+    exp: Bits,
+    sig: Bits,
+    is_nan: GateIndex,
+    is_inf: GateIndex,
+    is_zero: GateIndex,
+}
+
+impl WRK17CircuitBuilder {
+    /// IEEE 754 addition of two values of the same format, rounding to nearest, ties to even.
+    pub fn float_add(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let (e, m) = layout(a.len());
+        let width = exp_width(e);
+        let (zero, _) = self.constant_wires();
+        let a = bits(a);
+        let b = bits(b);
+
+        // Order the operands by magnitude so the larger one fixes the sign and the exponent
+        // difference is non-negative
+        let (a_smaller, _) = self.compare(&a[..m + e].to_vec().into(), &b[..m + e].to_vec().into());
+        let larger_bits = self.select(a_smaller, &a, &b);
+        let smaller_bits = self.select(a_smaller, &b, &a);
+        let x = self.unpack(&larger_bits, e, m, width);
+        let y = self.unpack(&smaller_bits, e, m, width);
+        let subtract = self.push_xor(&x.sign, &y.sign);
+
+        // Align the smaller significand, keeping the bits shifted out as a sticky bit
+        let wide = m + ROUND_BITS + 2;
+        let sig_x = widen(&x.sig, ROUND_BITS, wide, zero);
+        let sig_y = widen(&y.sig, ROUND_BITS, wide, zero);
+        let distance = self.sub_bits(&x.exp, &y.exp);
+        let sig_y = self.shift_right_jam(&sig_y, &distance);
+
+        let sum = self.add_bits(&sig_x, &sig_y);
+        let difference = self.sub_bits(&sig_x, &sig_y);
+        let sig = self.select(subtract, &sum, &difference);
+
+        // A carry out of the implicit bit moves the point one place left
+        let carry = sig[wide - 1];
+        let shifted = self.shift_right_jam_one(&sig);
+        let sig = self.select(carry, &sig, &shifted);
+        let incremented = bits(&self.increment(&x.exp.clone().into()));
+        let exp = self.select(carry, &x.exp, &incremented);
+
+        // Cancellation leaves leading zeros; shift them out, stopping at the subnormal exponent
+        let (sig, exp) = self.normalize(&sig, &exp, m + ROUND_BITS, true);
+        let exp = bits(&self.decrement(&exp.into()));
+        let result = self.round_pack(x.sign, &exp, &sig, e, m);
+
+        // An exact zero is +0 unless both operands were -0
+        let not_subtract = self.push_not(&subtract);
+        let zero_sign = self.push_and(&x.sign, &not_subtract);
+        let any_set = self.any(&sig);
+        let is_zero = self.push_not(&any_set);
+        let signed_zero = self.signed_constant(zero_sign, e, m, false, false);
+        let result = self.select(is_zero, &result, &signed_zero);
+
+        // The larger operand is NaN or infinite: it is the result, except for inf - inf
+        let both_inf = self.push_and(&x.is_inf, &y.is_inf);
+        let invalid = self.push_and(&both_inf, &subtract);
+        let nan = self.quiet_nan(e, m);
+        let special = self.select(invalid, &larger_bits, &nan);
+        let x_special = self.push_or(&x.is_nan, &x.is_inf);
+        self.select(x_special, &result, &special).into()
+    }
+
+    /// IEEE 754 subtraction, `a + (-b)`; negating only flips the sign wire.
+    pub fn float_sub(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let mut negated = bits(b);
+        let sign = negated.len() - 1;
+        negated[sign] = self.push_not(&negated[sign]);
+        self.float_add(a, &negated.into())
+    }
+
+    /// IEEE 754 multiplication of two values of the same format, rounding to nearest, ties to
+    /// even. The significands are multiplied exactly at double width before rounding.
+    pub fn float_mul(&mut self, a: &GateIndexVec, b: &GateIndexVec) -> GateIndexVec {
+        let (e, m) = layout(a.len());
+        let width = exp_width(e);
+        let (zero, _) = self.constant_wires();
+        let x = self.unpack(&bits(a), e, m, width);
+        let y = self.unpack(&bits(b), e, m, width);
+        let sign = self.push_xor(&x.sign, &y.sign);
+
+        let product_width = 2 * m + 2;
+        let sig_x = widen(&x.sig, 0, product_width, zero);
+        let sig_y = widen(&y.sig, 0, product_width, zero);
+        let product = bits(&self.mul(&sig_x.into(), &sig_y.into()));
+
+        // Subnormal operands leave the leading one low, so normalize without a floor and let
+        // round_pack shift back down if the result is subnormal
+        let exp = self.add_bits(&x.exp, &y.exp);
+        let bias = self.constant_bits((1 << (e - 1)) - 1, width);
+        let exp = self.sub_bits(&exp, &bias);
+        let (product, exp) = self.normalize(&product, &exp, product_width - 1, false);
+
+        // Keep the top m + ROUND_BITS + 1 bits with the implicit bit at m + ROUND_BITS, folding
+        // the rest into the sticky bit
+        let dropped = m + 1 - ROUND_BITS;
+        let mut sig = product[dropped..].to_vec();
+        sig[0] = {
+            let lost = self.any(&product[..dropped]);
+            self.push_or(&sig[0], &lost)
+        };
+        sig.push(zero);
+        let result = self.round_pack(sign, &exp, &sig, e, m);
+
+        let either_zero = self.push_or(&x.is_zero, &y.is_zero);
+        let either_inf = self.push_or(&x.is_inf, &y.is_inf);
+        let either_nan = self.push_or(&x.is_nan, &y.is_nan);
+        let inf_times_zero = self.push_and(&either_inf, &either_zero);
+        let invalid = self.push_or(&either_nan, &inf_times_zero);
+
+        let signed_zero = self.signed_constant(sign, e, m, false, false);
+        let result = self.select(either_zero, &result, &signed_zero);
+        let infinity = self.signed_constant(sign, e, m, true, false);
+        let result = self.select(either_inf, &result, &infinity);
+        let nan = self.quiet_nan(e, m);
+        self.select(invalid, &result, &nan).into()
+    }
+
+    /// IEEE 754 comparison, returning `(a < b, a == b, a > b)`.
+    ///
+    /// Unlike [`WRK17CircuitBuilder::comparison`], no wire is set when either operand is NaN,
+    /// and `-0` equals `+0`.
+    pub fn float_comparison(
+        &mut self,
+        a: &GateIndexVec,
+        b: &GateIndexVec,
+    ) -> (GateIndex, GateIndex, GateIndex) {
+        let (e, m) = layout(a.len());
+        let a = bits(a);
+        let b = bits(b);
+
+        // Flipping the sign bit of positive values and every bit of negative ones turns the
+        // encoding into one whose unsigned order is the numeric order, with -0 just below +0
+        let key_a = self.order_key(&a);
+        let key_b = self.order_key(&b);
+        let (lt, eq) = self.compare(&key_a.into(), &key_b.into());
+
+        let a_nonzero = self.any(&a[..m + e]);
+        let b_nonzero = self.any(&b[..m + e]);
+        let either_nonzero = self.push_or(&a_nonzero, &b_nonzero);
+        let a_nan = self.is_nan(&a, e, m);
+        let b_nan = self.is_nan(&b, e, m);
+        let unordered = self.push_or(&a_nan, &b_nan);
+        let ordered = self.push_not(&unordered);
+
+        let both_zero = self.push_not(&either_nonzero);
+        let eq = self.push_or(&eq, &both_zero);
+        let eq = self.push_and(&eq, &ordered);
+        let lt_or_eq = self.push_or(&lt, &eq);
+        let gt = self.push_not(&lt_or_eq);
+        let gt = self.push_and(&gt, &ordered);
+        let lt = self.push_and(&lt, &either_nonzero);
+        let lt = self.push_and(&lt, &ordered);
+        (lt, eq, gt)
+    }
+
+    // Rounds and packs sign * sig * 2^(exp + 1 - bias - (m + ROUND_BITS)). `sig` has its
+    // implicit bit at m + ROUND_BITS (or lower for subnormal results) and one bit of headroom;
+    // `exp` is one less than the biased exponent, so adding the implicit bit while packing
+    // fixes it up, and a rounding carry or a subnormal rounding up to the smallest normal
+    // moves into the exponent on its own.
+    fn round_pack(
+        &mut self,
+        sign: GateIndex,
+        exp: &[GateIndex],
+        sig: &[GateIndex],
+        e: usize,
+        m: usize,
+    ) -> Bits {
+        let (zero, one) = self.constant_wires();
+        let width = exp.len();
+
+        // Below the normal range: shift right into a subnormal, keeping a sticky bit
+        let negative = exp[width - 1];
+        let zeros = vec![zero; width];
+        let distance = self.sub_bits(&zeros, exp);
+        let shifted = self.shift_right_jam(sig, &distance);
+        let sig = self.select(negative, sig, &shifted);
+        let exp = self.select(negative, exp, &zeros);
+
+        // Round to nearest by adding half a unit in the last place, then clear the last bit on
+        // an exact tie so ties go to even
+        let half = self.constant_bits(1 << (ROUND_BITS - 1), sig.len());
+        let rounded = self.add_bits(&sig, &half);
+        let below_half = self.any(&sig[..ROUND_BITS - 1]);
+        let not_below_half = self.push_not(&below_half);
+        let tie = self.push_and(&sig[ROUND_BITS - 1], &not_below_half);
+        let not_tie = self.push_not(&tie);
+        let mut kept = rounded[ROUND_BITS..].to_vec();
+        kept[0] = self.push_and(&kept[0], &not_tie);
+
+        let mut shifted_exp = vec![zero; m];
+        shifted_exp.extend_from_slice(&exp);
+        let kept = widen(&kept, 0, shifted_exp.len(), zero);
+        let packed = self.add_bits(&shifted_exp, &kept);
+
+        // An exponent field of all ones or more overflowed to infinity
+        let max_field: Vec<bool> = (0..width).map(|i| i < e).collect();
+        let (finite, _) = self.compare_const(&packed[m..].to_vec().into(), &max_field);
+        let mut infinity = vec![zero; m];
+        infinity.extend(vec![one; e]);
+        let mut result = self.select(finite, &infinity, &packed[..m + e]);
+        result.push(sign);
+        result
+    }
+
+    fn unpack(&mut self, x: &[GateIndex], e: usize, m: usize, width: usize) -> Unpacked {
+        let (zero, _) = self.constant_wires();
+        let fraction = &x[..m];
+        let field = &x[m..m + e];
+
+        let field_nonzero = self.any(field);
+        let field_max = self.all(field);
+        let fraction_nonzero = self.any(fraction);
+        let fraction_zero = self.push_not(&fraction_nonzero);
+        let field_zero = self.push_not(&field_nonzero);
+
+        let mut sig = fraction.to_vec();
+        sig.push(field_nonzero);
+        let mut exp = field.to_vec();
+        exp[0] = self.push_or(&exp[0], &field_zero);
+
+        Unpacked {
+            sign: x[m + e],
+            exp: widen(&exp, 0, width, zero),
+            sig,
+            is_nan: self.push_and(&field_max, &fraction_nonzero),
+            is_inf: self.push_and(&field_max, &fraction_zero),
+            is_zero: self.push_and(&field_zero, &fraction_zero),
+        }
+    }
+
+    fn is_nan(&mut self, x: &[GateIndex], e: usize, m: usize) -> GateIndex {
+        let field_max = self.all(&x[m..m + e]);
+        let fraction_nonzero = self.any(&x[..m]);
+        self.push_and(&field_max, &fraction_nonzero)
+    }
+
+    fn order_key(&mut self, x: &[GateIndex]) -> Bits {
+        let sign = x[x.len() - 1];
+        let mut key: Bits = x.iter().map(|bit| self.push_xor(bit, &sign)).collect();
+        let top = key.len() - 1;
+        key[top] = self.push_not(&sign);
+        key
+    }
+
+    // Shifts sig left until bit `top` is set, lowering exp by the same amount, one power of
+    // two at a time from the largest. With `floored` a step is only taken while exp stays
+    // above 1, the exponent of subnormals.
+    fn normalize(
+        &mut self,
+        sig: &[GateIndex],
+        exp: &[GateIndex],
+        top: usize,
+        floored: bool,
+    ) -> (Bits, Bits) {
+        let (zero, _) = self.constant_wires();
+        let mut sig = sig.to_vec();
+        let mut exp = exp.to_vec();
+
+        let mut step = 1;
+        while step * 2 <= top + 1 {
+            step *= 2;
+        }
+        while step > 0 {
+            let high_bits = self.any(&sig[top + 1 - step..=top]);
+            let mut take = self.push_not(&high_bits);
+            let step_bits = self.constant_bits(step as u64, exp.len());
+            if floored {
+                let step_value: Vec<bool> = (0..exp.len()).map(|i| (step >> i) & 1 == 1).collect();
+                let (lt, eq) = self.signed_compare_const(&exp.clone().into(), &step_value);
+                let at_most = self.push_or(&lt, &eq);
+                let above = self.push_not(&at_most);
+                take = self.push_and(&take, &above);
+            }
+
+            let shifted: Bits = (0..sig.len())
+                .map(|i| if i >= step { sig[i - step] } else { zero })
+                .collect();
+            sig = self.select(take, &sig, &shifted);
+            let lowered = self.sub_bits(&exp, &step_bits);
+            exp = self.select(take, &exp, &lowered);
+            step /= 2;
+        }
+        (sig, exp)
+    }
+
+    // x >> distance, ORing every bit shifted out into bit 0 so rounding still sees them.
+    // A barrel shifter: stage k shifts by 2^k when bit k of distance is set.
+    fn shift_right_jam(&mut self, x: &[GateIndex], distance: &[GateIndex]) -> Bits {
+        let (zero, _) = self.constant_wires();
+        let width = x.len();
+        let mut x = x.to_vec();
+        for (k, bit) in distance.iter().enumerate() {
+            let step = if k < usize::BITS as usize - 1 {
+                1 << k
+            } else {
+                usize::MAX
+            };
+            let lost = self.any(&x[..step.min(width)]);
+            let mut shifted: Bits = (0..width)
+                .map(|i| if step < width - i { x[i + step] } else { zero })
+                .collect();
+            shifted[0] = self.push_or(&shifted[0], &lost);
+            x = self.select(*bit, &x, &shifted);
+        }
+        x
+    }
+
+    fn shift_right_jam_one(&mut self, x: &[GateIndex]) -> Bits {
+        let (zero, _) = self.constant_wires();
+        let mut shifted = x[1..].to_vec();
+        shifted.push(zero);
+        shifted[0] = self.push_or(&shifted[0], &x[0]);
+        shifted
+    }
+
+    // condition ? if_true : if_false, bit by bit
+    fn select(
+        &mut self,
+        condition: GateIndex,
+        if_false: &[GateIndex],
+        if_true: &[GateIndex],
+    ) -> Bits {
+        if_false
+            .iter()
+            .zip(if_true)
+            .map(|(f, t)| self.push_mux(&condition, f, t))
+            .collect()
+    }
+
+    fn all(&mut self, bits: &[GateIndex]) -> GateIndex {
+        match bits.split_first() {
+            Some((first, rest)) => rest
+                .iter()
+                .fold(*first, |acc, bit| self.push_and(&acc, bit)),
+            None => self.constant_wires().1,
+        }
+    }
+
+    fn add_bits(&mut self, a: &[GateIndex], b: &[GateIndex]) -> Bits {
+        bits(&self.add(&a.to_vec().into(), &b.to_vec().into()))
+    }
+
+    fn sub_bits(&mut self, a: &[GateIndex], b: &[GateIndex]) -> Bits {
+        bits(&self.sub(&a.to_vec().into(), &b.to_vec().into()))
+    }
+
+    fn constant_bits(&mut self, value: u64, width: usize) -> Bits {
+        let (zero, one) = self.constant_wires();
+        (0..width)
+            .map(|i| {
+                if i < 64 && (value >> i) & 1 == 1 {
+                    one
+                } else {
+                    zero
+                }
+            })
+            .collect()
+    }
+
+    // ±0 or ±infinity, optionally with the quiet bit set to make a NaN
+    fn signed_constant(
+        &mut self,
+        sign: GateIndex,
+        e: usize,
+        m: usize,
+        max_exponent: bool,
+        quiet: bool,
+    ) -> Bits {
+        let (zero, one) = self.constant_wires();
+        let mut result = vec![zero; m];
+        if quiet {
+            result[m - 1] = one;
+        }
+        result.extend(vec![if max_exponent { one } else { zero }; e]);
+        result.push(sign);
+        result
+    }
+
+    fn quiet_nan(&mut self, e: usize, m: usize) -> Bits {
+        let (zero, _) = self.constant_wires();
+        self.signed_constant(zero, e, m, true, true)
+    }
+}
+
+// Signed exponent width with room for the sum of two exponents and the normalization shift
+fn exp_width(e: usize) -> usize {
+    e + 3
+}
+
+fn bits(x: &GateIndexVec) -> Bits {
+    x.iter().copied().collect()
+}
+
+// Places x at bit `shift` of a `width`-bit vector, filling the rest with zero
+fn widen(x: &[GateIndex], shift: usize, width: usize, zero: GateIndex) -> Bits {
+    let mut result = vec![zero; shift];
+    result.extend_from_slice(x);
+    result.resize(width, zero);
+    result
+}
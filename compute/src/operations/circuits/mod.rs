@@ -1,3 +1,9 @@
 pub mod builder;
+pub mod encode;
+pub mod float;
+pub mod privacy;
+pub mod schedule;
+pub mod streaming;
 pub mod traits;
+pub mod typed;
 pub mod types;
@@ -0,0 +1,157 @@
+//! Plaintext values and their encoded counterparts inside a circuit.
+//!
+//! [`GarbledUint`](crate::uint::GarbledUint) and the other value types hold their bits in the
+//! clear, so holding one means knowing it. These wrappers make the difference visible in the
+//! types: a [`Plain<T>`] is a value the local party knows, and an [`Encoded<T>`] is the same
+//! value after [`Plain::encode`] turned it into circuit inputs. An `Encoded<T>` only holds
+//! wires, so there is nothing to read until [`Encoded::reveal`] runs the circuit and hands back
+//! a `Plain<T>`. Public values such as thresholds or fees go in a [`Constant<T>`], which the
+//! builder folds into fixed wires instead of spending inputs on them. The other party's value is
+//! never known locally, so it starts out as an `Encoded<T>` from [`Encoded::evaluator_input`].
+//!
+//! ```
+//! use compute::prelude::*;
+//!
+//! let mut builder = WRK17CircuitBuilder::default();
+//! let salary = Plain::new(GarbledUint32::from(5000_u32)).encode(&mut builder);
+//! let bonus = Constant::new(GarbledUint32::from(250_u32)).encode(&mut builder);
+//!
+//! let total: Encoded<GarbledUint32> =
+//!     Encoded::from_wires(builder.add(salary.wires(), bonus.wires()));
+//! let total = total.reveal(&builder).unwrap();
+//! assert_eq!(u32::from(total.into_inner()), 5250);
+//! ```
+
+use crate::error::CircuitError;
+use crate::operations::circuits::builder::WRK17CircuitBuilder;
+use crate::operations::circuits::encode::GarbledEncode;
+use core::fmt::{Debug, Formatter};
+
+/// A value known to the local party.
+#[derive(Debug, Clone)]
+pub struct Plain<T> {
+    value: T,
+}
+
+impl<T> Plain<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: GarbledEncode> Plain<T> {
+    /// Contributes the value as inputs of the circuit being built. The returned handle only
+    /// refers to the input wires.
+    pub fn encode(&self, builder: &mut WRK17CircuitBuilder) -> Encoded<T> {
+        Encoded {
+            wires: builder.input_encoded(&self.value),
+        }
+    }
+}
+
+/// A public value, known to every party and folded into the circuit as constant wires.
+#[derive(Debug, Clone)]
+pub struct Constant<T> {
+    value: T,
+}
+
+impl<T> Constant<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: GarbledEncode> Constant<T> {
+    /// Folds the value into the circuit being built without consuming input wires. Like
+    /// [`WRK17CircuitBuilder::constant`], this must come after all inputs.
+    pub fn encode(&self, builder: &mut WRK17CircuitBuilder) -> Encoded<T> {
+        Encoded {
+            wires: builder.constant_encoded(&self.value),
+        }
+    }
+}
+
+/// A value inside a circuit, known only as the wires that carry it.
+pub struct Encoded<T: GarbledEncode> {
+    wires: T::Wires,
+}
+
+impl<T: GarbledEncode> Encoded<T> {
+    /// Wraps the wires of a value computed in the circuit, e.g. the output of a gadget.
+    pub fn from_wires(wires: T::Wires) -> Self {
+        Self { wires }
+    }
+
+    /// Adds the input wires of a value the evaluator contributes when the circuit runs. Only the
+    /// wire positions are recorded; the evaluator supplies the bits of [`GarbledEncode::encode`]
+    /// to [`Encoded::reveal_with_evaluator_inputs`].
+    pub fn evaluator_input(builder: &mut WRK17CircuitBuilder) -> Self {
+        Self {
+            wires: builder.evaluator_input_encoded::<T>(),
+        }
+    }
+
+    pub fn wires(&self) -> &T::Wires {
+        &self.wires
+    }
+
+    pub fn into_wires(self) -> T::Wires {
+        self.wires
+    }
+
+    /// Compiles the circuit with this value as its output and executes it, revealing the result
+    /// to the local party.
+    pub fn reveal(&self, builder: &WRK17CircuitBuilder) -> Result<Plain<T>, CircuitError> {
+        builder.compile_and_decode(&self.wires).map(Plain::new)
+    }
+
+    /// Like [`Encoded::reveal`], with the evaluator's encoded inputs concatenated in the order
+    /// they were added with [`Encoded::evaluator_input`].
+    pub fn reveal_with_evaluator_inputs(
+        &self,
+        builder: &WRK17CircuitBuilder,
+        evaluator_inputs: &[bool],
+    ) -> Result<Plain<T>, CircuitError> {
+        builder
+            .compile_and_decode_with_evaluator_inputs(&self.wires, evaluator_inputs)
+            .map(Plain::new)
+    }
+}
+
+impl<T: GarbledEncode> Clone for Encoded<T>
+where
+    T::Wires: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            wires: self.wires.clone(),
+        }
+    }
+}
+
+impl<T: GarbledEncode> Debug for Encoded<T>
+where
+    T::Wires: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Encoded")
+            .field("wires", &self.wires)
+            .finish()
+    }
+}
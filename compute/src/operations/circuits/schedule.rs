@@ -0,0 +1,120 @@
+use crate::error::CircuitError;
+use crate::operations::circuits::builder::GateIndex;
+use tandem::{Circuit, Gate};
+
+/// Size and AND-depth statistics of a circuit.
+///
+/// XOR and NOT gates are free in the garbling scheme, so the number of communication rounds an
+/// executor needs is driven by the AND depth rather than the gate count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitStats {
+    /// Total number of gates, including input gates.
+    pub gates: usize,
+    /// Number of AND gates.
+    pub and_gates: usize,
+    /// Number of input gates from both parties.
+    pub inputs: usize,
+    /// Number of output wires.
+    pub outputs: usize,
+    /// Longest chain of AND gates leading to any gate.
+    pub and_depth: usize,
+    /// Number of AND gates at each AND depth, starting at depth 1. All gates of a level can be
+    /// evaluated together once the previous levels are done.
+    pub and_levels: Vec<usize>,
+}
+
+/// Computes the size and AND-depth statistics of a circuit.
+///
+/// # Errors
+///
+/// Returns [`CircuitError::Tandem`] if a gate reads a wire that is not defined before it.
+pub fn circuit_stats(circuit: &Circuit) -> Result<CircuitStats, CircuitError> {
+    // the depths are computed in gate order, so every operand must already have one
+    circuit.validate()?;
+    let gates = circuit.gates();
+    let depths = and_depths(gates);
+    let and_depth = depths.iter().copied().max().unwrap_or(0);
+
+    let mut and_levels = vec![0; and_depth];
+    for (gate, depth) in gates.iter().zip(&depths) {
+        if let Gate::And(_, _) = gate {
+            and_levels[depth - 1] += 1;
+        }
+    }
+
+    Ok(CircuitStats {
+        gates: gates.len(),
+        and_gates: and_levels.iter().sum(),
+        inputs: circuit.contrib_inputs() + circuit.eval_inputs(),
+        outputs: circuit.output_gates().len(),
+        and_depth,
+        and_levels,
+    })
+}
+
+/// Reorders the gates of a circuit into AND-depth levels without changing its semantics.
+///
+/// Input gates keep their relative order, the AND gates of each level are emitted together,
+/// and the free XOR/NOT gates of a level follow its AND gates. An executor can then process
+/// each level of AND gates in a single round. The AND depth reported by [`circuit_stats`] is
+/// unchanged.
+///
+/// # Errors
+///
+/// Returns [`CircuitError::Tandem`] if a gate reads a wire that is not defined before it.
+pub fn schedule_by_depth(circuit: Circuit) -> Result<Circuit, CircuitError> {
+    circuit.validate()?;
+    let gates = circuit.gates();
+    let depths = and_depths(gates);
+
+    // Inputs come first; the AND gates at depth d precede the free gates at depth d, which may
+    // consume them. The stable sort keeps gates of the same slot in their original,
+    // topologically valid order.
+    let slot = |index: usize| match gates[index] {
+        Gate::InContrib | Gate::InEval => 0,
+        Gate::And(_, _) => 2 * depths[index] - 1,
+        Gate::Xor(_, _) | Gate::Not(_) => 2 * depths[index],
+    };
+    let mut order: Vec<usize> = (0..gates.len()).collect();
+    order.sort_by_key(|&index| slot(index));
+
+    let mut new_index = vec![0; gates.len()];
+    for (position, &old) in order.iter().enumerate() {
+        new_index[old] = position as GateIndex;
+    }
+    let remap = |wire: GateIndex| new_index[wire as usize];
+
+    let scheduled = order
+        .iter()
+        .map(|&old| match gates[old] {
+            Gate::InContrib => Gate::InContrib,
+            Gate::InEval => Gate::InEval,
+            Gate::Xor(a, b) => Gate::Xor(remap(a), remap(b)),
+            Gate::And(a, b) => Gate::And(remap(a), remap(b)),
+            Gate::Not(a) => Gate::Not(remap(a)),
+        })
+        .collect();
+    let outputs = circuit
+        .output_gates()
+        .iter()
+        .map(|&wire| remap(wire))
+        .collect();
+
+    Ok(Circuit::new(scheduled, outputs))
+}
+
+// AND depth of every gate: inputs are at depth 0, XOR/NOT keep the depth of their deepest
+// operand and AND adds one
+fn and_depths(gates: &[Gate]) -> Vec<usize> {
+    let mut depths: Vec<usize> = Vec::with_capacity(gates.len());
+    for gate in gates {
+        let depth = match *gate {
+            Gate::InContrib | Gate::InEval => 0,
+            Gate::Xor(a, b) => depths[a as usize].max(depths[b as usize]),
+            Gate::And(a, b) => depths[a as usize].max(depths[b as usize]) + 1,
+            Gate::Not(a) => depths[a as usize],
+        };
+        depths.push(depth);
+    }
+    depths
+}
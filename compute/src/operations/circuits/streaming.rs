@@ -0,0 +1,102 @@
+use crate::operations::circuits::builder::GateIndex;
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use tandem::{Circuit, Gate};
+
+/// Output of [`evaluate_streaming`] together with its peak memory use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamingEvaluation {
+    pub output: Vec<bool>,
+    /// Number of value slots the evaluation allocated, i.e. the most wires held at once,
+    /// including output wires kept until the end.
+    pub peak_live_wires: usize,
+}
+
+/// Evaluates a circuit in the clear, gate by gate, releasing each wire once its last consumer
+/// has run.
+///
+/// Wire values live in slots that are handed back to a free list after the wire's last read,
+/// so value storage grows with the circuit's live-wire width rather than its gate count. The
+/// only per-gate state is a `u32` table of each wire's last reader. The gates must be in
+/// topological order, as produced by the builder.
+///
+/// Like [`crate::executor::LocalSimulator`] this is a local simulation that sees every input;
+/// it computes the same outputs without running the garbling protocol.
+pub fn evaluate_streaming(
+    circuit: &Circuit,
+    input_contributor: &[bool],
+    input_evaluator: &[bool],
+) -> Result<StreamingEvaluation> {
+    circuit
+        .validate()
+        .map_err(|e| anyhow!("invalid circuit: {:?}", e))?;
+    let gates = circuit.gates();
+
+    // index of the last gate reading each wire; outputs stay live until the end
+    let mut last_use: Vec<GateIndex> = (0..gates.len() as GateIndex).collect();
+    for (i, gate) in gates.iter().enumerate() {
+        for operand in operands(gate) {
+            last_use[operand as usize] = i as GateIndex;
+        }
+    }
+    for &output in circuit.output_gates() {
+        last_use[output as usize] = GateIndex::MAX;
+    }
+
+    let mut contributor = input_contributor.iter();
+    let mut evaluator = input_evaluator.iter();
+    // the slot holding each live wire, the slot values, and the slots free for reuse
+    let mut slot_of: HashMap<GateIndex, usize> = HashMap::new();
+    let mut values: Vec<bool> = Vec::new();
+    let mut free: Vec<usize> = Vec::new();
+    for (i, gate) in gates.iter().enumerate() {
+        let value = |wire: GateIndex| values[slot_of[&wire]];
+        let result = match *gate {
+            Gate::InContrib => *contributor
+                .next()
+                .ok_or_else(|| anyhow!("missing contributor input"))?,
+            Gate::InEval => *evaluator
+                .next()
+                .ok_or_else(|| anyhow!("missing evaluator input"))?,
+            Gate::Xor(a, b) => value(a) ^ value(b),
+            Gate::And(a, b) => value(a) & value(b),
+            Gate::Not(a) => !value(a),
+        };
+
+        // release the operands this gate was the last reader of, so the result can reuse a slot
+        for wire in operands(gate) {
+            if last_use[wire as usize] == i as GateIndex {
+                free.extend(slot_of.remove(&wire));
+            }
+        }
+        // a gate nothing reads is never stored
+        if last_use[i] != i as GateIndex {
+            let slot = free.pop().unwrap_or_else(|| {
+                values.push(false);
+                values.len() - 1
+            });
+            values[slot] = result;
+            slot_of.insert(i as GateIndex, slot);
+        }
+    }
+
+    let output = circuit
+        .output_gates()
+        .iter()
+        .map(|output| values[slot_of[output]])
+        .collect();
+    Ok(StreamingEvaluation {
+        output,
+        peak_live_wires: values.len(),
+    })
+}
+
+/// The distinct wires a gate reads, so that `Xor(a, a)` releases `a` once.
+fn operands(gate: &Gate) -> Vec<GateIndex> {
+    match *gate {
+        Gate::InContrib | Gate::InEval => vec![],
+        Gate::Xor(a, b) | Gate::And(a, b) if a == b => vec![a],
+        Gate::Xor(a, b) | Gate::And(a, b) => vec![a, b],
+        Gate::Not(a) => vec![a],
+    }
+}
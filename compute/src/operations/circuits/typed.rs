@@ -0,0 +1,214 @@
+//! A circuit builder whose wire groups carry their width in the type.
+//!
+//! [`WRK17CircuitBuilder`] works on untyped [`GateIndexVec`]s, so combining an 8-bit and a
+//! 16-bit value only shows up as a malformed circuit at runtime. [`TypedCircuitBuilder`] wraps
+//! it with [`Wires<N>`] handles: binary operations require both operands to have the same `N`,
+//! and widths only change through [`TypedCircuitBuilder::extend`] and
+//! [`TypedCircuitBuilder::truncate`].
+//!
+//! ```
+//! use compute::prelude::*;
+//!
+//! let mut builder = TypedCircuitBuilder::default();
+//! let a = builder.input(&GarbledUint8::from(200_u8));
+//! let b = builder.input(&GarbledUint16::from(1000_u16));
+//! let a = builder.extend::<8, 16>(&a);
+//! let sum = builder.add(&a, &b);
+//!
+//! let result: u16 = builder.compile_and_execute(&sum).unwrap().into();
+//! assert_eq!(result, 1200);
+//! ```
+//!
+//! Mixing widths without converting is rejected at compile time:
+//!
+//! ```compile_fail
+//! use compute::prelude::*;
+//!
+//! let mut builder = TypedCircuitBuilder::default();
+//! let a = builder.input(&GarbledUint8::from(200_u8));
+//! let b = builder.input(&GarbledUint16::from(1000_u16));
+//! let sum = builder.add(&a, &b);
+//! ```
+
+use crate::error::CircuitError;
+use crate::operations::circuits::builder::WRK17CircuitBuilder;
+use crate::operations::circuits::traits::CircuitExecutor;
+use crate::operations::circuits::types::GateIndexVec;
+use crate::uint::GarbledUint;
+use tandem::Circuit;
+
+/// A group of exactly `N` wires, least-significant bit first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wires<const N: usize>(GateIndexVec);
+
+impl<const N: usize> Wires<N> {
+    /// Wraps untyped wire indices, e.g. the result of a [`WRK17CircuitBuilder`] gadget.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` does not hold exactly `N` wires.
+    pub fn from_indices(indices: GateIndexVec) -> Self {
+        assert_eq!(
+            indices.len(),
+            N,
+            "Wires<{}> needs exactly {} wires, got {}",
+            N,
+            N,
+            indices.len()
+        );
+        Wires(indices)
+    }
+
+    pub fn indices(&self) -> &GateIndexVec {
+        &self.0
+    }
+
+    pub fn into_indices(self) -> GateIndexVec {
+        self.0
+    }
+}
+
+impl<const N: usize> From<Wires<N>> for GateIndexVec {
+    fn from(wires: Wires<N>) -> Self {
+        wires.0
+    }
+}
+
+/// Width-checked front end for [`WRK17CircuitBuilder`].
+#[derive(Debug, Default)]
+pub struct TypedCircuitBuilder {
+    builder: WRK17CircuitBuilder,
+}
+
+impl TypedCircuitBuilder {
+    /// Wraps a configured builder, e.g. one created with [`WRK17CircuitBuilder::with_adder`].
+    pub fn new(builder: WRK17CircuitBuilder) -> Self {
+        TypedCircuitBuilder { builder }
+    }
+
+    /// Gives access to the untyped builder for gadgets without a typed wrapper.
+    pub fn inner_mut(&mut self) -> &mut WRK17CircuitBuilder {
+        &mut self.builder
+    }
+
+    pub fn into_inner(self) -> WRK17CircuitBuilder {
+        self.builder
+    }
+
+    /// Adds `value` as `N` input wires. Values with fewer stored bits are zero-padded, so the
+    /// handle always covers all `N` bits.
+    pub fn input<const N: usize>(&mut self, value: &GarbledUint<N>) -> Wires<N> {
+        let mut bits = value.bits().into_owned();
+        bits.resize(N, false);
+        Wires(self.builder.input(&GarbledUint::<N>::new(bits)))
+    }
+
+    /// Returns wires carrying the public constant `value`; see [`WRK17CircuitBuilder::constant`].
+    pub fn constant<const N: usize>(&mut self, value: &GarbledUint<N>) -> Wires<N> {
+        Wires(self.builder.constant(value))
+    }
+
+    pub fn xor<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.xor(&a.0, &b.0))
+    }
+
+    pub fn and<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.and(&a.0, &b.0))
+    }
+
+    pub fn or<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.or(&a.0, &b.0))
+    }
+
+    pub fn not<const N: usize>(&mut self, a: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.not(&a.0))
+    }
+
+    pub fn add<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.add(&a.0, &b.0))
+    }
+
+    pub fn sub<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.sub(&a.0, &b.0))
+    }
+
+    pub fn mul<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.mul(&a.0, &b.0))
+    }
+
+    pub fn div<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.div(&a.0, &b.0))
+    }
+
+    pub fn rem<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<N> {
+        Wires(self.builder.rem(&a.0, &b.0))
+    }
+
+    pub fn eq<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<1> {
+        Wires(self.builder.eq(&a.0, &b.0).into())
+    }
+
+    pub fn ne<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<1> {
+        Wires(self.builder.ne(&a.0, &b.0).into())
+    }
+
+    pub fn lt<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<1> {
+        Wires(self.builder.lt(&a.0, &b.0).into())
+    }
+
+    pub fn le<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<1> {
+        Wires(self.builder.le(&a.0, &b.0).into())
+    }
+
+    pub fn gt<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<1> {
+        Wires(self.builder.gt(&a.0, &b.0).into())
+    }
+
+    pub fn ge<const N: usize>(&mut self, a: &Wires<N>, b: &Wires<N>) -> Wires<1> {
+        Wires(self.builder.ge(&a.0, &b.0).into())
+    }
+
+    /// Selects `if_true` when `condition` is set, otherwise `if_false`.
+    pub fn mux<const N: usize>(
+        &mut self,
+        condition: &Wires<1>,
+        if_true: &Wires<N>,
+        if_false: &Wires<N>,
+    ) -> Wires<N> {
+        Wires(self.builder.mux(&condition.0[0], &if_true.0, &if_false.0))
+    }
+
+    /// Zero-extends `a` to `M >= N` bits; narrowing fails to compile.
+    pub fn extend<const N: usize, const M: usize>(&mut self, a: &Wires<N>) -> Wires<M> {
+        const { assert!(M >= N, "extend cannot narrow; use truncate") };
+
+        let mut extended = a.0.clone();
+        if M > N {
+            let zero = self.builder.constant::<1>(&GarbledUint::new(vec![false]))[0];
+            for _ in N..M {
+                extended.push(zero);
+            }
+        }
+        Wires(extended)
+    }
+
+    /// Keeps the low `M <= N` bits of `a`; widening fails to compile.
+    pub fn truncate<const N: usize, const M: usize>(&self, a: &Wires<N>) -> Wires<M> {
+        const { assert!(M <= N, "truncate cannot widen; use extend") };
+
+        let mut truncated = a.0.clone();
+        truncated.truncate(M);
+        Wires(truncated)
+    }
+
+    pub fn compile<const N: usize>(&self, output: &Wires<N>) -> Circuit {
+        self.builder.compile(&output.0)
+    }
+
+    pub fn compile_and_execute<const N: usize>(
+        &self,
+        output: &Wires<N>,
+    ) -> Result<GarbledUint<N>, CircuitError> {
+        self.builder.compile_and_execute(&output.0)
+    }
+}
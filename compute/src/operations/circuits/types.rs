@@ -1,5 +1,5 @@
 use crate::operations::circuits::builder::GateIndex;
-use crate::uint::GarbledBoolean;
+use crate::uint::GarbledBit;
 
 #[derive(Default, Debug, Eq, Hash, PartialEq, Clone)]
 pub struct GateIndexVec(Vec<GateIndex>);
@@ -25,7 +25,7 @@ impl GateIndexVec {
         self.0.is_empty()
     }
 
-    pub fn iter(&self) -> std::slice::Iter<GateIndex> {
+    pub fn iter(&self) -> std::slice::Iter<'_, GateIndex> {
         self.0.iter()
     }
 
@@ -67,9 +67,9 @@ impl From<Vec<GateIndex>> for GateIndexVec {
     }
 }
 
-impl From<GateIndexVec> for GarbledBoolean {
+impl From<GateIndexVec> for GarbledBit {
     fn from(vec: GateIndexVec) -> Self {
-        GarbledBoolean::from(vec.0[0])
+        GarbledBit::from(vec.0[0])
     }
 }
 
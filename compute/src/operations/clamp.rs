@@ -0,0 +1,29 @@
+use crate::int::GarbledInt;
+use crate::operations::circuits::builder::build_and_execute_clamp;
+use crate::uint::GarbledUint;
+
+// Not named `clamp`, which would collide with `Ord::clamp`; that evaluates the comparisons one
+// at a time and reveals their results
+
+impl<const N: usize> GarbledUint<N> {
+    /// Restricts the value to `[lo, hi]` in a single circuit of two comparisons and two muxes,
+    /// as [`GarbledUint::secure_max`] with `lo` followed by [`GarbledUint::secure_min`] with `hi`.
+    ///
+    /// Expects `lo <= hi`. Unlike the native `clamp` this does not panic otherwise, and returns
+    /// `hi`.
+    pub fn secure_clamp(&self, lo: &Self, hi: &Self) -> Self {
+        build_and_execute_clamp(self, lo, hi, false)
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    /// Restricts the value to `[lo, hi]` in a single circuit of two signed comparisons and two
+    /// muxes, as [`GarbledInt::secure_max`] with `lo` followed by [`GarbledInt::secure_min`] with
+    /// `hi`.
+    ///
+    /// Expects `lo <= hi`. Unlike the native `clamp` this does not panic otherwise, and returns
+    /// `hi`.
+    pub fn secure_clamp(&self, lo: &Self, hi: &Self) -> Self {
+        build_and_execute_clamp(&self.into(), &lo.into(), &hi.into(), true).into()
+    }
+}
@@ -1,14 +1,75 @@
 use crate::int::GarbledInt;
 use crate::operations::circuits::builder::{
-    build_and_execute_comparator, build_and_execute_equality,
+    build_and_execute_comparator, build_and_execute_comparison,
 };
 use crate::uint::GarbledUint;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
-// Implementing comparison operators for GarbledUint
+/// All three outcomes of comparing `self` against `other`; exactly one flag is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub lt: bool,
+    pub eq: bool,
+    pub gt: bool,
+}
+
+impl Comparison {
+    fn from_flags([lt, eq, gt]: [bool; 3]) -> Self {
+        Comparison { lt, eq, gt }
+    }
+
+    pub fn ordering(&self) -> Ordering {
+        if self.lt {
+            Ordering::Less
+        } else if self.eq {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl<const N: usize> GarbledUint<N> {
+    /// Computes `lt`, `eq` and `gt` from a single comparator circuit.
+    pub fn compare(&self, other: &Self) -> Comparison {
+        Comparison::from_flags(build_and_execute_comparison(self, other, false))
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    /// Computes `lt`, `eq` and `gt` from a single two's complement comparator circuit.
+    pub fn compare(&self, other: &Self) -> Comparison {
+        Comparison::from_flags(build_and_execute_comparison(
+            &self.into(),
+            &other.into(),
+            true,
+        ))
+    }
+}
+
+// Yield exactly N bits read through `stored_bit`, treating bits beyond the stored ones as zero
+fn padded_bits<const N: usize>(
+    stored_bit: impl Fn(usize) -> Option<bool>,
+) -> impl Iterator<Item = bool> {
+    (0..N).map(move |i| stored_bit(i).unwrap_or(false))
+}
+
+/// Compares the local bit representation of two values.
+///
+/// This is a plaintext comparison of the bits held by this party and runs no circuit, so it
+/// is not a secure equality check. Use `CircuitExecutor::eq` (or `==` inside an `encrypted`
+/// function) to compare values inside a garbled circuit.
 impl<const N: usize> PartialEq for GarbledUint<N> {
     fn eq(&self, other: &Self) -> bool {
-        matches!(build_and_execute_comparator(self, other), Ordering::Equal)
+        padded_bits::<N>(|i| self.stored_bit(i)).eq(padded_bits::<N>(|i| other.stored_bit(i)))
+    }
+}
+
+// Hashing matches `PartialEq` by hashing the local bit representation
+impl<const N: usize> Hash for GarbledUint<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        padded_bits::<N>(|i| self.stored_bit(i)).for_each(|bit| bit.hash(state));
     }
 }
 
@@ -36,7 +97,7 @@ impl<const N: usize> Ord for GarbledUint<N> {
 // Implementing comparison operators for GarbledUint
 impl<const N: usize> PartialEq<&GarbledUint<N>> for GarbledUint<N> {
     fn eq(&self, other: &&Self) -> bool {
-        !build_and_execute_equality(self, other)
+        self == *other
     }
 }
 
@@ -47,13 +108,19 @@ impl<const N: usize> PartialOrd<&GarbledUint<N>> for GarbledUint<N> {
     }
 }
 
-// Implementing comparison operators for GarbledInt
+/// Compares the local bit representation of two values.
+///
+/// Like the `GarbledUint` impl, this runs no circuit and is not a secure equality check.
 impl<const N: usize> PartialEq for GarbledInt<N> {
     fn eq(&self, other: &Self) -> bool {
-        matches!(
-            build_and_execute_comparator(&self.into(), &other.into()),
-            Ordering::Equal
-        )
+        padded_bits::<N>(|i| self.stored_bit(i)).eq(padded_bits::<N>(|i| other.stored_bit(i)))
+    }
+}
+
+// Hashing matches `PartialEq` by hashing the local bit representation
+impl<const N: usize> Hash for GarbledInt<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        padded_bits::<N>(|i| self.stored_bit(i)).for_each(|bit| bit.hash(state));
     }
 }
 
@@ -0,0 +1,131 @@
+use crate::int::GarbledInt;
+use crate::operations::circuits::builder::{
+    build_and_execute_const_comparison, build_and_execute_is_one_of,
+};
+use crate::uint::{GarbledBit, GarbledUint};
+use std::cmp::Ordering;
+
+// Each method compiles one circuit with the secret value as its only input and reveals a single
+// bit. Constants outside the range of `N` bits compare the same way against every value, so
+// those are answered without running a circuit.
+
+impl<const N: usize> GarbledUint<N> {
+    fn cmp_const(&self, value: u128, accepted: &[Ordering]) -> bool {
+        if N < 128 && value >> N != 0 {
+            return accepted.contains(&Ordering::Less);
+        }
+
+        let bits: Vec<bool> = (0..N).map(|i| i < 128 && (value >> i) & 1 == 1).collect();
+        build_and_execute_const_comparison(self, &bits, false, accepted)
+    }
+
+    /// Returns `self == value` for a public constant.
+    pub fn eq_const(&self, value: u128) -> bool {
+        self.cmp_const(value, &[Ordering::Equal])
+    }
+
+    /// Returns `self != value` for a public constant.
+    pub fn ne_const(&self, value: u128) -> bool {
+        self.cmp_const(value, &[Ordering::Less, Ordering::Greater])
+    }
+
+    /// Returns `self < value` for a public constant.
+    pub fn lt_const(&self, value: u128) -> bool {
+        self.cmp_const(value, &[Ordering::Less])
+    }
+
+    /// Returns `self <= value` for a public constant.
+    pub fn le_const(&self, value: u128) -> bool {
+        self.cmp_const(value, &[Ordering::Less, Ordering::Equal])
+    }
+
+    /// Returns `self > value` for a public constant.
+    pub fn gt_const(&self, value: u128) -> bool {
+        self.cmp_const(value, &[Ordering::Greater])
+    }
+
+    /// Returns `self >= value` for a public constant.
+    pub fn ge_const(&self, value: u128) -> bool {
+        self.cmp_const(value, &[Ordering::Greater, Ordering::Equal])
+    }
+
+    /// Returns whether `self` equals any of the public constants in `values`, e.g. a set of
+    /// allowed status codes. Only the combined bit is revealed, not which constant matched.
+    /// Constants that do not fit in `N` bits can never match and are skipped.
+    pub fn is_one_of(&self, values: &[u128]) -> GarbledBit {
+        let constants: Vec<Vec<bool>> = values
+            .iter()
+            .filter(|&&value| N >= 128 || value >> N == 0)
+            .map(|&value| (0..N).map(|i| i < 128 && (value >> i) & 1 == 1).collect())
+            .collect();
+        if constants.is_empty() {
+            return false.into();
+        }
+        build_and_execute_is_one_of(self, &constants)
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    fn cmp_const(&self, value: i128, accepted: &[Ordering]) -> bool {
+        if N < 128 {
+            let min = -(1_i128 << (N - 1));
+            let max = (1_i128 << (N - 1)) - 1;
+            if value < min {
+                return accepted.contains(&Ordering::Greater);
+            }
+            if value > max {
+                return accepted.contains(&Ordering::Less);
+            }
+        }
+
+        // sign-extend the constant to N bits
+        let bits: Vec<bool> = (0..N).map(|i| (value >> i.min(127)) & 1 == 1).collect();
+        build_and_execute_const_comparison(&self.into(), &bits, true, accepted)
+    }
+
+    /// Returns `self == value` for a public constant.
+    pub fn eq_const(&self, value: i128) -> bool {
+        self.cmp_const(value, &[Ordering::Equal])
+    }
+
+    /// Returns `self != value` for a public constant.
+    pub fn ne_const(&self, value: i128) -> bool {
+        self.cmp_const(value, &[Ordering::Less, Ordering::Greater])
+    }
+
+    /// Returns `self < value` for a public constant.
+    pub fn lt_const(&self, value: i128) -> bool {
+        self.cmp_const(value, &[Ordering::Less])
+    }
+
+    /// Returns `self <= value` for a public constant.
+    pub fn le_const(&self, value: i128) -> bool {
+        self.cmp_const(value, &[Ordering::Less, Ordering::Equal])
+    }
+
+    /// Returns `self > value` for a public constant.
+    pub fn gt_const(&self, value: i128) -> bool {
+        self.cmp_const(value, &[Ordering::Greater])
+    }
+
+    /// Returns `self >= value` for a public constant.
+    pub fn ge_const(&self, value: i128) -> bool {
+        self.cmp_const(value, &[Ordering::Greater, Ordering::Equal])
+    }
+
+    /// Returns whether `self` equals any of the public constants in `values`; see
+    /// [`GarbledUint::is_one_of`].
+    pub fn is_one_of(&self, values: &[i128]) -> GarbledBit {
+        let constants: Vec<Vec<bool>> = values
+            .iter()
+            .filter(|&&value| {
+                N >= 128 || (-(1_i128 << (N - 1))..1_i128 << (N - 1)).contains(&value)
+            })
+            .map(|&value| (0..N).map(|i| (value >> i.min(127)) & 1 == 1).collect())
+            .collect();
+        if constants.is_empty() {
+            return false.into();
+        }
+        build_and_execute_is_one_of(&self.into(), &constants)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::operations::circuits::builder::build_and_execute_dot_product;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    /// Computes `sum(a[i] * b[i])` as an `M`-bit value in a single circuit.
+    ///
+    /// Every partial product of every multiplier feeds one carry-save accumulation tree, so the
+    /// circuit depends only on `N`, `M`, and the vector length. This is far cheaper than `*`
+    /// and `+` per element, which run a circuit per operation and a full adder per product.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors differ in length, or if `M` is narrower than
+    /// `2 * N + ceil(log2(len))` bits, the width that always holds `len * (2^N - 1)^2`.
+    pub fn dot_product<const M: usize>(a: &[Self], b: &[Self]) -> GarbledUint<M> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "dot_product requires equal-length vectors"
+        );
+        let len_bits = (usize::BITS - a.len().saturating_sub(1).leading_zeros()) as usize;
+        let required = 2 * N + len_bits;
+        assert!(
+            M >= required,
+            "dot_product of {} {}-bit values needs at least {} output bits, got {}",
+            a.len(),
+            N,
+            required,
+            M
+        );
+
+        build_and_execute_dot_product(a, b)
+    }
+}
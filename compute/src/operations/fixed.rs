@@ -0,0 +1,132 @@
+use crate::fixed::GarbledFixed;
+use crate::operations::circuits::builder::{
+    build_and_execute_addition, build_and_execute_fixed_div, build_and_execute_fixed_mul,
+    build_and_execute_subtraction,
+};
+use crate::uint::GarbledUint;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+// The raw values share the scale 2^F, so sums and differences are the integer circuits
+impl<const N: usize, const F: usize> Add for &GarbledFixed<N, F> {
+    type Output = GarbledFixed<N, F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let sum = build_and_execute_addition(&raw(self), &raw(rhs));
+        GarbledFixed::from_raw(sum.into())
+    }
+}
+
+impl<const N: usize, const F: usize> Add for GarbledFixed<N, F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<const N: usize, const F: usize> AddAssign for GarbledFixed<N, F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl<const N: usize, const F: usize> AddAssign<&GarbledFixed<N, F>> for GarbledFixed<N, F> {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = &*self + rhs;
+    }
+}
+
+impl<const N: usize, const F: usize> Sub for &GarbledFixed<N, F> {
+    type Output = GarbledFixed<N, F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let difference = build_and_execute_subtraction(&raw(self), &raw(rhs));
+        GarbledFixed::from_raw(difference.into())
+    }
+}
+
+impl<const N: usize, const F: usize> Sub for GarbledFixed<N, F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<const N: usize, const F: usize> SubAssign for GarbledFixed<N, F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = &*self - &rhs;
+    }
+}
+
+impl<const N: usize, const F: usize> SubAssign<&GarbledFixed<N, F>> for GarbledFixed<N, F> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = &*self - rhs;
+    }
+}
+
+// The product is computed at double width and shifted right by F, rounding toward negative
+// infinity like an arithmetic shift
+impl<const N: usize, const F: usize> Mul for &GarbledFixed<N, F> {
+    type Output = GarbledFixed<N, F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = build_and_execute_fixed_mul(&raw(self), &raw(rhs), F);
+        GarbledFixed::from_raw(product.into())
+    }
+}
+
+impl<const N: usize, const F: usize> Mul for GarbledFixed<N, F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<const N: usize, const F: usize> MulAssign for GarbledFixed<N, F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<const N: usize, const F: usize> MulAssign<&GarbledFixed<N, F>> for GarbledFixed<N, F> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = &*self * rhs;
+    }
+}
+
+// The dividend is shifted left by F before an unsigned division of the magnitudes, so the
+// quotient rounds toward zero; dividing by zero gives an unspecified value
+impl<const N: usize, const F: usize> Div for &GarbledFixed<N, F> {
+    type Output = GarbledFixed<N, F>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let quotient = build_and_execute_fixed_div(&raw(self), &raw(rhs), F);
+        GarbledFixed::from_raw(quotient.into())
+    }
+}
+
+impl<const N: usize, const F: usize> Div for GarbledFixed<N, F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<const N: usize, const F: usize> DivAssign for GarbledFixed<N, F> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = &*self / &rhs;
+    }
+}
+
+impl<const N: usize, const F: usize> DivAssign<&GarbledFixed<N, F>> for GarbledFixed<N, F> {
+    fn div_assign(&mut self, rhs: &Self) {
+        *self = &*self / rhs;
+    }
+}
+
+fn raw<const N: usize, const F: usize>(value: &GarbledFixed<N, F>) -> GarbledUint<N> {
+    value.raw().into()
+}
@@ -0,0 +1,111 @@
+use crate::float::GarbledFloat;
+use crate::operations::circuits::builder::{
+    build_and_execute_float_add, build_and_execute_float_comparison, build_and_execute_float_mul,
+    build_and_execute_float_sub,
+};
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+impl<const N: usize> Add for &GarbledFloat<N> {
+    type Output = GarbledFloat<N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        GarbledFloat::from_raw(build_and_execute_float_add(self.raw(), rhs.raw()))
+    }
+}
+
+impl<const N: usize> Add for GarbledFloat<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<const N: usize> AddAssign for GarbledFloat<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl<const N: usize> AddAssign<&GarbledFloat<N>> for GarbledFloat<N> {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = &*self + rhs;
+    }
+}
+
+impl<const N: usize> Sub for &GarbledFloat<N> {
+    type Output = GarbledFloat<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        GarbledFloat::from_raw(build_and_execute_float_sub(self.raw(), rhs.raw()))
+    }
+}
+
+impl<const N: usize> Sub for GarbledFloat<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<const N: usize> SubAssign for GarbledFloat<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = &*self - &rhs;
+    }
+}
+
+impl<const N: usize> SubAssign<&GarbledFloat<N>> for GarbledFloat<N> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = &*self - rhs;
+    }
+}
+
+impl<const N: usize> Mul for &GarbledFloat<N> {
+    type Output = GarbledFloat<N>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        GarbledFloat::from_raw(build_and_execute_float_mul(self.raw(), rhs.raw()))
+    }
+}
+
+impl<const N: usize> Mul for GarbledFloat<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<const N: usize> MulAssign for GarbledFloat<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<const N: usize> MulAssign<&GarbledFloat<N>> for GarbledFloat<N> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = &*self * rhs;
+    }
+}
+
+/// Compares the values inside a circuit with IEEE 754 semantics, so `-0 == +0` and NaN is
+/// unequal to everything, itself included.
+impl<const N: usize> PartialEq for GarbledFloat<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+// NaN is unordered, like the primitive float types
+impl<const N: usize> PartialOrd for GarbledFloat<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match build_and_execute_float_comparison(self.raw(), other.raw()) {
+            [true, _, _] => Some(Ordering::Less),
+            [_, true, _] => Some(Ordering::Equal),
+            [_, _, true] => Some(Ordering::Greater),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::operations::circuits::builder::build_and_execute_gcd;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    // greatest common divisor using a fixed-iteration binary GCD circuit
+    pub fn gcd(&self, other: &Self) -> Self {
+        build_and_execute_gcd(self, other)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::operations::circuits::builder::{
+    build_and_execute_from_gray, build_and_execute_to_gray,
+};
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    /// Encodes `self` in reflected binary (Gray) code, `x ^ (x >> 1)`.
+    ///
+    /// Adjacent values differ in exactly one bit of their encodings. The circuit is XOR gates
+    /// only, so it needs no AND gates.
+    pub fn to_gray(&self) -> Self {
+        build_and_execute_to_gray(self)
+    }
+
+    /// Decodes a Gray-coded `self` back to binary, the inverse of [`GarbledUint::to_gray`].
+    ///
+    /// Each output bit is the XOR of all input bits at or above it; like the encoding this uses
+    /// XOR gates only.
+    pub fn from_gray(&self) -> Self {
+        build_and_execute_from_gray(self)
+    }
+}
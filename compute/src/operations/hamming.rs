@@ -0,0 +1,21 @@
+use crate::operations::circuits::builder::{
+    build_and_execute_count_ones, build_and_execute_within_hamming,
+};
+use crate::uint::{GarbledBit, GarbledUint, GarbledUint32};
+
+impl<const N: usize> GarbledUint<N> {
+    /// Returns whether `self` and `other` differ in at most `k` bits, for a public `k`.
+    ///
+    /// The operands are XORed, the differing bits counted, and the count compared against `k`
+    /// inside the circuit, so only the boolean is revealed and not the distance itself.
+    pub fn within_hamming(&self, other: &Self, k: usize) -> GarbledBit {
+        build_and_execute_within_hamming(self, other, k)
+    }
+
+    /// Returns the number of set bits, the Hamming weight, as a `u32`-width value like the
+    /// native `count_ones`. The bits are summed by a tree of adders that are only as wide as
+    /// the counts they add.
+    pub fn count_ones(&self) -> GarbledUint32 {
+        build_and_execute_count_ones(self)
+    }
+}
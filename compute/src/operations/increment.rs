@@ -0,0 +1,32 @@
+use crate::int::GarbledInt;
+use crate::operations::circuits::builder::{
+    build_and_execute_decrement, build_and_execute_increment,
+};
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    /// Returns `self + 1`, wrapping to zero on overflow.
+    ///
+    /// Uses a half-adder chain against the constant 1 instead of a full adder, so it needs
+    /// fewer gates than `self + GarbledUint::one()`.
+    pub fn increment(&self) -> Self {
+        build_and_execute_increment(self)
+    }
+
+    /// Returns `self - 1`, wrapping to the maximum value on underflow.
+    pub fn decrement(&self) -> Self {
+        build_and_execute_decrement(self)
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    /// Returns `self + 1`, wrapping from the maximum to the minimum value.
+    pub fn increment(&self) -> Self {
+        build_and_execute_increment(&self.into()).into()
+    }
+
+    /// Returns `self - 1`, wrapping from the minimum to the maximum value.
+    pub fn decrement(&self) -> Self {
+        build_and_execute_decrement(&self.into()).into()
+    }
+}
@@ -0,0 +1,19 @@
+use crate::operations::circuits::builder::build_and_execute_intersection_size;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    /// Counts the elements of `a` that also occur in `b`, returning the count as an `M`-bit
+    /// value that wraps if it exceeds `M` bits.
+    ///
+    /// Every element of `a` is compared against every element of `b`, so the circuit depends only
+    /// on the two set sizes and reveals the count but not which elements matched.
+    pub fn intersection_size<const M: usize>(a: &[Self], b: &[Self]) -> GarbledUint<M> {
+        const {
+            assert!(
+                M > 0,
+                "intersection_size needs at least one bit for the count"
+            )
+        };
+        build_and_execute_intersection_size(a, b)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::int::GarbledInt;
+use crate::operations::circuits::builder::{
+    build_and_execute_max, build_and_execute_min, build_and_execute_signed_max,
+    build_and_execute_signed_min,
+};
+use crate::uint::GarbledUint;
+
+// Not named `min` and `max`, which would collide with `Ord::min` and `Ord::max`. Those run the
+// comparison as a circuit of its own and pick the result locally, revealing which one was smaller
+
+impl<const N: usize> GarbledUint<N> {
+    /// Returns the smaller value from one circuit of a comparison and a mux.
+    pub fn secure_min(&self, other: &Self) -> Self {
+        build_and_execute_min(self, other)
+    }
+
+    /// Returns the larger value from one circuit of a comparison and a mux.
+    pub fn secure_max(&self, other: &Self) -> Self {
+        build_and_execute_max(self, other)
+    }
+}
+
+impl<const N: usize> GarbledInt<N> {
+    /// Returns the smaller value by signed comparison, from one circuit of a comparison and a
+    /// mux.
+    pub fn secure_min(&self, other: &Self) -> Self {
+        build_and_execute_signed_min(&self.into(), &other.into()).into()
+    }
+
+    /// Returns the larger value by signed comparison, from one circuit of a comparison and a
+    /// mux.
+    pub fn secure_max(&self, other: &Self) -> Self {
+        build_and_execute_signed_max(&self.into(), &other.into()).into()
+    }
+}
@@ -1,5 +1,30 @@
+pub mod abs_diff;
 pub mod arithmetic;
+pub mod array;
+pub mod batch;
 pub mod bitwise;
+pub mod boolean;
+pub mod borrowing_sub;
+pub mod bytes;
+pub mod char;
 pub mod circuits;
+pub mod clamp;
 pub mod comparator;
+pub mod const_comparator;
+pub mod dot_product;
+pub mod fixed;
+pub mod float;
+pub mod gcd;
+pub mod gray;
+pub mod hamming;
+pub mod increment;
+pub mod intersection;
+pub mod min_max;
 pub mod mux;
+pub mod option;
+pub mod pow_mod;
+pub mod small;
+pub mod sort;
+pub mod string;
+pub mod util;
+pub mod vec;
@@ -1,26 +1,92 @@
 use crate::int::GarbledInt;
-use crate::operations::circuits::builder::build_and_execute_mux;
-use crate::uint::GarbledBoolean;
+use crate::operations::circuits::builder::{
+    build_and_execute_broadcast, build_and_execute_conditional_swap, build_and_execute_mask,
+    build_and_execute_mux, build_and_execute_mux_store, build_and_execute_negate_if,
+    build_and_execute_select_one_hot,
+};
+use crate::uint::GarbledBit;
 use crate::uint::GarbledUint;
 
 impl<const N: usize> GarbledUint<N> {
     // implementation of the MUX operation
     pub fn mux(
-        condition: &GarbledBoolean,
+        condition: &GarbledBit,
         if_true: &GarbledUint<N>,
         if_false: &GarbledUint<N>,
     ) -> GarbledUint<N> {
         build_and_execute_mux(condition, if_true, if_false)
     }
+
+    /// Returns a value whose `N` bits all equal `bit`: all ones when it is true, zero when it
+    /// is false.
+    ///
+    /// This is the condition broadcast a mux performs internally, exposed so custom
+    /// masked-select gadgets can AND or XOR against it directly. It adds no gates.
+    pub fn broadcast(bit: &GarbledBit) -> GarbledUint<N> {
+        build_and_execute_broadcast(bit)
+    }
+
+    /// Returns `x` when `condition` is true and zero otherwise.
+    ///
+    /// Each bit of `x` is ANDed with the broadcast condition, so this costs `N` AND gates and
+    /// no constant wires, cheaper than a [`mux`](Self::mux) against a garbled zero.
+    pub fn mask(condition: &GarbledBit, x: &GarbledUint<N>) -> GarbledUint<N> {
+        build_and_execute_mask(condition, x)
+    }
+
+    // swaps the two values when the condition is true, otherwise returns them unchanged
+    pub fn conditional_swap(
+        condition: &GarbledBit,
+        a: &GarbledUint<N>,
+        b: &GarbledUint<N>,
+    ) -> (GarbledUint<N>, GarbledUint<N>) {
+        build_and_execute_conditional_swap(condition, a, b)
+    }
+
+    /// Returns a copy of `values` with `values[index]` replaced by `new_value`, for a secret
+    /// `index`. Every position is compared against the index and passed through a mux, so the
+    /// written position is not revealed. An index past the end leaves every value unchanged.
+    pub fn mux_store<const M: usize>(
+        index: &GarbledUint<M>,
+        values: &[GarbledUint<N>],
+        new_value: &GarbledUint<N>,
+    ) -> Vec<GarbledUint<N>> {
+        build_and_execute_mux_store(index, values, new_value)
+    }
+
+    /// Returns `values[i]` for the single set bit `i` of the one-hot `mask`.
+    ///
+    /// Each value is ANDed with its mask bit and the results ORed together, which is cheaper
+    /// than a mux tree when the selector is already one-hot. `mask` must have exactly one bit
+    /// set: with several set the result is the OR of the selected values, and with none (or
+    /// only bits past the end of `values`) it is zero. Neither case is detected.
+    pub fn select_one_hot<const K: usize>(
+        mask: &GarbledUint<K>,
+        values: &[GarbledUint<N>],
+    ) -> GarbledUint<N> {
+        build_and_execute_select_one_hot(mask, values)
+    }
 }
 
 impl<const N: usize> GarbledInt<N> {
     // implementation of the MUX operation
     pub fn mux(
-        condition: &GarbledBoolean,
+        condition: &GarbledBit,
         if_true: &GarbledInt<N>,
         if_false: &GarbledInt<N>,
     ) -> GarbledInt<N> {
         build_and_execute_mux(condition, &if_true.into(), &if_false.into()).into()
     }
+
+    /// Returns `x` when `condition` is true and zero otherwise, with the same AND-only
+    /// circuit as [`GarbledUint::mask`].
+    pub fn mask(condition: &GarbledBit, x: &GarbledInt<N>) -> GarbledInt<N> {
+        build_and_execute_mask(condition, &x.into()).into()
+    }
+
+    // returns -x when the condition is true, otherwise x; MIN negates to itself, as with
+    // `wrapping_neg`
+    pub fn negate_if(condition: &GarbledBit, x: &GarbledInt<N>) -> GarbledInt<N> {
+        build_and_execute_negate_if(condition, &x.into()).into()
+    }
 }
@@ -0,0 +1,33 @@
+use crate::int::GarbledInt;
+use crate::option::GarbledOption;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledOption<GarbledUint<N>> {
+    /// The held value if present, otherwise `default`, selected with a mux.
+    pub fn unwrap_or(&self, default: &GarbledUint<N>) -> GarbledUint<N> {
+        GarbledUint::mux(self.is_some(), self.value(), default)
+    }
+
+    /// `self` if present, otherwise `other`, like `Option::or`. The result is present when
+    /// either input is.
+    pub fn or(&self, other: &Self) -> Self {
+        let value = GarbledUint::mux(self.is_some(), self.value(), other.value());
+        let is_some = self.is_some() | other.is_some();
+        GarbledOption::new(is_some, value)
+    }
+}
+
+impl<const N: usize> GarbledOption<GarbledInt<N>> {
+    /// The held value if present, otherwise `default`, selected with a mux.
+    pub fn unwrap_or(&self, default: &GarbledInt<N>) -> GarbledInt<N> {
+        GarbledInt::mux(self.is_some(), self.value(), default)
+    }
+
+    /// `self` if present, otherwise `other`, like `Option::or`. The result is present when
+    /// either input is.
+    pub fn or(&self, other: &Self) -> Self {
+        let value = GarbledInt::mux(self.is_some(), self.value(), other.value());
+        let is_some = self.is_some() | other.is_some();
+        GarbledOption::new(is_some, value)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::operations::circuits::builder::build_and_execute_pow_mod;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    // modular exponentiation using square-and-multiply over all N exponent bits, so the circuit
+    // does not leak the exponent; the modulus must be non-zero
+    pub fn pow_mod(&self, exponent: &Self, modulus: &Self) -> Self {
+        build_and_execute_pow_mod(self, exponent, modulus)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::small::SmallGarbledUint;
+use std::cmp::Ordering;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+// The operators run the same circuits as `GarbledUint`, copying the operands into heap-backed
+// storage for the duration of the circuit
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<const N: usize> $trait for SmallGarbledUint<N> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                $trait::$method(&self.to_uint(), &rhs.to_uint()).into()
+            }
+        }
+
+        impl<const N: usize> $assign_trait for SmallGarbledUint<N> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
+impl_binary_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_binary_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_binary_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<const N: usize> Not for SmallGarbledUint<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        (!&self.to_uint()).into()
+    }
+}
+
+// Shifts by a public amount only move bits, so like those of `GarbledUint` they run no circuit
+impl<const N: usize> Shl<usize> for SmallGarbledUint<N> {
+    type Output = Self;
+
+    fn shl(self, shift: usize) -> Self::Output {
+        if shift >= N {
+            return Self::zero();
+        }
+        Self::from_word(self.word() << shift)
+    }
+}
+
+impl<const N: usize> ShlAssign<usize> for SmallGarbledUint<N> {
+    fn shl_assign(&mut self, shift: usize) {
+        *self = *self << shift;
+    }
+}
+
+impl<const N: usize> Shr<usize> for SmallGarbledUint<N> {
+    type Output = Self;
+
+    fn shr(self, shift: usize) -> Self::Output {
+        if shift >= N {
+            return Self::zero();
+        }
+        Self::from_word(self.word() >> shift)
+    }
+}
+
+impl<const N: usize> ShrAssign<usize> for SmallGarbledUint<N> {
+    fn shr_assign(&mut self, shift: usize) {
+        *self = *self >> shift;
+    }
+}
+
+// Ordering runs the comparator circuit, while `==` compares the local words like
+// `GarbledUint`'s `PartialEq`
+impl<const N: usize> PartialOrd for SmallGarbledUint<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for SmallGarbledUint<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_uint().cmp(&other.to_uint())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::operations::circuits::builder::build_and_execute_bitonic_sort;
+use crate::uint::GarbledUint;
+
+impl<const N: usize> GarbledUint<N> {
+    /// Sorts the values in ascending order with a data-oblivious bitonic sorting network.
+    ///
+    /// The input is padded to the next power of two with all-ones sentinels, and every
+    /// comparison feeds a conditional swap, so the circuit depends only on `values.len()` and
+    /// does not reveal the permutation.
+    pub fn bitonic_sort(values: &[Self]) -> Vec<Self> {
+        build_and_execute_bitonic_sort(values)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::operations::circuits::builder::build_and_execute_bytes_predicate;
+use crate::string::GarbledString;
+use crate::uint::GarbledBit;
+
+impl<const L: usize> GarbledString<L> {
+    /// Returns whether both strings hold the same text, comparing all `L` bytes in one
+    /// circuit so neither length is revealed.
+    pub fn equals(&self, other: &Self) -> GarbledBit {
+        if L == 0 {
+            return true.into();
+        }
+        build_and_execute_bytes_predicate(self.as_bytes(), other.as_bytes(), |builder, a, b| {
+            builder.bytes_eq(a, b)
+        })
+    }
+
+    /// Returns whether `prefix` is a prefix of `self`. Every byte position is checked, and a
+    /// padding byte in `prefix` matches anything, so the prefix length stays private.
+    pub fn starts_with(&self, prefix: &Self) -> GarbledBit {
+        if L == 0 {
+            return true.into();
+        }
+        build_and_execute_bytes_predicate(self.as_bytes(), prefix.as_bytes(), |builder, a, p| {
+            builder.has_prefix(a, p)
+        })
+    }
+
+    /// Returns whether both strings are equal after mapping ASCII `A-Z` to `a-z`, like
+    /// `str::eq_ignore_ascii_case`. Bytes outside that range, including non-ASCII UTF-8, must
+    /// match exactly.
+    pub fn eq_ignore_ascii_case(&self, other: &Self) -> GarbledBit {
+        if L == 0 {
+            return true.into();
+        }
+        build_and_execute_bytes_predicate(self.as_bytes(), other.as_bytes(), |builder, a, b| {
+            let a: Vec<_> = a.iter().map(|byte| builder.ascii_lowercase(byte)).collect();
+            let b: Vec<_> = b.iter().map(|byte| builder.ascii_lowercase(byte)).collect();
+            builder.bytes_eq(&a, &b)
+        })
+    }
+}
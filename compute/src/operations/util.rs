@@ -18,9 +18,9 @@ pub enum GateW {
     Not(GateIndex),
 }
 
-impl Into<Gate> for GateW {
-    fn into(self) -> Gate {
-        match self {
+impl From<GateW> for Gate {
+    fn from(gate: GateW) -> Self {
+        match gate {
             GateW::InContrib => Gate::InContrib,
             GateW::InEval => Gate::InEval,
             GateW::Xor(a, b) => Gate::Xor(a, b),
@@ -42,7 +42,7 @@ impl From<Gate> for GateW {
     }
 }
 
-// Assuming `Gate` and `GateIndex` implement `Serialize` and `Deserialize`
+// Serializable mirror of `tandem::Circuit`
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CircuitWrapper {
     gates: Vec<GateW>,
@@ -56,11 +56,7 @@ pub struct CircuitWrapper {
 impl From<&Circuit> for CircuitWrapper {
     fn from(circuit: &Circuit) -> Self {
         CircuitWrapper {
-            gates: circuit
-                .gates()
-                .iter()
-                .map(|gate| gate.clone().into())
-                .collect(),
+            gates: circuit.gates().iter().cloned().map(GateW::from).collect(),
             output_gates: circuit.output_gates().clone(),
             and_gates: circuit.and_gates(),
             eval_inputs: circuit.eval_inputs(),
@@ -69,15 +65,17 @@ impl From<&Circuit> for CircuitWrapper {
     }
 }
 
-impl Into<Circuit> for CircuitWrapper {
-    fn into(self) -> Circuit {
+impl From<CircuitWrapper> for Circuit {
+    fn from(wrapper: CircuitWrapper) -> Self {
         Circuit::new(
-            self.gates.iter().map(|gate| gate.clone().into()).collect(),
-            self.output_gates,
+            wrapper.gates.into_iter().map(Gate::from).collect(),
+            wrapper.output_gates,
         )
     }
 }
 
+/// Serializes a compiled circuit, including its full gate list and output indices, so it can be
+/// stored or sent to another machine and executed later.
 pub fn serialize_circuit(circuit: &Circuit) -> anyhow::Result<Vec<u8>> {
     // Convert `Circuit` to `CircuitWrapper`
     let wrapper: CircuitWrapper = circuit.into();
@@ -87,6 +85,7 @@ pub fn serialize_circuit(circuit: &Circuit) -> anyhow::Result<Vec<u8>> {
     Ok(serialized_data)
 }
 
+/// Restores a circuit produced by [`serialize_circuit`].
 pub fn deserialize_circuit(data: &[u8]) -> anyhow::Result<Circuit> {
     // Deserialize into `CircuitWrapper`
     let wrapper: CircuitWrapper = bincode::deserialize(data)?;
@@ -96,30 +95,68 @@ pub fn deserialize_circuit(data: &[u8]) -> anyhow::Result<Circuit> {
     Ok(circuit)
 }
 
+/// A compiled circuit and its contributor inputs, the pair returned by `#[encrypted(compile)]`,
+/// in a form that can be embedded in other serializable messages.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CompiledCircuit {
+    circuit: CircuitWrapper,
+    inputs: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+impl From<&(Circuit, Vec<bool>)> for CompiledCircuit {
+    fn from((circuit, inputs): &(Circuit, Vec<bool>)) -> Self {
+        CompiledCircuit {
+            circuit: circuit.into(),
+            inputs: inputs.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<CompiledCircuit> for (Circuit, Vec<bool>) {
+    fn from(compiled: CompiledCircuit) -> Self {
+        (compiled.circuit.into(), compiled.inputs)
+    }
+}
+
+/// Serializes the `(circuit, inputs)` pair returned by `#[encrypted(compile)]`, so the program
+/// and its encoded inputs can be executed elsewhere.
+#[cfg(feature = "serde")]
+pub fn serialize_compiled(compiled: &(Circuit, Vec<bool>)) -> anyhow::Result<Vec<u8>> {
+    Ok(bincode::serialize(&CompiledCircuit::from(compiled))?)
+}
+
+/// Restores a pair produced by [`serialize_compiled`].
+#[cfg(feature = "serde")]
+pub fn deserialize_compiled(data: &[u8]) -> anyhow::Result<(Circuit, Vec<bool>)> {
+    let compiled: CompiledCircuit = bincode::deserialize(data)?;
+    Ok(compiled.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::executor::get_executor;
     use crate::prelude::*;
 
     #[test]
     fn test_serialize_deserialize_circuit_struct() -> anyhow::Result<()> {
-        #[circuit(compile)]
+        #[encrypted(compile)]
         fn multi_arithmetic(a: u8, b: u8, c: u8, d: u8) -> u8 {
             let res = a * b;
             let res = res + c;
             res - d
         }
 
-        // Initialize the evaluator instance with circuit and dummy input
-        let (circuit, _) = multi_arithmetic(0_u8, 0_u8, 0_u8, 0_u8);
+        let (circuit, inputs) = multi_arithmetic(5_u8, 6_u8, 7_u8, 3_u8);
 
         // Serialize the circuit
         let serialized_data = serialize_circuit(&circuit)?;
-        println!("Serialized Circuit data: {:?}", serialized_data);
 
         // Deserialize back into a `Circuit` struct
         let deserialized_circuit = deserialize_circuit(&serialized_data)?;
-        println!("Deserialized Circuit: {:?}", deserialized_circuit);
 
         // Check if the deserialized circuit is the same as the original circuit
         assert_eq!(circuit.gates(), deserialized_circuit.gates());
@@ -131,6 +168,35 @@ mod tests {
             deserialized_circuit.contrib_inputs()
         );
 
+        // Executing the restored circuit gives the same output as the original
+        let expected = get_executor().execute(&circuit, &inputs, &[])?;
+        let result = get_executor().execute(&deserialized_circuit, &inputs, &[])?;
+        assert_eq!(result, expected);
+
+        let result: u8 = GarbledUint8::new(result).into();
+        assert_eq!(result, 5 * 6 + 7 - 3);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_deserialize_compiled() -> anyhow::Result<()> {
+        #[encrypted(compile)]
+        fn weighted(a: u16, b: u16) -> u16 {
+            a * 3 + b
+        }
+
+        let compiled = weighted(1000_u16, 7_u16);
+        let data = serialize_compiled(&compiled)?;
+        let (circuit, inputs) = deserialize_compiled(&data)?;
+        assert_eq!(circuit.gates(), compiled.0.gates());
+        assert_eq!(inputs, compiled.1);
+
+        let result = get_executor().execute(&circuit, &inputs, &[])?;
+        let result: u16 = GarbledUint16::new(result).into();
+        assert_eq!(result, 3007);
+
         Ok(())
     }
 }
@@ -0,0 +1,34 @@
+use crate::operations::circuits::builder::build_and_execute_length_mask;
+use crate::uint::{GarbledBit, GarbledUint};
+use crate::vec::GarbledVec;
+
+impl<T> GarbledVec<T> {
+    /// One flag per slot, set for the slots below the length.
+    pub fn live_mask(&self) -> Vec<GarbledBit> {
+        build_and_execute_length_mask(self.len(), self.capacity())
+    }
+
+    /// Returns whether the length is zero, without revealing the length itself.
+    pub fn is_empty(&self) -> GarbledBit {
+        self.len().is_one_of(&[0])
+    }
+
+    /// Folds the live elements into `init` in order.
+    ///
+    /// `f` runs on every slot, and a mux keeps the previous accumulator for padding slots, so
+    /// the work done does not depend on the length.
+    pub fn fold<const N: usize>(
+        &self,
+        init: GarbledUint<N>,
+        mut f: impl FnMut(&GarbledUint<N>, &T) -> GarbledUint<N>,
+    ) -> GarbledUint<N> {
+        let live = self.live_mask();
+        self.as_padded_slice()
+            .iter()
+            .zip(&live)
+            .fold(init, |acc, (element, live)| {
+                let next = f(&acc, element);
+                GarbledUint::mux(live, &next, &acc)
+            })
+    }
+}
@@ -0,0 +1,55 @@
+//! Optional values whose presence is private.
+
+use crate::uint::GarbledBit;
+
+/// An optional value with a garbled presence flag.
+///
+/// A missing value still occupies a slot holding padding, so `Some` and `None` look the same
+/// from outside, and the operations select between values with muxes rather than branching on
+/// the flag.
+#[derive(Debug, Clone)]
+pub struct GarbledOption<T> {
+    is_some: GarbledBit,
+    value: T,
+}
+
+impl<T> GarbledOption<T> {
+    pub fn some(value: T) -> Self {
+        Self {
+            is_some: GarbledBit::one(),
+            value,
+        }
+    }
+
+    /// A missing value, represented by `padding`, which is never returned as the value.
+    pub fn none(padding: T) -> Self {
+        Self {
+            is_some: GarbledBit::zero(),
+            value: padding,
+        }
+    }
+
+    /// Pairs a value with an existing presence flag, e.g. one computed in a circuit.
+    pub fn new(is_some: GarbledBit, value: T) -> Self {
+        Self { is_some, value }
+    }
+
+    /// The garbled presence flag.
+    pub fn is_some(&self) -> &GarbledBit {
+        &self.is_some
+    }
+
+    /// The held value, which is padding when the flag is clear.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Applies `f` to the held value whether or not it is present, keeping the flag; `f` must
+    /// therefore accept padding.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> GarbledOption<U> {
+        GarbledOption {
+            is_some: self.is_some,
+            value: f(self.value),
+        }
+    }
+}
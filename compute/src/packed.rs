@@ -0,0 +1,70 @@
+//! Bit storage for the `packed` feature.
+//!
+//! [`GarbledUint`](crate::uint::GarbledUint) and [`GarbledInt`](crate::int::GarbledInt) store
+//! one `bool`, and so one byte, per bit by default. With `packed` they store 64 bits per `u64`
+//! word instead, an 8x reduction that matters for large batches of wide values. Bits are read
+//! through the same accessors either way; `bits()` borrows the `bool`s by default and unpacks a
+//! copy with `packed`, while `iter_bits()` and `to_words()` read the words directly.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Bits packed into `u64` words, least-significant bit first within and across words.
+#[derive(Debug, Clone)]
+pub(crate) struct PackedBits {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PackedBits {
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn get(&self, i: usize) -> Option<bool> {
+        (i < self.len).then(|| (self.words[i / 64] >> (i % 64)) & 1 == 1)
+    }
+
+    /// The words holding the bits; bits past `len` in the last word are zero.
+    pub(crate) fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Takes the low `len` bits of `words` without unpacking them.
+    pub(crate) fn from_words(mut words: Vec<u64>, len: usize) -> Self {
+        words.truncate(len.div_ceil(64));
+        if len % 64 != 0 {
+            if let Some(last) = words.last_mut() {
+                *last &= (1 << (len % 64)) - 1;
+            }
+        }
+        PackedBits { words, len }
+    }
+
+    pub(crate) fn to_vec(&self) -> Vec<bool> {
+        (0..self.len)
+            .map(|i| (self.words[i / 64] >> (i % 64)) & 1 == 1)
+            .collect()
+    }
+}
+
+impl From<Vec<bool>> for PackedBits {
+    fn from(bits: Vec<bool>) -> Self {
+        let mut words = vec![0_u64; bits.len().div_ceil(64)];
+        for (i, &bit) in bits.iter().enumerate() {
+            words[i / 64] |= (bit as u64) << (i % 64);
+        }
+        PackedBits {
+            words,
+            len: bits.len(),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for PackedBits {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.words);
+        self.len = 0;
+    }
+}
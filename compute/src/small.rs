@@ -0,0 +1,172 @@
+//! Inline storage for small garbled unsigned integers.
+//!
+//! [`GarbledUint`] keeps its bits on the heap, so every value created is an allocation. For
+//! widths up to 128 bits, [`SmallGarbledUint`] stores the bits in a single `u128` word instead:
+//! it is `Copy`, never allocates, and converts to and from [`GarbledUint`] when a value has to
+//! enter or leave a circuit. Being `Copy`, it cannot be wiped on drop by the `zeroize` feature.
+
+use crate::uint::GarbledUint;
+use core::fmt::Display;
+
+pub type SmallGarbledUint8 = SmallGarbledUint<8>;
+pub type SmallGarbledUint16 = SmallGarbledUint<16>;
+pub type SmallGarbledUint32 = SmallGarbledUint<32>;
+pub type SmallGarbledUint64 = SmallGarbledUint<64>;
+pub type SmallGarbledUint128 = SmallGarbledUint<128>;
+
+/// An `N`-bit unsigned integer stored inline, least-significant bit first. `N` must be at most
+/// 128; larger widths fail to compile.
+///
+/// ```compile_fail
+/// # use compute::small::SmallGarbledUint;
+/// let too_wide = SmallGarbledUint::<200>::default();
+/// ```
+///
+/// With the `std` feature it has the arithmetic, bitwise and comparison operators of
+/// [`GarbledUint`], which run the same circuits on a heap-backed copy of the operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SmallGarbledUint<const N: usize> {
+    word: u128,
+}
+
+impl<const N: usize> SmallGarbledUint<N> {
+    const MASK: u128 = {
+        assert!(N <= 128, "SmallGarbledUint<N> only supports N <= 128");
+        if N == 128 {
+            u128::MAX
+        } else {
+            (1 << N) - 1
+        }
+    };
+
+    /// Creates a value from the low `N` bits of `word`; higher bits are discarded.
+    pub const fn from_word(word: u128) -> Self {
+        Self {
+            word: word & Self::MASK,
+        }
+    }
+
+    /// The bits of the value as a word; bits at and above `N` are zero.
+    pub const fn word(&self) -> u128 {
+        self.word
+    }
+
+    pub const fn zero() -> Self {
+        Self::from_word(0)
+    }
+
+    pub const fn one() -> Self {
+        Self::from_word(1)
+    }
+
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns bit `i`, least-significant first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    pub fn test_bit(&self, i: usize) -> bool {
+        assert!(i < N, "bit index {} out of range for {} bits", i, N);
+        (self.word >> i) & 1 == 1
+    }
+
+    /// Returns a copy with bit `i` set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    pub fn set_bit(&self, i: usize, value: bool) -> Self {
+        assert!(i < N, "bit index {} out of range for {} bits", i, N);
+        Self {
+            word: (self.word & !(1 << i)) | ((value as u128) << i),
+        }
+    }
+
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..N).map(|i| (self.word >> i) & 1 == 1)
+    }
+
+    /// Copies the value into heap-backed storage, e.g. to use it as a circuit input.
+    pub fn to_uint(&self) -> GarbledUint<N> {
+        GarbledUint::from_words(&[self.word as u64, (self.word >> 64) as u64])
+    }
+}
+
+// Not derived, so that the width check in `MASK` also rejects `SmallGarbledUint<200>::default()`
+impl<const N: usize> Default for SmallGarbledUint<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> Display for SmallGarbledUint<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.word, f)
+    }
+}
+
+impl<const N: usize> From<GarbledUint<N>> for SmallGarbledUint<N> {
+    fn from(value: GarbledUint<N>) -> Self {
+        SmallGarbledUint::from(&value)
+    }
+}
+
+impl<const N: usize> From<&GarbledUint<N>> for SmallGarbledUint<N> {
+    fn from(value: &GarbledUint<N>) -> Self {
+        let words = value.to_words();
+        let low = words.first().copied().unwrap_or(0) as u128;
+        let high = words.get(1).copied().unwrap_or(0) as u128;
+        Self::from_word(low | (high << 64))
+    }
+}
+
+impl<const N: usize> From<SmallGarbledUint<N>> for GarbledUint<N> {
+    fn from(value: SmallGarbledUint<N>) -> Self {
+        value.to_uint()
+    }
+}
+
+macro_rules! impl_primitive_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> From<$t> for SmallGarbledUint<N> {
+                fn from(value: $t) -> Self {
+                    assert!(
+                        N <= <$t>::BITS as usize,
+                        "SmallGarbledUint<N> can only support up to {} bits for {}",
+                        <$t>::BITS,
+                        stringify!($t)
+                    );
+                    Self::from_word(value as u128)
+                }
+            }
+
+            impl<const N: usize> From<SmallGarbledUint<N>> for $t {
+                fn from(value: SmallGarbledUint<N>) -> Self {
+                    assert!(
+                        N <= <$t>::BITS as usize,
+                        "SmallGarbledUint<N> can only be converted to {} if N <= {}",
+                        stringify!($t),
+                        <$t>::BITS
+                    );
+                    value.word as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_conversions!(u8, u16, u32, u64, u128);
+
+impl<const N: usize> From<bool> for SmallGarbledUint<N> {
+    fn from(value: bool) -> Self {
+        Self::from_word(value as u128)
+    }
+}
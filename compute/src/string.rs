@@ -0,0 +1,62 @@
+//! Fixed-capacity text as garbled bytes.
+
+use crate::uint::GarbledUint8;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// UTF-8 text of at most `L` bytes, each a [`GarbledUint8`], padded with zero bytes.
+///
+/// The padding is what lets comparisons run without revealing the length, so the text itself
+/// must not contain NUL bytes. Two strings of different lengths still compare over all `L`
+/// bytes.
+#[derive(Debug, Clone)]
+pub struct GarbledString<const L: usize> {
+    bytes: Vec<GarbledUint8>,
+}
+
+impl<const L: usize> GarbledString<L> {
+    /// Encodes `text`, padding it with zero bytes up to `L`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `text` is longer than `L` bytes or contains a NUL byte.
+    pub fn new(text: &str) -> Self {
+        assert!(
+            text.len() <= L,
+            "{} bytes of text do not fit in GarbledString<{}>",
+            text.len(),
+            L
+        );
+        assert!(
+            !text.as_bytes().contains(&0),
+            "GarbledString text cannot contain NUL bytes, which mark the padding"
+        );
+        let mut bytes: Vec<GarbledUint8> = text.bytes().map(GarbledUint8::from).collect();
+        bytes.resize(L, GarbledUint8::from(0_u8));
+        Self { bytes }
+    }
+
+    /// All `L` bytes, padding included.
+    pub fn as_bytes(&self) -> &[GarbledUint8] {
+        &self.bytes
+    }
+}
+
+impl<const L: usize> From<&str> for GarbledString<L> {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+// Decodes the bytes before the padding, replacing invalid UTF-8 like `from_utf8_lossy`
+impl<const L: usize> From<GarbledString<L>> for String {
+    fn from(value: GarbledString<L>) -> Self {
+        let bytes: Vec<u8> = value
+            .bytes
+            .into_iter()
+            .map(u8::from)
+            .take_while(|&byte| byte != 0)
+            .collect();
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
@@ -0,0 +1,77 @@
+//! Gate-by-gate transcripts for debugging circuits.
+//!
+//! **This breaks the privacy model.** A transcript holds the plaintext value of every wire,
+//! including both parties' inputs, so it can only be computed by someone who knows all inputs.
+//! Use it to debug circuits in local simulation, never with real secret data.
+
+use crate::executor::get_executor;
+use crate::operations::circuits::builder::GateIndex;
+use anyhow::{anyhow, Result};
+use std::fmt::Write;
+use tandem::{Circuit, Gate};
+
+/// The plaintext value of every gate of a simulated circuit, next to the protocol's output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    /// Output of the garbled execution.
+    pub output: Vec<bool>,
+    /// Value computed at each gate, indexed like `Circuit::gates`.
+    pub wires: Vec<bool>,
+}
+
+impl Transcript {
+    /// Returns the value computed at gate `index`.
+    pub fn wire(&self, index: GateIndex) -> bool {
+        self.wires[index as usize]
+    }
+
+    /// Formats one line per gate, e.g. `17: And(0, 8) = 1`, with output gates marked.
+    pub fn dump(&self, circuit: &Circuit) -> String {
+        let mut dump = String::new();
+        for (i, (gate, value)) in circuit.gates().iter().zip(&self.wires).enumerate() {
+            let marker = if circuit.output_gates().contains(&(i as GateIndex)) {
+                " (output)"
+            } else {
+                ""
+            };
+            let _ = writeln!(dump, "{}: {:?} = {}{}", i, gate, *value as u8, marker);
+        }
+        dump
+    }
+}
+
+/// Executes `circuit` with the local executor and records the plaintext value of every gate.
+///
+/// The wire values come from evaluating the gates in the clear, alongside the garbled
+/// execution that produces [`Transcript::output`]; see the [module docs](self) for why this
+/// must never be used on real secret inputs.
+pub fn simulate_with_transcript(
+    circuit: &Circuit,
+    input_contributor: &[bool],
+    input_evaluator: &[bool],
+) -> Result<Transcript> {
+    circuit
+        .validate()
+        .map_err(|e| anyhow!("invalid circuit: {:?}", e))?;
+
+    let mut contributor = input_contributor.iter();
+    let mut evaluator = input_evaluator.iter();
+    let mut wires: Vec<bool> = Vec::with_capacity(circuit.gates().len());
+    for gate in circuit.gates() {
+        let value = match *gate {
+            Gate::InContrib => *contributor
+                .next()
+                .ok_or_else(|| anyhow!("missing contributor input"))?,
+            Gate::InEval => *evaluator
+                .next()
+                .ok_or_else(|| anyhow!("missing evaluator input"))?,
+            Gate::Xor(a, b) => wires[a as usize] ^ wires[b as usize],
+            Gate::And(a, b) => wires[a as usize] & wires[b as usize],
+            Gate::Not(a) => !wires[a as usize],
+        };
+        wires.push(value);
+    }
+
+    let output = get_executor().execute(circuit, input_contributor, input_evaluator)?;
+    Ok(Transcript { output, wires })
+}
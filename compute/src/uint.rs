@@ -1,7 +1,18 @@
+use crate::error::{ConversionError, LengthError, ParseError};
 use crate::int::GarbledInt;
-use std::fmt::Display;
-use std::marker::PhantomData;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Binary, Display, LowerHex, UpperHex};
+use core::marker::PhantomData;
+use core::ops::Index;
+use core::str::FromStr;
 
+#[cfg(feature = "packed")]
+use crate::packed::PackedBits;
+
+#[deprecated(note = "use `GarbledBit` for one-bit integers or `GarbledBool` for logical operators")]
 pub type GarbledBoolean = GarbledUint<1>;
 pub type GarbledBit = GarbledUint<1>;
 pub type GarbledUint2 = GarbledUint<2>;
@@ -16,65 +27,672 @@ pub type GarbledUint256 = GarbledUint<256>;
 pub type GarbledUint512 = GarbledUint<512>;
 pub type GarbledUint1024 = GarbledUint<1024>;
 
-// Define a new type Uint<N>
+/// An `N`-bit unsigned integer, stored as the plaintext bits one party feeds into a circuit.
+///
+/// # Conversions
+///
+/// The `From` impls to and from the primitive integers check the width, not the value, and
+/// panic on a mismatch, e.g. `GarbledUint32::from(5_u16)`. Convert checked values with
+/// `TryFrom` on references, which returns a [`ConversionError`] when the value does not fit, or
+/// with the equivalent `try_from_uX` constructors such as [`GarbledUint::try_from_u16`] and
+/// `try_into_uX` methods such as [`GarbledUint::try_into_u8`].
+///
+/// ```
+/// # use compute::uint::GarbledUint8;
+/// assert!(GarbledUint8::try_from(&300_u16).is_err());
+/// let value = GarbledUint8::try_from(&200_u16).unwrap();
+/// assert_eq!(u8::try_from(&value), Ok(200));
+/// ```
+///
+/// `try_from` on an owned primitive is the standard library's blanket impl over `From`, which
+/// never returns an error.
 #[derive(Debug, Clone)]
 pub struct GarbledUint<const N: usize> {
-    pub bits: Vec<bool>,              // Store the bits of the unsigned integer
+    #[cfg(not(feature = "packed"))]
+    bits: Vec<bool>, // Store the bits of the unsigned integer, LSB first
+    #[cfg(feature = "packed")]
+    bits: PackedBits,
     _phantom: PhantomData<[bool; N]>, // PhantomData to ensure the N bit size
 }
 
 impl<const N: usize> GarbledUint<N> {
+    /// Zero stored as a single bit; use [`GarbledUint::constant`] for all `N` bits.
     pub fn zero() -> Self {
         GarbledUint::new(vec![false])
     }
 
+    /// One stored as a single bit; use [`GarbledUint::constant`] for all `N` bits.
     pub fn one() -> Self {
         GarbledUint::new(vec![true])
     }
 
+    /// Creates `value` with all `N` bits stored, unlike [`GarbledUint::zero`] and
+    /// [`GarbledUint::one`], so it can be mixed into `N`-bit circuits. Widths above 128 bits
+    /// are zero-extended.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in `N` bits.
+    pub fn constant(value: u128) -> Self {
+        assert!(
+            N >= 128 || value >> N == 0,
+            "{} does not fit in GarbledUint<{}>",
+            value,
+            N
+        );
+        GarbledUint::new((0..N).map(|i| i < 128 && (value >> i) & 1 == 1).collect())
+    }
+
     pub fn len(&self) -> usize {
         self.bits.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.bits.is_empty()
+        self.len() == 0
+    }
+
+    /// Packs the bits into bytes, least-significant bit first.
+    ///
+    /// The final byte is padded with zero bits when `N` is not a multiple of 8. Same as
+    /// [`GarbledUint::to_le_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_le_bytes()
+    }
+
+    /// Unpacks exactly `N` bits from bytes produced by [`GarbledUint::to_bytes`].
+    ///
+    /// Returns an error if `bytes` holds fewer than `N` bits.
+    #[cfg(feature = "std")]
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bytes.len() * 8 >= N,
+            "GarbledUint<{}> needs at least {} bytes, got {}",
+            N,
+            N.div_ceil(8),
+            bytes.len()
+        );
+
+        let bits = (0..N).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1).collect();
+        Ok(GarbledUint::new(bits))
+    }
+
+    /// Returns the `N.div_ceil(8)` bytes of the value, least-significant byte first, like
+    /// [`u32::to_le_bytes`]. The unused high bits of the last byte are zero.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        self.to_bits_le()
+            .chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (i, &bit)| byte | ((bit as u8) << i))
+            })
+            .collect()
+    }
+
+    /// Returns the `N.div_ceil(8)` bytes of the value, most-significant byte first, like
+    /// [`u32::to_be_bytes`]. The unused high bits of the first byte are zero.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Reads a value from [`GarbledUint::to_le_bytes`]. Bits above `N` in the last byte are
+    /// ignored.
+    ///
+    /// Returns an error unless `bytes` holds exactly `N.div_ceil(8)` bytes.
+    pub fn from_le_bytes(bytes: &[u8]) -> Result<Self, LengthError> {
+        if bytes.len() != N.div_ceil(8) {
+            return Err(LengthError {
+                expected: N.div_ceil(8),
+                found: bytes.len(),
+            });
+        }
+
+        let bits = (0..N).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1).collect();
+        Ok(GarbledUint::new(bits))
+    }
+
+    /// Reads a value from [`GarbledUint::to_be_bytes`]. Bits above `N` in the first byte are
+    /// ignored.
+    ///
+    /// Returns an error unless `bytes` holds exactly `N.div_ceil(8)` bytes.
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, LengthError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_le_bytes(&bytes)
+    }
+
+    /// Packs an arbitrary-precision integer into `N` bits.
+    ///
+    /// Returns an error if `value` does not fit in `N` bits.
+    #[cfg(feature = "num-bigint")]
+    pub fn from_biguint(value: &num_bigint::BigUint) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            value.bits() <= N as u64,
+            "value needs {} bits but GarbledUint<{}> only holds {}",
+            value.bits(),
+            N,
+            N
+        );
+
+        let bits = (0..N as u64).map(|i| value.bit(i)).collect();
+        Ok(GarbledUint::new(bits))
+    }
+
+    /// Unpacks all bits into an arbitrary-precision integer.
+    #[cfg(feature = "num-bigint")]
+    pub fn to_biguint(&self) -> num_bigint::BigUint {
+        num_bigint::BigUint::from_bytes_le(&self.to_bytes())
+    }
+
+    /// Fills all `N` bits from `rng`, e.g. for test inputs or additive masks.
+    #[cfg(all(feature = "rand", not(target_arch = "wasm32")))]
+    pub fn random(rng: &mut impl rand::Rng) -> Self {
+        GarbledUint::new((0..N).map(|_| rng.gen()).collect())
+    }
+
+    /// Iterates over the bits, least-significant bit first.
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len()).map(move |i| self.stored_bit(i).unwrap_or(false))
+    }
+
+    /// Returns bit `i` (bit 0 is the least significant).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn test_bit(&self, i: usize) -> GarbledBit {
+        assert!(i < N, "bit index {} out of range for {} bits", i, N);
+        GarbledUint::new(vec![self.stored_bit(i).unwrap_or(false)])
+    }
+
+    /// Returns a copy with bit `i` replaced by `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn set_bit(&self, i: usize, value: &GarbledBit) -> Self {
+        assert!(i < N, "bit index {} out of range for {} bits", i, N);
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        bits[i] = value.bits().first().copied().unwrap_or(false);
+        GarbledUint::new(bits)
+    }
+
+    /// Returns a copy with bit `i` set to 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn with_bit_set(&self, i: usize) -> Self {
+        self.set_bit(i, &GarbledBit::one())
+    }
+
+    /// Returns a copy with bit `i` cleared to 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn with_bit_cleared(&self, i: usize) -> Self {
+        self.set_bit(i, &GarbledBit::zero())
+    }
+
+    /// Reverses the order of the `N` bits, moving bit `i` to bit `N - 1 - i`, like
+    /// [`u8::reverse_bits`].
+    pub fn reverse_bits(&self) -> Self {
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        bits.reverse();
+        GarbledUint::new(bits)
+    }
+
+    /// Reverses the byte order, like [`u32::swap_bytes`].
+    ///
+    /// `N` must be a multiple of 8; other widths fail to compile.
+    pub fn swap_bytes(&self) -> Self {
+        const { assert!(N % 8 == 0, "swap_bytes requires N to be a multiple of 8") };
+
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        let bits = bits.rchunks(8).flatten().copied().collect();
+        GarbledUint::new(bits)
+    }
+
+    /// Returns the `M` bits starting at bit `start`, like `(x >> start) as uM`. `M > N` fails to
+    /// compile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range does not fit in `N` bits.
+    pub fn slice<const M: usize>(&self, start: usize) -> GarbledUint<M> {
+        const { assert!(M <= N, "slice cannot be wider than the value") };
+
+        assert!(
+            start.checked_add(M).is_some_and(|end| end <= N),
+            "{} bits at offset {} do not fit in GarbledUint<{}>",
+            M,
+            start,
+            N
+        );
+        let bits = self.to_bits_le();
+        GarbledUint::new(bits[start..start + M].to_vec())
+    }
+
+    /// Places `high` above the bits of `self`, giving a value of `N + M` bits; `K` must be that
+    /// sum, which is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use compute::uint::{GarbledUint32, GarbledUint8};
+    /// let value = GarbledUint8::from(1_u8);
+    /// let _: GarbledUint32 = value.concat(&value);
+    /// ```
+    pub fn concat<const M: usize, const K: usize>(&self, high: &GarbledUint<M>) -> GarbledUint<K> {
+        const { assert!(K == N + M, "concatenating N and M bits gives N + M, not K") };
+
+        let mut bits = self.to_bits_le();
+        bits.extend(high.to_bits_le());
+        GarbledUint::new(bits)
+    }
+
+    /// Widens to `M >= N` bits with zeros above the value, like `x as u64` from a `u32`.
+    /// Narrowing fails to compile.
+    pub fn zero_extend<const M: usize>(&self) -> GarbledUint<M> {
+        const { assert!(M >= N, "zero_extend cannot narrow; use truncate") };
+
+        let mut bits = self.to_bits_le();
+        bits.resize(M, false);
+        GarbledUint::new(bits)
+    }
+
+    /// Keeps the low `M <= N` bits, like `x as u8` from a `u32`. Widening fails to compile.
+    pub fn truncate<const M: usize>(&self) -> GarbledUint<M> {
+        const { assert!(M <= N, "truncate cannot widen; use zero_extend") };
+
+        let mut bits = self.to_bits_le();
+        bits.truncate(M);
+        GarbledUint::new(bits)
+    }
+}
+
+impl<const N: usize> FromIterator<bool> for GarbledUint<N> {
+    /// Collects exactly `N` bits, least-significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator does not yield exactly `N` bits.
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let bits: Vec<bool> = iter.into_iter().collect();
+        assert_eq!(
+            bits.len(),
+            N,
+            "GarbledUint<{}> must be built from exactly {} bits, got {}",
+            N,
+            N,
+            bits.len()
+        );
+        GarbledUint::new(bits)
+    }
+}
+
+impl<const N: usize> Index<usize> for GarbledUint<N> {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(
+            index < N,
+            "bit index {} out of range for GarbledUint<{}>",
+            index,
+            N
+        );
+        // a reference to a constant, since packed storage has no `bool` to borrow
+        if self.stored_bit(index).unwrap_or(false) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl<const N: usize> GarbledUint<N> {
+    /// Parses digits in `radix` (2 to 36) without a prefix, like [`u32::from_str_radix`].
+    /// `_` separators between digits are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let magnitude = parse_magnitude(s, radix)?;
+        magnitude_to_bits(&magnitude, N).map(GarbledUint::new)
+    }
+
+    /// Parses hexadecimal digits, with or without a `0x` prefix, e.g. `"0xdead_beef"`.
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        Self::from_str_radix(strip_radix_prefix(s, 'x'), 16)
+    }
+
+    /// Parses binary digits, with or without a `0b` prefix, e.g. `"0b1010"`.
+    pub fn from_bin(s: &str) -> Result<Self, ParseError> {
+        Self::from_str_radix(strip_radix_prefix(s, 'b'), 2)
     }
 }
 
+/// Parses a decimal literal, or a hexadecimal, octal or binary one with a `0x`, `0o` or `0b`
+/// prefix, e.g. `"0xdeadbeef"`. An optional leading `+` is accepted.
+impl<const N: usize> FromStr for GarbledUint<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('+').unwrap_or(s);
+        let (digits, radix) = split_radix(s);
+        Self::from_str_radix(digits, radix)
+    }
+}
+
+/// Splits a `0x`, `0o` or `0b` prefix off `s`, returning the digits and their radix.
+pub(crate) fn split_radix(s: &str) -> (&str, u32) {
+    for (marker, radix) in [('x', 16), ('o', 8), ('b', 2)] {
+        let stripped = strip_radix_prefix(s, marker);
+        if stripped.len() < s.len() {
+            return (stripped, radix);
+        }
+    }
+    (s, 10)
+}
+
+/// Strips `0<marker>` in either case from the start of `s`.
+pub(crate) fn strip_radix_prefix(s: &str, marker: char) -> &str {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some('0'), Some(c)) if c.eq_ignore_ascii_case(&marker) => chars.as_str(),
+        _ => s,
+    }
+}
+
+/// Parses unsigned digits into a little-endian byte magnitude, growing it as needed.
+pub(crate) fn parse_magnitude(s: &str, radix: u32) -> Result<Vec<u8>, ParseError> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in 2..=36, got {}",
+        radix
+    );
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut magnitude: Vec<u8> = Vec::new();
+    let mut digits = 0;
+    for c in s.chars() {
+        if c == '_' && digits > 0 {
+            continue;
+        }
+        let mut carry = c.to_digit(radix).ok_or(ParseError::InvalidDigit)?;
+        digits += 1;
+        // magnitude = magnitude * radix + digit
+        for byte in magnitude.iter_mut() {
+            let value = *byte as u32 * radix + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        while carry > 0 {
+            magnitude.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    Ok(magnitude)
+}
+
+/// Unpacks a byte magnitude into `width` bits, failing if it needs more.
+pub(crate) fn magnitude_to_bits(magnitude: &[u8], width: usize) -> Result<Vec<bool>, ParseError> {
+    let bits: Vec<bool> = (0..magnitude.len() * 8)
+        .map(|i| (magnitude[i / 8] >> (i % 8)) & 1 == 1)
+        .collect();
+    if bits.iter().skip(width).any(|&bit| bit) {
+        return Err(ParseError::Overflow);
+    }
+
+    let mut bits = bits;
+    bits.resize(width, false);
+    Ok(bits)
+}
+
 impl<const N: usize> Display for GarbledUint<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", u128::from(self.clone()))
     }
 }
 
+impl<const N: usize> Binary for GarbledUint<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_radix(&self.bits(), f, 1, false, "0b")
+    }
+}
+
+impl<const N: usize> LowerHex for GarbledUint<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_radix(&self.bits(), f, 4, false, "0x")
+    }
+}
+
+impl<const N: usize> UpperHex for GarbledUint<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_radix(&self.bits(), f, 4, true, "0x")
+    }
+}
+
+// Format LSB-first bits in a power-of-two radix, honouring width, fill and `#` flags
+pub(crate) fn fmt_radix(
+    bits: &[bool],
+    f: &mut core::fmt::Formatter<'_>,
+    bits_per_digit: usize,
+    uppercase: bool,
+    prefix: &str,
+) -> core::fmt::Result {
+    // Bits are stored little-endian, so digits are produced LSB first and reversed for display
+    let mut digits: Vec<char> = bits
+        .chunks(bits_per_digit)
+        .map(|chunk| {
+            let value = chunk
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, &bit)| acc | ((bit as u32) << i));
+            let digit = core::char::from_digit(value, 1 << bits_per_digit).unwrap_or('0');
+            if uppercase {
+                digit.to_ascii_uppercase()
+            } else {
+                digit
+            }
+        })
+        .collect();
+
+    // Drop leading zeros like the primitive formatters do, keeping at least one digit
+    while digits.len() > 1 && digits.last() == Some(&'0') {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push('0');
+    }
+
+    let digits: String = digits.iter().rev().collect();
+    f.pad_integral(true, prefix, &digits)
+}
+
 // Implement Uint<N>
 impl<const N: usize> GarbledUint<N> {
-    // Constructor for GarbledUint<N> from a boolean vector
+    /// Creates a value from bits in little-endian order: `bits[0]` is the least-significant
+    /// bit. The length is not checked; use [`GarbledUint::from_bits_le`] or
+    /// [`GarbledUint::from_bits_be`] to validate it and to choose the bit order explicitly.
+    // `into` packs the bits with the `packed` feature and is the identity otherwise
+    #[allow(clippy::useless_conversion)]
     pub fn new(bits: Vec<bool>) -> Self {
         //assert_eq!(bits.len(), N, "The number of bits must be {}", N);
         GarbledUint {
-            bits,
+            bits: bits.into(),
             _phantom: PhantomData,
         }
     }
+
+    /// Creates a value from its stored bits, least-significant bit first; the same as
+    /// [`GarbledUint::new`].
+    pub fn from_bits(bits: Vec<bool>) -> Self {
+        GarbledUint::new(bits)
+    }
+
+    /// Returns the stored bits, least-significant bit first. They are borrowed by default;
+    /// with the `packed` feature they are unpacked into a new vector on every call, so prefer
+    /// [`GarbledUint::iter_bits`] or [`GarbledUint::to_words`] there.
+    ///
+    /// Values such as [`GarbledUint::zero`] store fewer than `N` bits, with the missing high
+    /// bits zero; use [`GarbledUint::to_bits_le`] to always get exactly `N`.
+    pub fn bits(&self) -> Cow<'_, [bool]> {
+        #[cfg(not(feature = "packed"))]
+        return Cow::Borrowed(&self.bits);
+        #[cfg(feature = "packed")]
+        return Cow::Owned(self.bits.to_vec());
+    }
+
+    /// Consumes the value and returns its stored bits, least-significant bit first.
+    // Taken rather than moved out, as the `zeroize` feature gives values a `Drop` impl
+    #[allow(unused_mut)]
+    pub fn into_bits(mut self) -> Vec<bool> {
+        #[cfg(not(feature = "packed"))]
+        return core::mem::take(&mut self.bits);
+        #[cfg(feature = "packed")]
+        return self.bits.to_vec();
+    }
+
+    /// Returns stored bit `i`, or `None` past the stored bits, without unpacking the others.
+    pub(crate) fn stored_bit(&self, i: usize) -> Option<bool> {
+        #[cfg(not(feature = "packed"))]
+        return self.bits.get(i).copied();
+        #[cfg(feature = "packed")]
+        return self.bits.get(i);
+    }
+
+    /// Returns the `N.div_ceil(64)` words of the value, least-significant word and bit first.
+    /// With the `packed` feature the stored words are copied without unpacking.
+    pub fn to_words(&self) -> Vec<u64> {
+        #[cfg(feature = "packed")]
+        let mut words = self.bits.words().to_vec();
+        #[cfg(not(feature = "packed"))]
+        let mut words: Vec<u64> = self
+            .bits
+            .chunks(64)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |word, (i, &bit)| word | ((bit as u64) << i))
+            })
+            .collect();
+
+        words.resize(N.div_ceil(64), 0);
+        if N % 64 != 0 {
+            if let Some(last) = words.last_mut() {
+                *last &= (1 << (N % 64)) - 1;
+            }
+        }
+        words
+    }
+
+    /// Creates a value from the low `N` bits of `words`, least-significant word first. With the
+    /// `packed` feature the words are stored as they are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words` holds fewer than `N` bits.
+    pub fn from_words(words: &[u64]) -> Self {
+        assert!(
+            N <= 64 * words.len(),
+            "GarbledUint<{}> needs at least {} words, got {}",
+            N,
+            N.div_ceil(64),
+            words.len()
+        );
+
+        #[cfg(feature = "packed")]
+        return GarbledUint {
+            bits: PackedBits::from_words(words.to_vec(), N),
+            _phantom: PhantomData,
+        };
+        #[cfg(not(feature = "packed"))]
+        return GarbledUint::new(
+            (0..N)
+                .map(|i| (words[i / 64] >> (i % 64)) & 1 == 1)
+                .collect(),
+        );
+    }
+
+    /// Creates a value from exactly `N` bits, least-significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` does not hold exactly `N` bits.
+    pub fn from_bits_le(bits: &[bool]) -> Self {
+        assert_eq!(
+            bits.len(),
+            N,
+            "GarbledUint<{}> needs exactly {} bits, got {}",
+            N,
+            N,
+            bits.len()
+        );
+        GarbledUint::new(bits.to_vec())
+    }
+
+    /// Creates a value from exactly `N` bits, most-significant bit first, so
+    /// `[true, false, false, false]` is 8 for `N = 4`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` does not hold exactly `N` bits.
+    pub fn from_bits_be(bits: &[bool]) -> Self {
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        Self::from_bits_le(&bits)
+    }
+
+    /// Returns all `N` bits, least-significant bit first.
+    pub fn to_bits_le(&self) -> Vec<bool> {
+        let mut bits = self.bits().into_owned();
+        bits.resize(N, false);
+        bits
+    }
+
+    /// Returns all `N` bits, most-significant bit first.
+    pub fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits = self.to_bits_le();
+        bits.reverse();
+        bits
+    }
+
+    /// Reinterprets the same `N` bits as a two's complement [`GarbledInt`], like `u8 as i8`.
+    ///
+    /// No bit changes, so the numeric value does: values with the top bit set become
+    /// negative, e.g. 255 as a `GarbledUint8` is -1 as a `GarbledInt8`. Runs no circuit.
+    pub fn to_signed(self) -> GarbledInt<N> {
+        GarbledInt::new(self.to_bits_le())
+    }
+
+    /// Zero-extends to `M >= N` bits and reinterprets the result as a [`GarbledInt`], like
+    /// `u8 as i16`. The value is unchanged when `M > N`; with `M == N` this is
+    /// [`to_signed`](Self::to_signed).
+    pub fn zero_extend_signed<const M: usize>(&self) -> GarbledInt<M> {
+        self.zero_extend::<M>().to_signed()
+    }
 }
 
 impl<const N: usize> From<GarbledInt<N>> for GarbledUint<N> {
     fn from(uint: GarbledInt<N>) -> Self {
         // Directly copy the bits from the unsigned Uint<N> to the signed GarbledInt<N>
-        GarbledUint {
-            bits: uint.bits,
-            _phantom: PhantomData,
-        }
+        GarbledUint::new(uint.into_bits())
     }
 }
 
 impl<const N: usize> From<&GarbledInt<N>> for GarbledUint<N> {
     fn from(int: &GarbledInt<N>) -> Self {
-        GarbledUint {
-            bits: int.bits.clone(),
-            _phantom: PhantomData,
-        }
+        GarbledUint::new(int.bits().into_owned())
     }
 }
 
@@ -149,9 +767,38 @@ impl<const N: usize> From<u128> for GarbledUint<N> {
     }
 }
 
+// Limb arrays cover widths above 128 bits. Like the primitive conversions they keep the low N
+// bits, with the first element least significant.
+impl<const N: usize, const L: usize> From<[u64; L]> for GarbledUint<N> {
+    fn from(value: [u64; L]) -> Self {
+        assert!(
+            N <= 64 * L,
+            "Uint<N> can only support up to {} bits for [u64; {}]",
+            64 * L,
+            L
+        );
+
+        GarbledUint::from_words(&value)
+    }
+}
+
+impl<const N: usize, const L: usize> From<[u8; L]> for GarbledUint<N> {
+    fn from(value: [u8; L]) -> Self {
+        assert!(
+            N <= 8 * L,
+            "Uint<N> can only support up to {} bits for [u8; {}]",
+            8 * L,
+            L
+        );
+
+        let bits = (0..N).map(|i| (value[i / 8] >> (i % 8)) & 1 == 1).collect();
+        GarbledUint::new(bits)
+    }
+}
+
 impl<const N: usize> From<GarbledUint<N>> for bool {
     fn from(guint: GarbledUint<N>) -> Self {
-        guint.bits[0]
+        guint.bits()[0]
     }
 }
 
@@ -160,7 +807,7 @@ impl<const N: usize> From<GarbledUint<N>> for u8 {
         assert!(N <= 8, "Uint<N> can only be converted to u8 if N <= 8");
 
         let mut value: u8 = 0;
-        for (i, &bit) in guint.bits.iter().enumerate() {
+        for (i, bit) in guint.iter_bits().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -175,7 +822,7 @@ impl<const N: usize> From<GarbledUint<N>> for u16 {
         assert!(N <= 16, "Uint<N> can only be converted to u16 if N <= 16");
 
         let mut value: u16 = 0;
-        for (i, &bit) in guint.bits.iter().enumerate() {
+        for (i, bit) in guint.iter_bits().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -190,7 +837,7 @@ impl<const N: usize> From<GarbledUint<N>> for u32 {
         assert!(N <= 32, "Uint<N> can only be converted to u32 if N <= 32");
 
         let mut value: u32 = 0;
-        for (i, &bit) in guint.bits.iter().enumerate() {
+        for (i, bit) in guint.iter_bits().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -205,7 +852,7 @@ impl<const N: usize> From<GarbledUint<N>> for u64 {
         assert!(N <= 64, "Uint<N> can only be converted to u64 if N <= 64");
 
         let mut value: u64 = 0;
-        for (i, &bit) in guint.bits.iter().enumerate() {
+        for (i, bit) in guint.iter_bits().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -223,7 +870,7 @@ impl<const N: usize> From<GarbledUint<N>> for u128 {
         );
 
         let mut value: u128 = 0;
-        for (i, &bit) in guint.bits.iter().enumerate() {
+        for (i, bit) in guint.iter_bits().enumerate() {
             if bit {
                 value |= 1 << i;
             }
@@ -233,10 +880,157 @@ impl<const N: usize> From<GarbledUint<N>> for u128 {
     }
 }
 
+impl<const N: usize, const L: usize> From<GarbledUint<N>> for [u64; L] {
+    fn from(guint: GarbledUint<N>) -> Self {
+        assert!(
+            N <= 64 * L,
+            "Uint<N> can only be converted to [u64; {}] if N <= {}",
+            L,
+            64 * L
+        );
+
+        let mut value = [0u64; L];
+        let words = guint.to_words();
+        value[..words.len()].copy_from_slice(&words);
+        value
+    }
+}
+
+impl<const N: usize, const L: usize> From<GarbledUint<N>> for [u8; L] {
+    fn from(guint: GarbledUint<N>) -> Self {
+        assert!(
+            N <= 8 * L,
+            "Uint<N> can only be converted to [u8; {}] if N <= {}",
+            L,
+            8 * L
+        );
+
+        let mut value = [0u8; L];
+        for (i, bit) in guint.iter_bits().enumerate() {
+            if bit {
+                value[i / 8] |= 1 << (i % 8);
+            }
+        }
+
+        value
+    }
+}
+
+// Fallible counterparts of the conversions above. They check the value rather than the width,
+// so `try_from_u16(200)` succeeds for `GarbledUint<8>`. `TryFrom` is only implemented for
+// references, since the owned directions are already covered by the blanket impl over `From`.
+macro_rules! impl_try_conversions {
+    ($(($t:ty, $try_from:ident, $try_into:ident)),*) => {
+        $(
+            impl<const N: usize> GarbledUint<N> {
+                #[doc = concat!(
+                    "Converts a `",
+                    stringify!($t),
+                    "`, failing if it needs more than `N` bits."
+                )]
+                pub fn $try_from(value: $t) -> Result<Self, ConversionError> {
+                    let required = (<$t>::BITS - value.leading_zeros()) as usize;
+                    if required > N {
+                        return Err(ConversionError {
+                            required,
+                            available: N,
+                        });
+                    }
+
+                    let mut bits: Vec<bool> =
+                        (0..required).map(|i| (value >> i) & 1 == 1).collect();
+                    bits.resize(N, false);
+                    Ok(GarbledUint::new(bits))
+                }
+
+                #[doc = concat!(
+                    "Converts to `",
+                    stringify!($t),
+                    "`, failing if the value does not fit."
+                )]
+                pub fn $try_into(&self) -> Result<$t, ConversionError> {
+                    let bits = self.bits();
+                    let required = bits.iter().rposition(|&bit| bit).map_or(0, |i| i + 1);
+                    if required > <$t>::BITS as usize {
+                        return Err(ConversionError {
+                            required,
+                            available: <$t>::BITS as usize,
+                        });
+                    }
+
+                    Ok(bits[..required]
+                        .iter()
+                        .enumerate()
+                        .fold(0, |value, (i, &bit)| value | ((bit as $t) << i)))
+                }
+            }
+
+            impl<const N: usize> TryFrom<&$t> for GarbledUint<N> {
+                type Error = ConversionError;
+
+                fn try_from(value: &$t) -> Result<Self, Self::Error> {
+                    GarbledUint::$try_from(*value)
+                }
+            }
+
+            impl<const N: usize> TryFrom<&GarbledUint<N>> for $t {
+                type Error = ConversionError;
+
+                fn try_from(guint: &GarbledUint<N>) -> Result<Self, Self::Error> {
+                    guint.$try_into()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_conversions!(
+    (u8, try_from_u8, try_into_u8),
+    (u16, try_from_u16, try_into_u16),
+    (u32, try_from_u32, try_into_u32),
+    (u64, try_from_u64, try_into_u64),
+    (u128, try_from_u128, try_into_u128)
+);
+
+// Serialized as the `N.div_ceil(8)` bytes of `to_le_bytes`, so the width is checked on the way in
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for GarbledUint<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.to_le_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for GarbledUint<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        GarbledUint::from_le_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+// Wipes the plaintext bits, leaving an empty value that converts to zero
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::Zeroize for GarbledUint<N> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bits);
+    }
+}
+
+// Wipes the plaintext bits so they do not linger in freed memory after a session
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Drop for GarbledUint<N> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::ZeroizeOnDrop for GarbledUint<N> {}
+
 /*
 impl From<GarbledBit> for bool {
     fn from(guint: GarbledUint<1>) -> Self {
-        guint.bits[0]
+        guint.bits()[0]
     }
 }
 */
@@ -0,0 +1,91 @@
+//! Variable-length collections whose length is private.
+
+use crate::uint::{GarbledUint, GarbledUint32};
+use alloc::vec::Vec;
+
+/// A collection with a public capacity and a garbled length.
+///
+/// Every slot up to the capacity holds a value; slots at or past the length hold padding. Each
+/// operation touches every slot the same way and masks off the padding with the length, so
+/// nothing about the length leaks beyond the capacity.
+#[derive(Debug, Clone)]
+pub struct GarbledVec<T> {
+    elements: Vec<T>,
+    len: GarbledUint32,
+}
+
+impl<T: Clone> GarbledVec<T> {
+    /// Creates an empty collection of `capacity` slots, all holding `padding`.
+    pub fn with_capacity(capacity: usize, padding: T) -> Self {
+        Self {
+            elements: alloc::vec![padding; capacity],
+            len: GarbledUint::from(0_u32),
+        }
+    }
+
+    /// Builds a collection holding `values`, padded with `padding` up to `capacity` slots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more values than slots or more than `u32::MAX` values.
+    pub fn from_values(values: Vec<T>, capacity: usize, padding: T) -> Self {
+        assert!(
+            values.len() <= capacity,
+            "{} values do not fit in a GarbledVec of capacity {}",
+            values.len(),
+            capacity
+        );
+        let len = GarbledUint::from(
+            u32::try_from(values.len()).expect("GarbledVec length does not fit in u32"),
+        );
+        let mut elements = values;
+        elements.resize(capacity, padding);
+        Self { elements, len }
+    }
+}
+
+impl<T> GarbledVec<T> {
+    /// Appends `value` while the data owner builds the collection.
+    ///
+    /// This writes to the slot after the current length, which is read locally, so it runs no
+    /// circuit and belongs with the party supplying the input rather than inside a computation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every slot is already in use or the length would pass `u32::MAX`.
+    pub fn push(&mut self, value: T) {
+        let position = u32::from(self.len.clone()) as usize;
+        assert!(
+            position < self.elements.len(),
+            "GarbledVec is full at capacity {}",
+            self.elements.len()
+        );
+        self.elements[position] = value;
+        self.len = GarbledUint::from(
+            u32::try_from(position + 1).expect("GarbledVec length does not fit in u32"),
+        );
+    }
+
+    /// The garbled number of live elements.
+    pub fn len(&self) -> &GarbledUint32 {
+        &self.len
+    }
+
+    /// The public number of slots, live and padding.
+    pub fn capacity(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Every slot, padding included.
+    pub fn as_padded_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// Applies `f` to every slot, padding included, keeping the same length.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> GarbledVec<U> {
+        GarbledVec {
+            elements: self.elements.iter().map(f).collect(),
+            len: self.len.clone(),
+        }
+    }
+}
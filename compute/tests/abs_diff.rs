@@ -0,0 +1,39 @@
+use compute::prelude::*;
+
+fn check(a: u32, b: u32) {
+    let ga: GarbledUint32 = a.into();
+    let gb: GarbledUint32 = b.into();
+    let result: u32 = ga.abs_diff(&gb).into();
+    assert_eq!(result, a.abs_diff(b), "|{} - {}|", a, b);
+}
+
+#[test]
+fn test_abs_diff_greater() {
+    check(100, 42);
+    check(u32::MAX, 0);
+    check(1 << 31, 1);
+}
+
+#[test]
+fn test_abs_diff_less() {
+    check(42, 100);
+    check(0, u32::MAX);
+    check(7, 8);
+}
+
+#[test]
+fn test_abs_diff_equal() {
+    check(0, 0);
+    check(12345, 12345);
+    check(u32::MAX, u32::MAX);
+}
+
+#[test]
+fn test_abs_diff_u8() {
+    for (a, b) in [(0_u8, 255_u8), (255, 0), (128, 127), (3, 200)] {
+        let ga: GarbledUint8 = a.into();
+        let gb: GarbledUint8 = b.into();
+        let result: u8 = ga.abs_diff(&gb).into();
+        assert_eq!(result, a.abs_diff(b));
+    }
+}
@@ -0,0 +1,155 @@
+use compute::prelude::*;
+
+fn add_with<const N: usize>(adder: Adder, a: u128, b: u128) -> (u128, CircuitStats) {
+    let a: GarbledUint<N> = a.into();
+    let b: GarbledUint<N> = b.into();
+
+    let mut builder = WRK17CircuitBuilder::default().with_adder(adder);
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let sum = builder.add(&a, &b);
+
+    let stats = circuit_stats(&builder.compile(&sum)).unwrap();
+    let result: GarbledUint<N> = builder.compile_and_execute(&sum).unwrap();
+    (result.into(), stats)
+}
+
+fn check_width<const N: usize>() {
+    let mask = if N == 128 { u128::MAX } else { (1 << N) - 1 };
+    let values = [
+        0,
+        1,
+        mask,
+        mask >> 1,
+        0x5555_5555_5555_5555_5555_5555_5555_5555 & mask,
+        0xdead_beef_0123_4567_89ab_cdef_f00d_cafe & mask,
+    ];
+
+    for &a in &values {
+        for &b in &values {
+            let (ripple, _) = add_with::<N>(Adder::RippleCarry, a, b);
+            assert_eq!(ripple, a.wrapping_add(b) & mask, "{a} + {b} at {N} bits");
+            for adder in [Adder::CarryLookahead, Adder::KoggeStone, Adder::BrentKung] {
+                let (sum, _) = add_with::<N>(adder, a, b);
+                assert_eq!(sum, ripple, "{a} + {b} at {N} bits with {adder:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_prefix_adders_match_ripple() {
+    check_width::<1>();
+    check_width::<2>();
+    check_width::<3>();
+    check_width::<5>();
+    check_width::<8>();
+    check_width::<13>();
+    check_width::<16>();
+    check_width::<32>();
+    check_width::<64>();
+    check_width::<128>();
+}
+
+#[test]
+fn test_lookahead_reduces_depth() {
+    let (_, ripple) = add_with::<64>(Adder::RippleCarry, 1, 2);
+    let (_, lookahead) = add_with::<64>(Adder::CarryLookahead, 1, 2);
+
+    // the ripple carry passes through every bit
+    assert!(ripple.and_depth >= 63);
+    assert!(
+        lookahead.and_depth <= 8,
+        "lookahead depth {}",
+        lookahead.and_depth
+    );
+    // the shallower adder pays for it with extra AND gates
+    assert!(lookahead.and_gates > ripple.and_gates);
+}
+
+#[test]
+fn test_lookahead_multiplication() {
+    let a: GarbledUint32 = 123_456_u32.into();
+    let b: GarbledUint32 = 7_891_u32.into();
+
+    let mut builder = WRK17CircuitBuilder::default().with_adder(Adder::CarryLookahead);
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let product = builder.mul(&a, &b);
+
+    let result: GarbledUint32 = builder.compile_and_execute(&product).unwrap();
+    assert_eq!(u32::from(result), 123_456_u32.wrapping_mul(7_891));
+}
+
+#[test]
+fn test_lookahead_widening_multiplication() {
+    let mut builder = WRK17CircuitBuilder::default().with_adder(Adder::CarryLookahead);
+    let a = builder.input(&GarbledUint32::from(u32::MAX));
+    let b = builder.input(&GarbledUint32::from(4_000_000_007_u32));
+    let product = builder.widening_mul(&a, &b);
+
+    let result: GarbledUint64 = builder.compile_and_execute(&product).unwrap();
+    assert_eq!(u64::from(result), u32::MAX as u64 * 4_000_000_007);
+}
+
+#[test]
+fn test_prefix_adder_tradeoffs() {
+    let (_, ripple) = add_with::<64>(Adder::RippleCarry, 1, 2);
+    let (_, sklansky) = add_with::<64>(Adder::CarryLookahead, 1, 2);
+    let (_, kogge_stone) = add_with::<64>(Adder::KoggeStone, 1, 2);
+    let (_, brent_kung) = add_with::<64>(Adder::BrentKung, 1, 2);
+
+    // Kogge-Stone is the shallowest and the largest
+    assert!(kogge_stone.and_depth <= sklansky.and_depth);
+    assert!(kogge_stone.and_gates > sklansky.and_gates);
+
+    // Brent-Kung trades about twice the depth for far fewer gates
+    assert!(
+        brent_kung.and_depth <= 2 * 6,
+        "brent-kung depth {}",
+        brent_kung.and_depth
+    );
+    assert!(brent_kung.and_gates < sklansky.and_gates);
+    assert!(brent_kung.and_gates > ripple.and_gates);
+    assert!(brent_kung.and_depth < ripple.and_depth / 4);
+}
+
+fn sum_depth(adder: Adder, values: &[u32], tree: bool) -> (u32, usize) {
+    let mut builder = WRK17CircuitBuilder::default().with_adder(adder);
+    let inputs: Vec<_> = values
+        .iter()
+        .map(|&value| builder.input(&GarbledUint32::from(value)))
+        .collect();
+    let sum = if tree {
+        builder.reduce_add(&inputs)
+    } else {
+        let mut sum = inputs[0].clone();
+        for value in &inputs[1..] {
+            sum = builder.add(&sum, value);
+        }
+        sum
+    };
+
+    let depth = circuit_stats(&builder.compile(&sum)).unwrap().and_depth;
+    let result: GarbledUint32 = builder.compile_and_execute(&sum).unwrap();
+    (result.into(), depth)
+}
+
+#[test]
+fn test_reduce_add_is_balanced() {
+    let values: Vec<u32> = (1..=16).map(|i| i * 1_000_003).collect();
+    let expected = values.iter().fold(0_u32, |acc, &x| acc.wrapping_add(x));
+
+    let (tree, tree_depth) = sum_depth(Adder::CarryLookahead, &values, true);
+    let (chain, chain_depth) = sum_depth(Adder::CarryLookahead, &values, false);
+    assert_eq!(tree, expected);
+    assert_eq!(chain, expected);
+    // four levels of shallow adders rather than fifteen in a row
+    assert!(
+        2 * tree_depth < chain_depth,
+        "tree depth {tree_depth} vs chain depth {chain_depth}"
+    );
+
+    let (tree, _) = sum_depth(Adder::RippleCarry, &values, true);
+    assert_eq!(tree, expected);
+}
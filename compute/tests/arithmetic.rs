@@ -425,6 +425,81 @@ fn test_uint_div() {
     assert_eq!(result, 300_u16 / 7_u16); // Expected result of division between 1010101010101011 and 0101010101010101
 }
 
+#[test]
+fn test_uint_div_rem_odd_dividend() {
+    // the low bit of the dividend is the first input wire and must not leak into the remainder
+    for (x, y) in [(7_u8, 2_u8), (255, 16), (1, 1), (129, 3), (13, 200)] {
+        let a: GarbledUint8 = x.into();
+        let b: GarbledUint8 = y.into();
+        let quotient: u8 = (&a / &b).into();
+        let remainder: u8 = (&a % &b).into();
+        assert_eq!(quotient, x / y, "{} / {}", x, y);
+        assert_eq!(remainder, x % y, "{} % {}", x, y);
+    }
+}
+
+#[test]
+fn test_div_rem_divisor_is_first_input() {
+    // wire 0 is the divisor's low bit here, which must not seed the remainder either
+    for (x, y) in [(7_u8, 3_u8), (200, 9), (6, 1)] {
+        let mut builder = WRK17CircuitBuilder::default();
+        let divisor = builder.input(&GarbledUint8::from(y));
+        let dividend = builder.input(&GarbledUint8::from(x));
+        let (quotient, remainder) = builder.div_rem(&dividend, &divisor);
+
+        let mut output = quotient;
+        output.push_all(&remainder);
+        let result = builder.compile_and_execute::<16>(&output).unwrap();
+        let result: u16 = result.into();
+        assert_eq!(result as u8, x / y, "{} / {}", x, y);
+        assert_eq!((result >> 8) as u8, x % y, "{} % {}", x, y);
+    }
+}
+
+#[test]
+fn test_uint_div_full_width() {
+    let values = [0_u8, 1, 2, 3, 7, 64, 127, 128, 129, 200, 254, 255];
+    for &x in &values {
+        for &y in values.iter().filter(|&&y| y != 0) {
+            let quotient: u8 = (GarbledUint8::from(x) / GarbledUint8::from(y)).into();
+            assert_eq!(quotient, x / y, "{} / {}", x, y);
+        }
+    }
+
+    for (x, y) in [
+        (u32::MAX, 1_u32),
+        (u32::MAX, u32::MAX),
+        (u32::MAX - 1, u32::MAX),
+        (0xdead_beef, 0x8000_0001),
+        (4_000_000_000, 65_537),
+    ] {
+        let quotient: u32 = (GarbledUint32::from(x) / GarbledUint32::from(y)).into();
+        assert_eq!(quotient, x / y, "{} / {}", x, y);
+    }
+
+    for (x, y) in [
+        (u64::MAX, 3_u64),
+        (u64::MAX, 1 << 63),
+        (0x0123_4567_89ab_cdef, 0xffff_ffff),
+    ] {
+        let quotient: u64 = (GarbledUint64::from(x) / GarbledUint64::from(y)).into();
+        assert_eq!(quotient, x / y, "{} / {}", x, y);
+    }
+
+    let (x, y) = (u128::MAX - 12345, (1_u128 << 127) + 99);
+    let quotient: u128 = (GarbledUint128::from(x) / GarbledUint128::from(y)).into();
+    assert_eq!(quotient, x / y);
+    let (x, y) = (u128::MAX, 10_u128);
+    let quotient: u128 = (GarbledUint128::from(x) / GarbledUint128::from(y)).into();
+    assert_eq!(quotient, x / y);
+}
+
+#[test]
+fn test_uint_div_by_zero() {
+    let quotient: u16 = (GarbledUint16::from(1234_u16) / GarbledUint16::from(0_u16)).into();
+    assert_eq!(quotient, u16::MAX);
+}
+
 #[test]
 fn test_int_div() {
     let a: GarbledInt8 = 6_i8.into();
@@ -440,6 +515,67 @@ fn test_int_div() {
     assert_eq!(result, 134_i16 / 85_i16);
 }
 
+#[test]
+fn test_int_div_rem_matches_native() {
+    let values = [i8::MIN, -127, -100, -7, -2, -1, 0, 1, 2, 3, 7, 100, i8::MAX];
+    for &x in &values {
+        for &y in values.iter().filter(|&&y| y != 0) {
+            let a = GarbledInt8::from(x);
+            let b = GarbledInt8::from(y);
+            assert_eq!(i8::from(&a / &b), x.wrapping_div(y), "{} / {}", x, y);
+            assert_eq!(i8::from(&a % &b), x.wrapping_rem(y), "{} % {}", x, y);
+        }
+    }
+
+    for (x, y) in [
+        (i32::MIN, -1_i32),
+        (i32::MIN, 7),
+        (-2_000_000_000, -65_537),
+        (123_456_789, -1000),
+    ] {
+        let (quotient, remainder) = GarbledInt32::from(x).div_rem(&GarbledInt32::from(y));
+        assert_eq!(
+            (i32::from(quotient), i32::from(remainder)),
+            (x.wrapping_div(y), x.wrapping_rem(y)),
+            "{} / {}",
+            x,
+            y
+        );
+    }
+
+    let (x, y) = (i64::MIN + 1, -3_i64);
+    assert_eq!(
+        i64::from(GarbledInt64::from(x) / GarbledInt64::from(y)),
+        x / y
+    );
+    assert_eq!(
+        i64::from(GarbledInt64::from(x) % GarbledInt64::from(y)),
+        x % y
+    );
+}
+
+#[test]
+fn test_int_div_by_zero() {
+    let zero = GarbledInt16::from(0_i16);
+    let (quotient, remainder) = GarbledInt16::from(300_i16).div_rem(&zero);
+    assert_eq!((i16::from(quotient), i16::from(remainder)), (-1, 300));
+    let (quotient, remainder) = GarbledInt16::from(-300_i16).div_rem(&zero);
+    assert_eq!((i16::from(quotient), i16::from(remainder)), (1, -300));
+}
+
+#[test]
+fn test_int_neg() {
+    for x in [i8::MIN, -100, -1, 0, 1, 7, i8::MAX] {
+        assert_eq!(i8::from(-GarbledInt8::from(x)), x.wrapping_neg(), "-{}", x);
+    }
+    for x in [i64::MIN, -1_234_567_890_123, 0, 1, i64::MAX] {
+        let value = GarbledInt64::from(x);
+        assert_eq!(i64::from(-&value), x.wrapping_neg(), "-{}", x);
+    }
+    let x = i128::MIN + 12345;
+    assert_eq!(i128::from(-GarbledInt128::from(x)), -x);
+}
+
 #[test]
 fn test_uint_div_assign() {
     let mut a: GarbledUint8 = 6_u8.into(); // Binary 0110
@@ -487,6 +623,152 @@ fn test_uint_rem() {
     assert_eq!(result, 300_u16 % 7_u16); // Expected result of remainder between 1010101010101011 and 0101010101010101
 }
 
+#[test]
+fn test_uint_rem_full_width() {
+    let values = [0_u8, 1, 2, 3, 7, 64, 127, 128, 129, 200, 254, 255];
+    for &x in &values {
+        for &y in values.iter().filter(|&&y| y != 0) {
+            let remainder: u8 = (GarbledUint8::from(x) % GarbledUint8::from(y)).into();
+            assert_eq!(remainder, x % y, "{} % {}", x, y);
+        }
+    }
+
+    for (x, y) in [
+        (u32::MAX, u32::MAX - 1),
+        (0xdead_beef, 0x8000_0001),
+        (4_000_000_000, 65_537),
+    ] {
+        let remainder: u32 = (GarbledUint32::from(x) % GarbledUint32::from(y)).into();
+        assert_eq!(remainder, x % y, "{} % {}", x, y);
+    }
+
+    let (x, y) = (u64::MAX, 0xffff_fffb_u64);
+    let remainder: u64 = (GarbledUint64::from(x) % GarbledUint64::from(y)).into();
+    assert_eq!(remainder, x % y);
+    let (x, y) = (u128::MAX - 12345, (1_u128 << 127) + 99);
+    let remainder: u128 = (GarbledUint128::from(x) % GarbledUint128::from(y)).into();
+    assert_eq!(remainder, x % y);
+
+    // dividing by zero leaves the dividend
+    let remainder: u16 = (GarbledUint16::from(1234_u16) % GarbledUint16::from(0_u16)).into();
+    assert_eq!(remainder, 1234);
+}
+
+#[test]
+fn test_uint_div_rem() {
+    for (x, y) in [(100_u16, 7_u16), (65535, 256), (3, 5), (40000, 40000)] {
+        let (quotient, remainder) = GarbledUint16::from(x).div_rem(&GarbledUint16::from(y));
+        assert_eq!((u16::from(quotient), u16::from(remainder)), (x / y, x % y));
+    }
+}
+
+#[test]
+fn test_uint_widening_mul() {
+    for (x, y) in [(0_u8, 0_u8), (255, 255), (200, 3), (1, 255), (17, 15)] {
+        let product: GarbledUint16 = GarbledUint8::from(x).widening_mul(&y.into());
+        assert_eq!(u16::from(product), x as u16 * y as u16);
+    }
+
+    let x = 0xffff_ffff_ffff_fffb_u64;
+    let y = 0x1234_5678_9abc_def0_u64;
+    let product: GarbledUint128 = GarbledUint64::from(x).widening_mul(&y.into());
+    assert_eq!(u128::from(product.clone()), x as u128 * y as u128);
+    // the halves are what `*` and a high multiply would give
+    assert_eq!(u64::from(product.slice::<64>(0)), x.wrapping_mul(y));
+    assert_eq!(
+        u64::from(product.slice::<64>(64)),
+        ((x as u128 * y as u128) >> 64) as u64
+    );
+}
+
+#[test]
+#[should_panic(expected = "inputs must be added before any constant")]
+fn test_widening_mul_then_input() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(200_u8));
+    let b = builder.input(&GarbledUint8::from(3_u8));
+    builder.widening_mul(&a, &b);
+    builder.input(&GarbledUint8::from(1_u8));
+}
+
+#[test]
+fn test_uint_isqrt() {
+    for x in 0..=u8::MAX {
+        assert_eq!(
+            u8::from(GarbledUint8::from(x).isqrt()),
+            x.isqrt(),
+            "isqrt({})",
+            x
+        );
+    }
+
+    for x in [
+        0_u64,
+        1,
+        2,
+        3,
+        4,
+        99,
+        100,
+        101,
+        u32::MAX as u64,
+        u64::MAX,
+        1 << 63,
+    ] {
+        assert_eq!(
+            u64::from(GarbledUint64::from(x).isqrt()),
+            x.isqrt(),
+            "isqrt({})",
+            x
+        );
+    }
+
+    // an odd width brings down a single bit first
+    for x in [0_u16, 1, 1000, 2047, 1024] {
+        let root = GarbledUint::<11>::from(x).isqrt();
+        assert_eq!(u16::from(root), x.isqrt(), "isqrt({})", x);
+    }
+}
+
+#[test]
+#[should_panic(expected = "inputs must be added before any constant")]
+fn test_isqrt_then_input() {
+    // an input inserted after the gadget would shift the gates it already emitted
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(100_u8));
+    builder.isqrt(&a);
+    builder.input(&GarbledUint8::from(1_u8));
+}
+
+#[test]
+fn test_uint_sum() {
+    let values: Vec<u16> = vec![1, 2, 3, 40_000, 30_000, 7];
+    let garbled: Vec<GarbledUint16> = values.iter().map(|&x| x.into()).collect();
+
+    let expected = values.iter().fold(0_u16, |acc, &x| acc.wrapping_add(x));
+    let by_ref: GarbledUint16 = garbled.iter().sum();
+    assert_eq!(u16::from(by_ref), expected);
+    let by_value: GarbledUint16 = garbled.into_iter().sum();
+    assert_eq!(u16::from(by_value), expected);
+
+    // an empty sum is zero and a single value is unchanged
+    let empty: GarbledUint16 = Vec::<GarbledUint16>::new().into_iter().sum();
+    assert_eq!(u16::from(empty.clone()), 0);
+    let value: GarbledUint16 = 1000_u16.into();
+    assert_eq!(u16::from(&empty + &value), 1000);
+    assert_eq!(u16::from(&value + &empty), 1000);
+    let single: GarbledUint16 = std::iter::once(GarbledUint16::one()).sum();
+    assert_eq!(u16::from(single), 1);
+}
+
+#[test]
+fn test_int_sum() {
+    let values = [-100_i8, 50, -3, 127, 1];
+    let sum: GarbledInt8 = values.iter().map(|&x| GarbledInt8::from(x)).sum();
+    let expected = values.iter().fold(0_i8, |acc, &x| acc.wrapping_add(x));
+    assert_eq!(i8::from(sum), expected);
+}
+
 #[test]
 fn test_int_rem() {
     let a: GarbledInt8 = 6_i8.into();
@@ -531,3 +813,50 @@ fn test_int_rem_assign() {
     a %= b;
     assert_eq!(<GarbledInt<16> as Into<i16>>::into(a), 134_i16 % 85_i16);
 }
+
+#[test]
+fn test_int_mul_signed() {
+    let cases_i8 = [
+        (-3_i8, 5_i8),
+        (-4, -4),
+        (7, -9),
+        (-128, -1),
+        (127, 2),
+        (-1, -1),
+    ];
+    for (x, y) in cases_i8 {
+        let a: GarbledInt8 = x.into();
+        let b: GarbledInt8 = y.into();
+        let result: i8 = (a * b).into();
+        assert_eq!(result, x.wrapping_mul(y), "{} * {}", x, y);
+    }
+
+    let cases_i16 = [(-300_i16, 7_i16), (-181, -181), (1024, -33), (i16::MIN, -1)];
+    for (x, y) in cases_i16 {
+        let a: GarbledInt16 = x.into();
+        let b: GarbledInt16 = y.into();
+        let result: i16 = (&a * &b).into();
+        assert_eq!(result, x.wrapping_mul(y), "{} * {}", x, y);
+    }
+
+    let cases_i32 = [(-123456_i32, 789_i32), (-46341, -46341), (65536, -65536)];
+    for (x, y) in cases_i32 {
+        let a: GarbledInt32 = x.into();
+        let b: GarbledInt32 = y.into();
+        let result: i32 = (a * b).into();
+        assert_eq!(result, x.wrapping_mul(y), "{} * {}", x, y);
+    }
+}
+
+#[test]
+fn test_int_mul_assign_signed() {
+    let mut a: GarbledInt8 = (-3_i8).into();
+    let b: GarbledInt8 = 5_i8.into();
+    a *= b;
+    assert_eq!(<GarbledInt<8> as Into<i8>>::into(a), -15);
+
+    let mut a: GarbledInt8 = (-4_i8).into();
+    let b: GarbledInt8 = (-4_i8).into();
+    a *= &b;
+    assert_eq!(<GarbledInt<8> as Into<i8>>::into(a), 16);
+}
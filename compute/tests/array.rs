@@ -0,0 +1,62 @@
+use compute::prelude::*;
+
+fn table() -> GarbledArray<GarbledUint8, 5> {
+    GarbledArray::new([3_u8, 14, 15, 92, 65].map(GarbledUint8::from))
+}
+
+#[test]
+fn test_array_get_each_index() {
+    let array = table();
+    for (i, expected) in [3_u8, 14, 15, 92, 65].into_iter().enumerate() {
+        let index: GarbledUint<3> = (i as u8).into();
+        assert_eq!(u8::from(array.get(&index)), expected, "index {}", i);
+    }
+}
+
+#[test]
+fn test_array_get_out_of_range_is_zero() {
+    let array = table();
+    for i in 5..8_u8 {
+        let index: GarbledUint<3> = i.into();
+        assert_eq!(u8::from(array.get(&index)), 0, "index {}", i);
+    }
+
+    // index bits past the tree must all be clear
+    let wide_index: GarbledUint8 = 129_u8.into();
+    assert_eq!(u8::from(array.get(&wide_index)), 0);
+    let wide_index: GarbledUint8 = 1_u8.into();
+    assert_eq!(u8::from(array.get(&wide_index)), 14);
+}
+
+#[test]
+fn test_array_get_narrow_index() {
+    // a 2-bit index reaches only the first four elements
+    let array = table();
+    for i in 0..4_u8 {
+        let index: GarbledUint2 = i.into();
+        assert_eq!(
+            u8::from(array.get(&index)),
+            u8::from(array[i as usize].clone())
+        );
+    }
+}
+
+#[test]
+fn test_array_get_signed() {
+    let array = GarbledArray::new([-5_i16, 300, -32768, 7].map(GarbledInt16::from));
+    for (i, expected) in [-5_i16, 300, -32768, 7].into_iter().enumerate() {
+        let index: GarbledUint<2> = (i as u8).into();
+        assert_eq!(i16::from(array.get(&index)), expected);
+    }
+}
+
+#[test]
+fn test_array_single_and_empty() {
+    let single = GarbledArray::new([GarbledUint8::from(42_u8)]);
+    assert_eq!(u8::from(single.get(&GarbledUint4::from(0_u8))), 42);
+    assert_eq!(u8::from(single.get(&GarbledUint4::from(1_u8))), 0);
+
+    let empty: GarbledArray<GarbledUint8, 0> = GarbledArray::new([]);
+    assert!(empty.is_empty());
+    assert_eq!(u8::from(empty.get(&GarbledUint4::from(0_u8))), 0);
+}
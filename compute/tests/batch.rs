@@ -0,0 +1,44 @@
+use compute::prelude::*;
+
+#[test]
+fn test_batch_apply_and() {
+    let lhs: Vec<GarbledUint8> = (0..32_u8).map(|i| (i * 7).into()).collect();
+    let rhs: Vec<GarbledUint8> = (0..32_u8).map(|i| (255 - i * 3).into()).collect();
+
+    let batched = GarbledUint8::batch_apply(WRK17CircuitBuilder::and, &lhs, &rhs);
+    assert_eq!(batched.len(), lhs.len());
+
+    for ((a, b), result) in lhs.iter().zip(&rhs).zip(batched) {
+        let expected: u8 = (a & b).into();
+        let result: u8 = result.into();
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_batch_apply_add() {
+    let lhs: Vec<GarbledUint16> = (0..16_u16).map(|i| (i * 1000).into()).collect();
+    let rhs: Vec<GarbledUint16> = (0..16_u16).map(|i| (i * 3 + 1).into()).collect();
+
+    let batched = GarbledUint16::batch_apply(WRK17CircuitBuilder::add, &lhs, &rhs);
+
+    for ((a, b), result) in lhs.iter().zip(&rhs).zip(batched) {
+        let expected: u16 = (a + b).into();
+        let result: u16 = result.into();
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_batch_apply_empty() {
+    let batched = GarbledUint8::batch_apply(WRK17CircuitBuilder::xor, &[], &[]);
+    assert!(batched.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "batch operands must have the same length")]
+fn test_batch_apply_length_mismatch() {
+    let lhs: Vec<GarbledUint8> = vec![1_u8.into(), 2_u8.into()];
+    let rhs: Vec<GarbledUint8> = vec![1_u8.into()];
+    GarbledUint8::batch_apply(WRK17CircuitBuilder::xor, &lhs, &rhs);
+}
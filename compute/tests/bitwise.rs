@@ -517,6 +517,121 @@ fn test_uint_left_shift_and_assign() {
     assert_eq!(<GarbledUint<4> as Into<u8>>::into(a), 0b1000_u8); // Binary 1000 (Left shift result of 0001)
 }
 
+#[test]
+fn test_uint_checked_left_shift() {
+    // only 0 bits are shifted out
+    let a: GarbledUint8 = 0b0001_0110_u8.into();
+    let (result, lost) = a.checked_shl(3);
+    assert_eq!(u8::from(result), 0b1011_0000);
+    assert!(!bool::from(lost));
+
+    // a 1 bit is shifted out, matching the wrapping `<<` result
+    let (result, lost) = a.checked_shl(4);
+    assert_eq!(u8::from(result), 0b0110_0000);
+    assert!(bool::from(lost));
+
+    let a: GarbledUint8 = 0b1000_0000_u8.into();
+    let (result, lost) = a.checked_shl(1);
+    assert_eq!(u8::from(result), 0);
+    assert!(bool::from(lost));
+
+    // shifting by zero never loses bits; shifting past the width loses any set bit
+    let (result, lost) = a.checked_shl(0);
+    assert_eq!(u8::from(result), 0b1000_0000);
+    assert!(!bool::from(lost));
+
+    let (result, lost) = GarbledUint8::from(1_u8).checked_shl(8);
+    assert_eq!(u8::from(result), 0);
+    assert!(bool::from(lost));
+
+    let (result, lost) = GarbledUint8::zero().checked_shl(20);
+    assert_eq!(u8::from(result), 0);
+    assert!(!bool::from(lost));
+}
+
+#[test]
+fn test_uint_rotate() {
+    for x in [0b1011_0001_u8, 0x80, 0x01, 0xff, 0] {
+        let a: GarbledUint8 = x.into();
+        for n in 0..20 {
+            assert_eq!(u8::from(a.rotate_left(n)), x.rotate_left(n as u32));
+            assert_eq!(u8::from(a.rotate_right(n)), x.rotate_right(n as u32));
+        }
+    }
+
+    let a: GarbledUint16 = 0x1234_u16.into();
+    assert_eq!(u16::from(a.rotate_left(4)), 0x2341);
+    assert_eq!(u16::from(a.rotate_right(4)), 0x4123);
+}
+
+#[test]
+fn test_uint_rotate_by_garbled_amount() {
+    let x = 0b1011_0001_u8;
+    let a: GarbledUint8 = x.into();
+    for n in 0..16_u8 {
+        let amount: GarbledUint4 = n.into();
+        assert_eq!(
+            u8::from(a.rotate_left_by(&amount)),
+            x.rotate_left(n as u32),
+            "rotate_left({})",
+            n
+        );
+        assert_eq!(
+            u8::from(a.rotate_right_by(&amount)),
+            x.rotate_right(n as u32),
+            "rotate_right({})",
+            n
+        );
+    }
+
+    let a: GarbledUint32 = 0xdead_beef_u32.into();
+    let amount: GarbledUint8 = 200_u8.into();
+    assert_eq!(
+        u32::from(a.rotate_left_by(&amount)),
+        0xdead_beef_u32.rotate_left(200)
+    );
+
+    // a width that is not a power of two takes the amount modulo the width
+    let a: GarbledUint<12> = 0xabc_u16.into();
+    for n in 0..16_u8 {
+        let amount: GarbledUint4 = n.into();
+        assert_eq!(a.rotate_left_by(&amount), a.rotate_left(n as usize));
+        assert_eq!(a.rotate_right_by(&amount), a.rotate_right(n as usize));
+    }
+}
+
+#[test]
+fn test_uint_leading_trailing_zeros() {
+    for x in 0..=u8::MAX {
+        let a: GarbledUint8 = x.into();
+        assert_eq!(u32::from(a.leading_zeros()), x.leading_zeros(), "{x:#x}");
+        assert_eq!(u32::from(a.trailing_zeros()), x.trailing_zeros(), "{x:#x}");
+    }
+
+    for x in [0_u64, 1, 1 << 63, 0x0000_ffff_0000_0000, u64::MAX, 0x10] {
+        let a: GarbledUint64 = x.into();
+        assert_eq!(u32::from(a.leading_zeros()), x.leading_zeros(), "{x:#x}");
+        assert_eq!(u32::from(a.trailing_zeros()), x.trailing_zeros(), "{x:#x}");
+    }
+
+    // widths that are not powers of two count up to the width
+    let a: GarbledUint<12> = 0_u16.into();
+    assert_eq!(u32::from(a.leading_zeros()), 12);
+    assert_eq!(u32::from(a.trailing_zeros()), 12);
+    let a: GarbledUint<12> = 0b0000_0101_1000_u16.into();
+    assert_eq!(u32::from(a.leading_zeros()), 5);
+    assert_eq!(u32::from(a.trailing_zeros()), 3);
+}
+
+#[test]
+#[should_panic(expected = "inputs must be added before any constant")]
+fn test_leading_zeros_then_input() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(0x10_u8));
+    builder.leading_zeros(&a);
+    builder.input(&GarbledUint8::from(1_u8));
+}
+
 #[test]
 fn test_int_left_shift() {
     let a: GarbledInt8 = 0b1000_i8.into(); // Binary 1000
@@ -915,4 +1030,85 @@ fn test_right_shift_int() {
 
     let result: i8 = (a >> 3).into(); // Perform right shift by 3
     assert_eq!(result, 0b0000_i8); // Binary 0000 (Right shift result of 0001)
+
+    // negative values keep their sign, like `>>` on `i8`
+    for x in [-128_i8, -100, -8, -1] {
+        for shift in 0..8 {
+            let a: GarbledInt8 = x.into();
+            let result: i8 = (&a >> shift).into();
+            assert_eq!(result, x >> shift, "{} >> {}", x, shift);
+        }
+    }
+
+    let mut a: GarbledInt16 = (-1000_i16).into();
+    a >>= 3;
+    assert_eq!(i16::from(a), -1000_i16 >> 3);
+
+    // unsigned values still shift in zeros
+    let a: GarbledUint8 = 0x80_u8.into();
+    assert_eq!(u8::from(a >> 7), 1);
+}
+
+#[test]
+fn test_uint_try_bitwise() {
+    let a: GarbledUint8 = 170_u8.into();
+    let b: GarbledUint8 = 204_u8.into();
+
+    let result: u8 = a.try_bitand(&b).unwrap().into();
+    assert_eq!(result, 170_u8 & 204_u8);
+
+    let result: u8 = a.try_bitor(&b).unwrap().into();
+    assert_eq!(result, 170_u8 | 204_u8);
+
+    let result: u8 = a.try_bitxor(&b).unwrap().into();
+    assert_eq!(result, 170_u8 ^ 204_u8);
+
+    let result: u8 = a.try_not().unwrap().into();
+    assert_eq!(result, !170_u8);
+
+    let result: u8 = a.try_xnor(&b).unwrap().into();
+    assert_eq!(result, !(170_u8 ^ 204_u8));
+}
+
+#[test]
+fn test_int_try_bitwise() {
+    let a: GarbledInt8 = (-86_i8).into();
+    let b: GarbledInt8 = 51_i8.into();
+
+    let result: i8 = a.try_bitand(&b).unwrap().into();
+    assert_eq!(result, -86_i8 & 51_i8);
+
+    let result: i8 = a.try_nand(&b).unwrap().into();
+    assert_eq!(result, !(-86_i8 & 51_i8));
+
+    let result: i8 = a.try_nor(&b).unwrap().into();
+    assert_eq!(result, !(-86_i8 | 51_i8));
+}
+
+#[test]
+fn test_malformed_circuit_returns_error() {
+    let a: GarbledUint8 = 170_u8.into();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let input = builder.input(&a);
+
+    // the output references a wire that was never created
+    let mut output = input.clone();
+    output.push(10_000);
+    let circuit = builder.compile(&output);
+
+    let result = builder.execute::<9>(&circuit);
+    assert!(matches!(result, Err(CircuitError::Tandem(_))));
+}
+
+#[test]
+fn test_circuit_error_converts_into_anyhow() {
+    fn run(a: &GarbledUint8) -> anyhow::Result<u8> {
+        let mut builder = WRK17CircuitBuilder::default();
+        let input = builder.input(a);
+        let output = builder.compile_and_execute::<8>(&input)?;
+        Ok(output.into())
+    }
+
+    assert_eq!(run(&GarbledUint8::from(170_u8)).unwrap(), 170);
 }
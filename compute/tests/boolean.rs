@@ -0,0 +1,59 @@
+use compute::prelude::*;
+
+fn truth_table(op: impl Fn(GarbledBool, GarbledBool) -> GarbledBool, expected: [bool; 4]) {
+    for (i, (a, b)) in [(false, false), (false, true), (true, false), (true, true)]
+        .into_iter()
+        .enumerate()
+    {
+        let result: bool = op(a.into(), b.into()).into();
+        assert_eq!(result, expected[i], "inputs ({}, {})", a, b);
+    }
+}
+
+#[test]
+fn test_bool_operators() {
+    truth_table(|a, b| a & b, [false, false, false, true]);
+    truth_table(|a, b| a | b, [false, true, true, true]);
+    truth_table(|a, b| a ^ b, [false, true, true, false]);
+}
+
+#[test]
+fn test_bool_methods() {
+    truth_table(|a, b| a.and(&b), [false, false, false, true]);
+    truth_table(|a, b| a.or(&b), [false, true, true, true]);
+    truth_table(|a, b| a.xor(&b), [false, true, true, false]);
+
+    let t = GarbledBool::from(true);
+    assert!(!bool::from(!&t));
+    assert!(bool::from(!!&t));
+}
+
+#[test]
+fn test_bool_assign_and_compose() {
+    let salary_ok = GarbledBool::from(true);
+    let age_ok = GarbledBool::from(false);
+    let is_manager = GarbledBool::from(true);
+
+    let eligible = &(&salary_ok & &age_ok) | &is_manager;
+    assert!(bool::from(eligible.clone()));
+
+    let mut flag = eligible;
+    flag &= GarbledBool::from(false);
+    assert!(!bool::from(flag.clone()));
+    flag |= &is_manager;
+    flag ^= GarbledBool::from(true);
+    assert!(!bool::from(flag));
+}
+
+#[test]
+fn test_bool_converts_to_garbled_boolean() {
+    // a predicate result can be combined as a GarbledBool and then drive a mux
+    let a = GarbledUint8::from(3_u8);
+    let b = GarbledUint8::from(9_u8);
+    let small: GarbledBool = a.is_one_of(&[1, 2, 3]).into();
+    let condition = small & GarbledBool::from(true);
+    assert_eq!(condition.to_string(), "true");
+
+    let selected = GarbledUint8::mux(condition.bit(), &a, &b);
+    assert_eq!(u8::from(selected), 3);
+}
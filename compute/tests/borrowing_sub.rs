@@ -0,0 +1,51 @@
+use compute::prelude::*;
+
+// subtracts 16-bit values as two 8-bit limbs, least-significant limb first
+fn sub_limbs(a: u16, b: u16) -> (u16, bool) {
+    let a_low: GarbledUint8 = (a as u8).into();
+    let a_high: GarbledUint8 = ((a >> 8) as u8).into();
+    let b_low: GarbledUint8 = (b as u8).into();
+    let b_high: GarbledUint8 = ((b >> 8) as u8).into();
+
+    let (low, borrow) = a_low.borrowing_sub(&b_low, &false.into());
+    let (high, borrow) = a_high.borrowing_sub(&b_high, &borrow);
+
+    let low: u8 = low.into();
+    let high: u8 = high.into();
+    (((high as u16) << 8) | low as u16, borrow.into())
+}
+
+#[test]
+fn test_borrowing_sub_two_limbs() {
+    for (a, b) in [
+        (0x1234_u16, 0x0034_u16),
+        (0x1200, 0x0034),
+        (0x0100, 0x0001),
+        (0xffff, 0xffff),
+        (40000, 1234),
+    ] {
+        assert_eq!(sub_limbs(a, b), (a - b, false), "{} - {}", a, b);
+    }
+}
+
+#[test]
+fn test_borrowing_sub_two_limbs_wraps() {
+    for (a, b) in [(0_u16, 1_u16), (0x0034, 0x1200), (1234, 40000)] {
+        assert_eq!(sub_limbs(a, b), (a.wrapping_sub(b), true), "{} - {}", a, b);
+    }
+}
+
+#[test]
+fn test_borrowing_sub_matches_native() {
+    for (a, b) in [(5_u8, 3_u8), (3, 5), (0, 0), (255, 0), (0, 255), (128, 128)] {
+        for borrow_in in [false, true] {
+            let x: GarbledUint8 = a.into();
+            let y: GarbledUint8 = b.into();
+            let (difference, borrow) = x.borrowing_sub(&y, &borrow_in.into());
+
+            let expected = (a as i16) - (b as i16) - (borrow_in as i16);
+            assert_eq!(u8::from(difference), expected as u8);
+            assert_eq!(bool::from(borrow), expected < 0);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use compute::prelude::*;
+
+#[test]
+fn test_broadcast_true_is_all_ones() {
+    let bit = GarbledBit::one();
+    assert_eq!(u8::from(GarbledUint8::broadcast(&bit)), u8::MAX);
+    assert_eq!(u16::from(GarbledUint16::broadcast(&bit)), u16::MAX);
+    assert_eq!(u32::from(GarbledUint32::broadcast(&bit)), u32::MAX);
+    assert_eq!(u64::from(GarbledUint64::broadcast(&bit)), u64::MAX);
+    assert_eq!(u128::from(GarbledUint128::broadcast(&bit)), u128::MAX);
+}
+
+#[test]
+fn test_broadcast_false_is_all_zeros() {
+    let bit = GarbledBit::zero();
+    assert_eq!(u8::from(GarbledUint8::broadcast(&bit)), 0);
+    assert_eq!(u16::from(GarbledUint16::broadcast(&bit)), 0);
+    assert_eq!(u32::from(GarbledUint32::broadcast(&bit)), 0);
+    assert_eq!(u64::from(GarbledUint64::broadcast(&bit)), 0);
+    assert_eq!(u128::from(GarbledUint128::broadcast(&bit)), 0);
+}
+
+#[test]
+fn test_broadcast_odd_width() {
+    let ones: GarbledUint<3> = GarbledUint::broadcast(&true.into());
+    assert_eq!(*ones.bits(), [true; 3]);
+    let zeros: GarbledUint<3> = GarbledUint::broadcast(&false.into());
+    assert_eq!(*zeros.bits(), [false; 3]);
+}
+
+#[test]
+fn test_broadcast_as_mask() {
+    // a & broadcast(c) keeps a when c is set and clears it otherwise
+    let a: GarbledUint8 = 0b1011_0110_u8.into();
+    let kept = &a & &GarbledUint8::broadcast(&true.into());
+    let cleared = &a & &GarbledUint8::broadcast(&false.into());
+    assert_eq!(u8::from(kept), 0b1011_0110);
+    assert_eq!(u8::from(cleared), 0);
+}
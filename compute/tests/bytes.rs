@@ -0,0 +1,78 @@
+use compute::prelude::*;
+
+#[test]
+fn test_bytes_round_trip() {
+    let key = GarbledBytes::<4>::try_from(&[0xde_u8, 0xad, 0xbe, 0xef][..]).unwrap();
+    assert_eq!(Vec::<u8>::from(key), [0xde, 0xad, 0xbe, 0xef]);
+
+    let key = GarbledBytes::from([1_u8, 2, 3]);
+    let bytes: Vec<u8> = key.into();
+    assert_eq!(bytes, [1, 2, 3]);
+}
+
+#[test]
+fn test_bytes_wrong_length() {
+    let error = GarbledBytes::<4>::try_from(&[1_u8, 2, 3][..]).unwrap_err();
+    assert_eq!(
+        error,
+        LengthError {
+            expected: 4,
+            found: 3
+        }
+    );
+}
+
+#[test]
+fn test_bytes_xor() {
+    let data = GarbledBytes::from([0x00_u8, 0xff, 0x5a, 0x12, 0x34]);
+    let pad = GarbledBytes::from([0xff_u8, 0xff, 0xa5, 0x00, 0x34]);
+    let masked = &data ^ &pad;
+    assert_eq!(
+        Vec::<u8>::from(masked.clone()),
+        [0xff, 0x00, 0xff, 0x12, 0x00]
+    );
+
+    // XORing the pad again restores the data
+    let restored = masked ^ pad;
+    assert_eq!(Vec::<u8>::from(restored), [0x00, 0xff, 0x5a, 0x12, 0x34]);
+}
+
+#[test]
+fn test_bytes_concat_and_slice() {
+    let head = GarbledBytes::from([1_u8, 2]);
+    let tail = GarbledBytes::from([3_u8, 4, 5]);
+    let joined: GarbledBytes<5> = head.concat(&tail);
+    assert_eq!(Vec::<u8>::from(joined.clone()), [1, 2, 3, 4, 5]);
+
+    let middle: GarbledBytes<3> = joined.slice(1);
+    assert_eq!(Vec::<u8>::from(middle), [2, 3, 4]);
+    let empty: GarbledBytes<0> = joined.slice(5);
+    assert_eq!(Vec::<u8>::from(empty), Vec::<u8>::new());
+}
+
+#[test]
+#[should_panic(expected = "do not fit")]
+fn test_bytes_slice_out_of_range() {
+    let bytes = GarbledBytes::from([1_u8, 2, 3]);
+    let _: GarbledBytes<2> = bytes.slice(2);
+}
+
+#[test]
+fn test_bytes_equals() {
+    let digest = GarbledBytes::from([0x12_u8, 0x34, 0x56, 0x78]);
+    assert!(
+        digest
+            .equals(&GarbledBytes::from([0x12, 0x34, 0x56, 0x78]))
+            .bits()[0]
+    );
+    assert!(
+        !digest
+            .equals(&GarbledBytes::from([0x12, 0x34, 0x56, 0x79]))
+            .bits()[0]
+    );
+    assert!(
+        !digest
+            .equals(&GarbledBytes::from([0x92, 0x34, 0x56, 0x78]))
+            .bits()[0]
+    );
+}
@@ -0,0 +1,56 @@
+use compute::prelude::*;
+
+#[test]
+fn test_char_round_trip() {
+    let c = GarbledChar::new('Q');
+    assert_eq!(u8::from(c.byte().clone()), b'Q');
+    assert_eq!(char::from(c.clone()), 'Q');
+    assert_eq!(c.to_string(), "Q");
+}
+
+#[test]
+#[should_panic(expected = "GarbledChar only holds ASCII")]
+fn test_char_rejects_non_ascii() {
+    GarbledChar::new('é');
+}
+
+#[test]
+fn test_char_classification_matches_std() {
+    for byte in 0..=255_u8 {
+        let c = GarbledChar::from(GarbledUint8::from(byte));
+        assert_eq!(
+            bool::from(c.is_digit()),
+            byte.is_ascii_digit(),
+            "{:#x}",
+            byte
+        );
+        assert_eq!(
+            bool::from(c.is_alpha()),
+            byte.is_ascii_alphabetic(),
+            "{:#x}",
+            byte
+        );
+        assert_eq!(
+            u8::from(c.to_lowercase().into_byte()),
+            byte.to_ascii_lowercase(),
+            "{:#x}",
+            byte
+        );
+    }
+}
+
+#[test]
+fn test_char_validates_id_privately() {
+    // An ID is two letters followed by four digits
+    let id: Vec<GarbledChar> = "ab1234".chars().map(GarbledChar::new).collect();
+    let valid = id
+        .iter()
+        .enumerate()
+        .map(|(i, c)| if i < 2 { c.is_alpha() } else { c.is_digit() })
+        .reduce(|a, b| a & b)
+        .unwrap();
+    assert!(bool::from(valid));
+
+    let id: Vec<GarbledChar> = "a11234".chars().map(GarbledChar::new).collect();
+    assert!(!bool::from(id[1].is_alpha()));
+}
@@ -0,0 +1,63 @@
+use compute::prelude::*;
+
+fn clamp_u8(value: u8, lo: u8, hi: u8) -> u8 {
+    let value: GarbledUint8 = value.into();
+    value.secure_clamp(&lo.into(), &hi.into()).into()
+}
+
+fn clamp_i8(value: i8, lo: i8, hi: i8) -> i8 {
+    let value: GarbledInt8 = value.into();
+    value.secure_clamp(&lo.into(), &hi.into()).into()
+}
+
+#[test]
+fn test_uint_clamp() {
+    // below range
+    assert_eq!(clamp_u8(3, 10, 200), 10);
+    // in range
+    assert_eq!(clamp_u8(42, 10, 200), 42);
+    assert_eq!(clamp_u8(10, 10, 200), 10);
+    assert_eq!(clamp_u8(200, 10, 200), 200);
+    // above range
+    assert_eq!(clamp_u8(250, 10, 200), 200);
+
+    for value in [0_u8, 9, 10, 11, 128, 199, 200, 201, 255] {
+        assert_eq!(clamp_u8(value, 10, 200), value.clamp(10, 200));
+    }
+}
+
+#[test]
+fn test_int_clamp_signed() {
+    // below range
+    assert_eq!(clamp_i8(-100, -50, 20), -50);
+    // in range
+    assert_eq!(clamp_i8(-7, -50, 20), -7);
+    // above range
+    assert_eq!(clamp_i8(90, -50, 20), 20);
+    // an all-negative range
+    assert_eq!(clamp_i8(-1, -20, -10), -10);
+    assert_eq!(clamp_i8(i8::MIN, -20, -10), -20);
+
+    for value in [i8::MIN, -51, -50, -1, 0, 19, 20, 21, i8::MAX] {
+        assert_eq!(clamp_i8(value, -50, 20), value.clamp(-50, 20));
+    }
+}
+
+#[test]
+fn test_clamp_inverted_bounds_returns_hi() {
+    assert_eq!(clamp_u8(50, 100, 20), 20);
+    assert_eq!(clamp_i8(0, 10, -10), -10);
+}
+
+#[test]
+fn test_clamp_matches_max_then_min() {
+    for value in [-30_000_i16, -256, -1, 0, 255, 256, 30_000] {
+        let lo: GarbledInt16 = (-256_i16).into();
+        let hi: GarbledInt16 = 255_i16.into();
+        let x: GarbledInt16 = value.into();
+        let clamped = x.secure_clamp(&lo, &hi);
+        assert_eq!(clamped, x.secure_max(&lo).secure_min(&hi));
+        // saturating a fixed-point value to a narrower range
+        assert_eq!(i16::from(clamped), value.clamp(-256, 255));
+    }
+}
@@ -84,3 +84,172 @@ fn test_int_larger_comparison() {
     let b128: GarbledInt128 = 200000000000000000000_i128.into();
     assert!(a128 < b128);
 }
+
+#[test]
+fn test_uint_const_comparison() {
+    let secrets = [0_u8, 1, 99, 100, 101, 254, 255];
+    // 256 does not fit in 8 bits and is greater than every value
+    let constants = [0_u128, 1, 100, 255, 256];
+
+    for &x in &secrets {
+        let a: GarbledUint8 = x.into();
+        for &c in &constants {
+            let x = x as u128;
+            assert_eq!(a.eq_const(c), x == c, "{x} == {c}");
+            assert_eq!(a.ne_const(c), x != c, "{x} != {c}");
+            assert_eq!(a.lt_const(c), x < c, "{x} < {c}");
+            assert_eq!(a.le_const(c), x <= c, "{x} <= {c}");
+            assert_eq!(a.gt_const(c), x > c, "{x} > {c}");
+            assert_eq!(a.ge_const(c), x >= c, "{x} >= {c}");
+        }
+    }
+
+    // a single bit compares directly against its input wire
+    let bit = GarbledUint::<1>::from_bits_le(&[true]);
+    assert!(bit.eq_const(1));
+    assert!(bit.gt_const(0));
+    assert!(!bit.lt_const(1));
+}
+
+#[test]
+fn test_int_const_comparison() {
+    let secrets = [-128_i8, -1, 0, 1, 100, 127];
+    let constants = [-129_i128, -128, -1, 0, 100, 127, 128];
+
+    for &x in &secrets {
+        let a: GarbledInt8 = x.into();
+        for &c in &constants {
+            let x = x as i128;
+            assert_eq!(a.eq_const(c), x == c, "{x} == {c}");
+            assert_eq!(a.ne_const(c), x != c, "{x} != {c}");
+            assert_eq!(a.lt_const(c), x < c, "{x} < {c}");
+            assert_eq!(a.le_const(c), x <= c, "{x} <= {c}");
+            assert_eq!(a.gt_const(c), x > c, "{x} > {c}");
+            assert_eq!(a.ge_const(c), x >= c, "{x} >= {c}");
+        }
+    }
+}
+
+#[test]
+fn test_const_comparison_circuit_is_smaller() {
+    let a: GarbledUint8 = 42_u8.into();
+    let c: GarbledUint8 = 100_u8.into();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let x = builder.input(&a);
+    let y = builder.input(&c);
+    let (lt, eq) = builder.compare(&x, &y);
+    let full = circuit_stats(&builder.compile(&vec![lt, eq].into())).unwrap();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let x = builder.input(&a);
+    let (lt, eq) = builder.compare_const(&x, &c.to_bits_le());
+    let folded = circuit_stats(&builder.compile(&vec![lt, eq].into())).unwrap();
+
+    assert_eq!(folded.inputs, 8);
+    assert!(folded.gates < full.gates);
+    assert!(folded.and_gates < full.and_gates);
+}
+
+#[test]
+fn test_uint_compare_flags() {
+    for (a, b) in [(3_u8, 9_u8), (9, 3), (7, 7), (0, 255), (255, 0), (0, 0)] {
+        let comparison = GarbledUint8::from(a).compare(&GarbledUint8::from(b));
+        assert_eq!(
+            [comparison.lt, comparison.eq, comparison.gt],
+            [a < b, a == b, a > b],
+            "{} vs {}",
+            a,
+            b
+        );
+        assert_eq!(comparison.ordering(), a.cmp(&b));
+    }
+}
+
+#[test]
+fn test_int_compare_flags() {
+    for (a, b) in [
+        (-3_i16, 9_i16),
+        (9, -3),
+        (-7, -7),
+        (i16::MIN, i16::MAX),
+        (0, -1),
+    ] {
+        let comparison = GarbledInt16::from(a).compare(&GarbledInt16::from(b));
+        let flags = [comparison.lt, comparison.eq, comparison.gt];
+        assert_eq!(flags.iter().filter(|&&flag| flag).count(), 1);
+        assert_eq!(flags, [a < b, a == b, a > b], "{} vs {}", a, b);
+        assert_eq!(comparison.ordering(), a.cmp(&b));
+    }
+}
+
+#[test]
+fn test_comparison_reused_for_min_and_max() {
+    let a: GarbledUint8 = 42_u8.into();
+    let b: GarbledUint8 = 17_u8.into();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let x = builder.input(&a);
+    let y = builder.input(&b);
+    let comparison = builder.comparison(&x, &y);
+    let and_gates_before = circuit_stats(&builder.compile(&x)).unwrap().and_gates;
+
+    let mut output = builder.select_min(&comparison, &x, &y);
+    output.push_all(&builder.select_max(&comparison, &x, &y));
+    output.push(comparison.gt);
+
+    // min, max and gt share the comparator; only the two muxes add AND gates
+    let circuit = builder.compile(&output);
+    assert_eq!(
+        circuit_stats(&circuit).unwrap().and_gates - and_gates_before,
+        2 * 8 * builder_mux_and_gates()
+    );
+
+    let result = get_executor()
+        .execute(&circuit, builder.inputs(), &[])
+        .unwrap();
+    let min: u8 = GarbledUint8::new(result[..8].to_vec()).into();
+    let max: u8 = GarbledUint8::new(result[8..16].to_vec()).into();
+    assert_eq!((min, max, result[16]), (17, 42, true));
+}
+
+// AND gates of a single-bit mux
+fn builder_mux_and_gates() -> usize {
+    let bit = GarbledUint::<1>::from_bits_le(&[true]);
+    let mut builder = WRK17CircuitBuilder::default();
+    let s = builder.input(&bit);
+    let a = builder.input(&bit);
+    let b = builder.input(&bit);
+    let output = builder.mux(&s[0], &a, &b);
+    circuit_stats(&builder.compile(&output)).unwrap().and_gates
+}
+
+#[test]
+fn test_uint_is_one_of() {
+    let statuses = [200_u128, 201, 204, 304];
+
+    let a: GarbledUint16 = 204_u16.into();
+    assert!(bool::from(a.is_one_of(&statuses)));
+
+    let b: GarbledUint16 = 404_u16.into();
+    assert!(!bool::from(b.is_one_of(&statuses)));
+
+    // 260 does not fit in 8 bits and must not match its truncation 4
+    let c: GarbledUint8 = 4_u8.into();
+    assert!(!bool::from(c.is_one_of(&[260, 3])));
+    assert!(bool::from(c.is_one_of(&[260, 4])));
+}
+
+#[test]
+fn test_uint_is_one_of_empty_set() {
+    let a: GarbledUint8 = 0_u8.into();
+    assert!(!bool::from(a.is_one_of(&[])));
+}
+
+#[test]
+fn test_int_is_one_of() {
+    let a: GarbledInt8 = (-3_i8).into();
+    assert!(bool::from(a.is_one_of(&[-3, 5])));
+    assert!(!bool::from(a.is_one_of(&[3, -5, 253])));
+    assert!(!bool::from(a.is_one_of(&[])));
+}
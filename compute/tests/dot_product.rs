@@ -0,0 +1,118 @@
+use compute::prelude::*;
+
+fn garble<const N: usize>(values: &[u8]) -> Vec<GarbledUint<N>> {
+    values.iter().map(|&value| value.into()).collect()
+}
+
+fn native(a: &[u8], b: &[u8]) -> u32 {
+    a.iter().zip(b).map(|(&x, &y)| x as u32 * y as u32).sum()
+}
+
+#[test]
+fn test_dot_product_len3() {
+    let cases: [([u8; 3], [u8; 3]); 3] = [
+        ([1, 2, 3], [4, 5, 6]),
+        ([0, 17, 200], [9, 0, 31]),
+        ([255, 255, 255], [255, 255, 255]),
+    ];
+
+    for (a, b) in cases {
+        let result: GarbledUint<18> = GarbledUint8::dot_product(&garble(&a), &garble(&b));
+        assert_eq!(u32::from(result), native(&a, &b), "{a:?} . {b:?}");
+    }
+}
+
+#[test]
+fn test_dot_product_len4() {
+    let cases: [([u8; 4], [u8; 4]); 3] = [
+        ([1, 2, 3, 4], [5, 6, 7, 8]),
+        ([128, 0, 64, 3], [2, 250, 4, 81]),
+        ([255, 255, 255, 255], [255, 255, 255, 255]),
+    ];
+
+    for (a, b) in cases {
+        let result: GarbledUint<18> = GarbledUint8::dot_product(&garble(&a), &garble(&b));
+        assert_eq!(u32::from(result), native(&a, &b), "{a:?} . {b:?}");
+    }
+}
+
+#[test]
+fn test_dot_product_small_width() {
+    let a = garble::<4>(&[15, 7, 3]);
+    let b = garble::<4>(&[15, 2, 9]);
+    let result: GarbledUint<10> = GarbledUint::dot_product(&a, &b);
+    assert_eq!(u16::from(result), 15 * 15 + 7 * 2 + 3 * 9);
+
+    // a single pair is just a widening multiply
+    let result: GarbledUint<8> = GarbledUint::dot_product(&a[..1], &b[..1]);
+    assert_eq!(u8::from(result), 225);
+}
+
+#[test]
+fn test_dot_product_empty() {
+    let result: GarbledUint<16> = GarbledUint8::dot_product(&[], &[]);
+    assert_eq!(u16::from(result), 0);
+}
+
+#[test]
+#[should_panic(expected = "needs at least 18 output bits")]
+fn test_dot_product_too_narrow() {
+    let a = garble::<8>(&[1, 2, 3]);
+    let _: GarbledUint<16> = GarbledUint8::dot_product(&a, &a);
+}
+
+#[test]
+#[should_panic(expected = "equal-length vectors")]
+fn test_dot_product_length_mismatch() {
+    let a = garble::<8>(&[1, 2, 3]);
+    let _: GarbledUint<18> = GarbledUint8::dot_product(&a, &a[..2]);
+}
+
+#[test]
+fn test_dot_product_cheaper_than_composed() {
+    let a: Vec<u16> = vec![1, 60_000, 3, 65_535, 1_234, 0, 777, 42];
+    let b: Vec<u16> = vec![65_535, 2, 50_000, 65_535, 4_321, 9, 0, 42];
+    let expected: u64 = a.iter().zip(&b).map(|(&x, &y)| x as u64 * y as u64).sum();
+
+    // one fused carry-save tree over every partial product
+    let mut builder = WRK17CircuitBuilder::default();
+    let a_wires: Vec<_> = a
+        .iter()
+        .map(|&x| builder.input(&GarbledUint16::from(x)))
+        .collect();
+    let b_wires: Vec<_> = b
+        .iter()
+        .map(|&y| builder.input(&GarbledUint16::from(y)))
+        .collect();
+    let fused = builder.dot_product(&a_wires, &b_wires, 35);
+    let fused_and_gates = circuit_stats(&builder.compile(&fused)).unwrap().and_gates;
+    let result: GarbledUint<35> = builder.compile_and_execute(&fused).unwrap();
+    assert_eq!(u64::from(result), expected);
+
+    // the same sum from a multiplier per element at the output width, then an adder tree
+    let mut builder = WRK17CircuitBuilder::default();
+    let a_wires: Vec<_> = a
+        .iter()
+        .map(|&x| builder.input(&GarbledUint16::from(x).zero_extend::<35>()))
+        .collect();
+    let b_wires: Vec<_> = b
+        .iter()
+        .map(|&y| builder.input(&GarbledUint16::from(y).zero_extend::<35>()))
+        .collect();
+    let products: Vec<_> = a_wires
+        .iter()
+        .zip(&b_wires)
+        .map(|(x, y)| builder.mul(x, y))
+        .collect();
+    let composed = builder.reduce_add(&products);
+    let composed_and_gates = circuit_stats(&builder.compile(&composed))
+        .unwrap()
+        .and_gates;
+    let result: GarbledUint<35> = builder.compile_and_execute(&composed).unwrap();
+    assert_eq!(u64::from(result), expected);
+
+    assert!(
+        3 * fused_and_gates < composed_and_gates,
+        "fused {fused_and_gates} vs composed {composed_and_gates} AND gates"
+    );
+}
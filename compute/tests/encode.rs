@@ -0,0 +1,208 @@
+use compute::prelude::*;
+
+#[derive(GarbledEncode, Debug, Clone, PartialEq)]
+struct Employee {
+    salary: u32,
+    age: u8,
+    manager: bool,
+}
+
+#[derive(GarbledEncode, Debug, PartialEq)]
+struct Reading {
+    offset: i16,
+    // primitives may be spelled as paths
+    sample: core::primitive::u64,
+}
+
+#[test]
+fn test_encode_roundtrip() {
+    let employee = Employee {
+        salary: 72_000,
+        age: 35,
+        manager: true,
+    };
+    assert_eq!(Employee::WIDTH, 41);
+
+    let bits = employee.encode();
+    assert_eq!(bits.len(), Employee::WIDTH);
+    // the salary comes first, least-significant bit first
+    assert_eq!(bits[..32], GarbledUint32::from(72_000_u32).to_bits_le()[..]);
+    assert!(bits[40]);
+    assert_eq!(Employee::decode(&bits), employee);
+}
+
+#[test]
+fn test_encode_signed_fields() {
+    let reading = Reading {
+        offset: -1234,
+        sample: u64::MAX - 7,
+    };
+    assert_eq!(Reading::decode(&reading.encode()), reading);
+}
+
+#[test]
+fn test_encoded_struct_in_circuit() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input_encoded(&Employee {
+        salary: 50_000,
+        age: 29,
+        manager: false,
+    });
+    let b = builder.input_encoded(&Employee {
+        salary: 65_000,
+        age: 44,
+        manager: true,
+    });
+
+    // field-wise: the larger salary, the smaller age and either manager flag
+    let b_earns_more = builder.lt(&a.salary, &b.salary);
+    let a_is_younger = builder.lt(&a.age, &b.age);
+    let merged = EmployeeWires {
+        salary: builder.mux(&b_earns_more, &b.salary, &a.salary),
+        age: builder.mux(&a_is_younger, &a.age, &b.age),
+        manager: builder.or(&a.manager, &b.manager),
+    };
+
+    let result: Employee = builder.compile_and_decode(&merged).unwrap();
+    assert_eq!(
+        result,
+        Employee {
+            salary: 65_000,
+            age: 29,
+            manager: true,
+        }
+    );
+}
+
+#[test]
+fn test_encoded_wires_roundtrip() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let reading = Reading {
+        offset: -5,
+        sample: 99,
+    };
+    let wires = builder.input_encoded(&reading);
+    let indices = Reading::indices(&wires);
+    assert_eq!(indices.len(), Reading::WIDTH);
+    assert_eq!(wires.offset.len(), 16);
+
+    let result: Reading = builder.compile_and_decode(&wires).unwrap();
+    assert_eq!(result, reading);
+}
+
+#[test]
+#[should_panic(expected = "Employee needs exactly 41 bits, got 40")]
+fn test_decode_rejects_wrong_width() {
+    Employee::decode(&[false; 40]);
+}
+
+#[test]
+fn test_tuple_roundtrip() {
+    let value = (
+        GarbledUint8::from(0xA5_u8),
+        GarbledInt16::from(-300_i16),
+        GarbledBit::from(true),
+    );
+    type Triple = (GarbledUint8, GarbledInt16, GarbledBit);
+    assert_eq!(Triple::WIDTH, 25);
+
+    let (a, b, c) = Triple::decode(&value.encode());
+    assert_eq!(u8::from(a), 0xA5);
+    assert_eq!(i16::from(b), -300);
+    assert!(bool::from(c));
+}
+
+#[test]
+fn test_circuit_returns_tuple() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint16::from(1000_u16));
+    let b = builder.input(&GarbledUint16::from(7_u16));
+
+    let quotient = builder.div(&a, &b);
+    let remainder = builder.rem(&a, &b);
+    let a_lt_b = builder.lt(&a, &b);
+
+    let (quotient, remainder, a_lt_b): (GarbledUint16, GarbledUint16, GarbledBit) = builder
+        .compile_and_decode(&(quotient, remainder, a_lt_b.into()))
+        .unwrap();
+    assert_eq!(u16::from(quotient), 142);
+    assert_eq!(u16::from(remainder), 6);
+    assert!(!bool::from(a_lt_b));
+}
+
+#[test]
+fn test_tuple_inputs_and_nesting() {
+    // a derived struct and a garbled integer in one tuple share a single input call
+    let mut builder = WRK17CircuitBuilder::default();
+    let (reading, threshold) = builder.input_encoded(&(
+        Reading {
+            offset: -20,
+            sample: 500,
+        },
+        GarbledInt16::from(-10_i16),
+    ));
+    let below = builder.signed_lt(&reading.offset, &threshold);
+
+    let (below, reading): (GarbledBit, Reading) = builder
+        .compile_and_decode(&(below.into(), reading))
+        .unwrap();
+    assert!(bool::from(below));
+    assert_eq!(
+        reading,
+        Reading {
+            offset: -20,
+            sample: 500,
+        }
+    );
+}
+
+#[test]
+fn test_encrypted_struct_params() {
+    #[encrypted(execute)]
+    fn raise(employee: Employee, bonus: u32) -> u32 {
+        employee.salary + bonus
+    }
+
+    // without a primitive parameter the circuit takes the width of the return type
+    #[encrypted(execute)]
+    fn payroll(first: Employee, second: Employee) -> u32 {
+        if first.salary > second.salary {
+            first.salary - second.salary
+        } else {
+            second.salary - first.salary
+        }
+    }
+
+    let alice = Employee {
+        salary: 72_000,
+        age: 35,
+        manager: true,
+    };
+    let bob = Employee {
+        salary: 80_500,
+        age: 41,
+        manager: false,
+    };
+    assert_eq!(raise(alice.clone(), 1_500_u32), 73_500);
+    assert_eq!(payroll(alice.clone(), bob.clone()), 8_500);
+    assert_eq!(payroll(bob, alice), 8_500);
+}
+
+#[test]
+fn test_short_signed_values_encode_all_bits() {
+    // converted from `zero()`, the value stores a single bit but still takes 16 wires
+    let x = GarbledInt16::from(GarbledUint16::zero());
+    assert_eq!(x.to_bits_le(), vec![false; 16]);
+    assert_eq!(x.to_bits_be(), vec![false; 16]);
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let (x, y) = builder.input_encoded(&(x, GarbledUint8::from(3_u8)));
+    assert_eq!((x.len(), y.len()), (16, 8));
+}
+
+#[test]
+#[should_panic(expected = "expected 24 wires, got 8")]
+fn test_tuple_wires_reject_wrong_width() {
+    let indices: GateIndexVec = vec![0, 1, 2, 3, 4, 5, 6, 7].into();
+    <(GarbledUint8, GarbledUint16)>::wires(&indices);
+}
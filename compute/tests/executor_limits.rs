@@ -0,0 +1,71 @@
+use compute::prelude::*;
+
+// an 8-bit multiplication, with its gate counts
+fn multiplication() -> (Circuit, Vec<bool>, CircuitStats) {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(12_u8));
+    let b = builder.input(&GarbledUint8::from(11_u8));
+    let output = builder.mul(&a, &b);
+    let circuit = builder.compile(&output);
+    let stats = circuit_stats(&circuit).unwrap();
+    (circuit, builder.inputs().to_vec(), stats)
+}
+
+#[test]
+fn test_and_gate_limit() {
+    let (circuit, inputs, stats) = multiplication();
+
+    let at_limit = LocalSimulator::with_config(ExecutorConfig {
+        max_and_gates: Some(stats.and_gates),
+        ..Default::default()
+    });
+    let result = at_limit.execute(&circuit, &inputs, &[]).unwrap();
+    assert_eq!(u8::from(GarbledUint8::new(result)), 132);
+
+    let over_limit = LocalSimulator::with_config(ExecutorConfig {
+        max_and_gates: Some(stats.and_gates - 1),
+        ..Default::default()
+    });
+    let error = over_limit.execute(&circuit, &inputs, &[]).unwrap_err();
+    assert!(error.to_string().contains("AND gates"), "{}", error);
+}
+
+#[test]
+fn test_total_gate_limit() {
+    let (circuit, inputs, stats) = multiplication();
+
+    let at_limit = LocalSimulator::with_config(ExecutorConfig {
+        max_total_gates: Some(stats.gates),
+        ..Default::default()
+    });
+    assert!(at_limit.execute(&circuit, &inputs, &[]).is_ok());
+
+    let over_limit = LocalSimulator::with_config(ExecutorConfig {
+        max_total_gates: Some(stats.gates - 1),
+        ..Default::default()
+    });
+    assert!(over_limit.execute(&circuit, &inputs, &[]).is_err());
+}
+
+#[test]
+fn test_limits_apply_to_prepared_circuits() {
+    let (circuit, inputs, stats) = multiplication();
+    let executor: std::sync::Arc<dyn Executor + Send + Sync> =
+        std::sync::Arc::new(LocalSimulator::with_config(ExecutorConfig {
+            max_and_gates: Some(stats.and_gates - 1),
+            max_total_gates: None,
+        }));
+
+    let prepared = executor.prepared(&circuit).unwrap();
+    assert!(prepared.execute_inputs(&inputs, &[]).is_err());
+}
+
+#[test]
+fn test_default_executor_is_unlimited() {
+    let (circuit, inputs, _) = multiplication();
+    assert_eq!(ExecutorConfig::default().check(&circuit).ok(), Some(()));
+    assert!(get_executor().execute(&circuit, &inputs, &[]).is_ok());
+    assert!(LocalSimulator::new()
+        .execute(&circuit, &inputs, &[])
+        .is_ok());
+}
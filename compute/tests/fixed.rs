@@ -0,0 +1,127 @@
+use compute::prelude::*;
+
+const EPSILON_16: f64 = 1.0 / 65536.0;
+
+#[test]
+fn test_fixed_f64_round_trip() {
+    for value in [0.0, 1.5, -2.25, 0.125, -0.0625, 1234.75, -32768.0] {
+        let fixed = GarbledFixed32::from_f64(value);
+        assert_eq!(fixed.to_f64(), value);
+    }
+}
+
+#[test]
+fn test_fixed_from_f64_rounds_to_nearest() {
+    // 0.1 is not representable; the result is the closest multiple of 2^-16
+    let fixed = GarbledFixed32::from_f64(0.1);
+    assert!((fixed.to_f64() - 0.1).abs() <= EPSILON_16 / 2.0);
+
+    let coarse: GarbledFixed<8, 2> = 0.3.into();
+    assert_eq!(f64::from(coarse), 0.25);
+    let coarse: GarbledFixed<8, 2> = (-0.4).into();
+    assert_eq!(f64::from(coarse), -0.5);
+
+    // adding 0.5 to the largest double below 0.5 rounds up to 1.0 before truncating
+    let below_half = 0.49999999999999994;
+    assert_eq!(GarbledFixed::<8, 0>::from_f64(below_half).to_f64(), 0.0);
+    assert_eq!(GarbledFixed::<8, 0>::from_f64(-below_half).to_f64(), 0.0);
+    assert_eq!(GarbledFixed::<8, 0>::from_f64(2.5).to_f64(), 3.0);
+    assert_eq!(GarbledFixed::<8, 0>::from_f64(-2.5).to_f64(), -3.0);
+}
+
+#[test]
+fn test_fixed_raw_representation() {
+    let fixed = GarbledFixed32::from_f64(-1.5);
+    assert_eq!(i32::from(fixed.raw().clone()), -3 << 15);
+
+    let from_raw = GarbledFixed32::from_raw((3_i32 << 14).into());
+    assert_eq!(from_raw.to_f64(), 0.75);
+}
+
+#[test]
+fn test_fixed_from_short_raw() {
+    // `zero()` and `one()` store a single bit; the raw value must still act as all 16 bits
+    let two = GarbledFixed16::from_f64(2.0);
+    let zero = GarbledFixed16::from_raw(GarbledUint16::zero().into());
+    assert_eq!((&zero * &two).to_f64(), 0.0);
+    let smallest = GarbledFixed16::from_raw(GarbledUint16::one().into());
+    assert_eq!((&smallest * &two).to_f64(), 2.0 / 256.0);
+    assert_eq!((&smallest + &two).to_f64(), 2.0 + 1.0 / 256.0);
+}
+
+#[test]
+fn test_fixed_from_integers() {
+    let unsigned: GarbledUint32 = 42_u32.into();
+    assert_eq!(GarbledFixed32::from(unsigned).to_f64(), 42.0);
+
+    let signed: GarbledInt32 = (-7_i32).into();
+    assert_eq!(GarbledFixed32::from(signed).to_f64(), -7.0);
+
+    // only N - F integer bits fit, so 40000 wraps past the sign bit of a Q16.16 value
+    let large: GarbledUint32 = 40000_u32.into();
+    assert_eq!(GarbledFixed32::from(large).to_f64(), 40000.0 - 65536.0);
+}
+
+#[test]
+fn test_fixed_add_sub() {
+    let a = GarbledFixed32::from_f64(1.5);
+    let b = GarbledFixed32::from_f64(-2.25);
+    assert_eq!((&a + &b).to_f64(), -0.75);
+    assert_eq!((&a - &b).to_f64(), 3.75);
+
+    let mut c = a.clone();
+    c += &b;
+    c -= GarbledFixed32::from_f64(0.25);
+    assert_eq!(c.to_f64(), -1.0);
+}
+
+#[test]
+fn test_fixed_mul() {
+    let a = GarbledFixed32::from_f64(1.5);
+    let b = GarbledFixed32::from_f64(-2.25);
+    assert_eq!((&a * &b).to_f64(), -3.375);
+    assert_eq!((&b * &b).to_f64(), 5.0625);
+
+    let fraction = GarbledFixed32::from_f64(0.5);
+    assert_eq!((&fraction * &fraction).to_f64(), 0.25);
+
+    let mut c = GarbledFixed16::from_f64(3.0);
+    c *= GarbledFixed16::from_f64(-0.5);
+    assert_eq!(c.to_f64(), -1.5);
+}
+
+#[test]
+fn test_fixed_mul_truncates_toward_negative_infinity() {
+    // 2^-16 * 0.5 is below the resolution: 0 for positive, -2^-16 for negative
+    let smallest = GarbledFixed32::from_f64(EPSILON_16);
+    let half = GarbledFixed32::from_f64(0.5);
+    assert_eq!((&smallest * &half).to_f64(), 0.0);
+    let negative_smallest = GarbledFixed32::from_f64(-EPSILON_16);
+    assert_eq!((&negative_smallest * &half).to_f64(), -EPSILON_16);
+}
+
+#[test]
+fn test_fixed_div() {
+    let a = GarbledFixed32::from_f64(7.5);
+    let b = GarbledFixed32::from_f64(2.5);
+    assert_eq!((&a / &b).to_f64(), 3.0);
+
+    let c = GarbledFixed32::from_f64(-1.0);
+    let d = GarbledFixed32::from_f64(4.0);
+    assert_eq!((&c / &d).to_f64(), -0.25);
+    assert_eq!((&d / &c).to_f64(), -4.0);
+    assert_eq!((&c / &c).to_f64(), 1.0);
+
+    // 1 / 3 truncates toward zero in both signs
+    let third = GarbledFixed32::from_f64(1.0) / GarbledFixed32::from_f64(3.0);
+    assert!((third.to_f64() - 1.0 / 3.0).abs() < EPSILON_16);
+    assert!(third.to_f64() <= 1.0 / 3.0);
+    let negative_third = GarbledFixed32::from_f64(-1.0) / GarbledFixed32::from_f64(3.0);
+    assert_eq!(negative_third.to_f64(), -third.to_f64());
+}
+
+#[test]
+fn test_fixed_display() {
+    let fixed = GarbledFixed16::from_f64(-2.5);
+    assert_eq!(fixed.to_string(), "-2.5");
+}
@@ -0,0 +1,202 @@
+use compute::prelude::*;
+
+// Normal, subnormal, signed zero, boundary and special values
+const F32_CASES: [f32; 18] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    0.1,
+    -2.75,
+    3.5,
+    1.0e30,
+    -1.0e-30,
+    f32::MAX,
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    1.0e-45,
+    -3.0e-40,
+    f32::EPSILON,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+const F64_CASES: [f64; 10] = [
+    0.0,
+    -0.0,
+    1.5,
+    -0.1,
+    1.0e300,
+    f64::MAX,
+    f64::MIN_POSITIVE,
+    5.0e-324,
+    f64::INFINITY,
+    f64::NAN,
+];
+
+// Same bits, or both NaN (NaN payloads are not specified)
+fn assert_same_f32(actual: f32, expected: f32, context: &str) {
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{}: got {:e} ({:#010x}), expected {:e} ({:#010x})",
+        context,
+        actual,
+        actual.to_bits(),
+        expected,
+        expected.to_bits()
+    );
+}
+
+fn assert_same_f64(actual: f64, expected: f64, context: &str) {
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{}: got {:e}, expected {:e}",
+        context,
+        actual,
+        expected
+    );
+}
+
+// xorshift, so the random cases are the same on every run
+fn random_f32s(count: usize) -> Vec<f32> {
+    let mut state = 0x2545_f491_u32;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            f32::from_bits(state)
+        })
+        .collect()
+}
+
+#[test]
+fn test_float_conversion_round_trip() {
+    for value in F32_CASES {
+        let garbled: GarbledFloat32 = value.into();
+        assert_same_f32(garbled.into(), value, "f32 round trip");
+    }
+    for value in F64_CASES {
+        let garbled: GarbledFloat64 = value.into();
+        assert_same_f64(garbled.into(), value, "f64 round trip");
+    }
+}
+
+#[test]
+fn test_float_from_short_raw() {
+    // `zero()` stores a single bit, which must still read as +0.0 in the 32-bit circuits
+    let zero = GarbledFloat32::from_raw(GarbledUint::<32>::zero());
+    let sum: f32 = (zero + GarbledFloat32::from(1.5)).into();
+    assert_eq!(sum, 1.5);
+
+    let zero = GarbledFloat64::from_raw(GarbledUint::<64>::zero());
+    let product: f64 = (zero * GarbledFloat64::from(-2.0)).into();
+    assert_same_f64(product, -0.0, "0 * -2");
+}
+
+#[test]
+fn test_float32_add_matches_native() {
+    for a in F32_CASES {
+        for b in F32_CASES {
+            let sum: f32 = (GarbledFloat32::from(a) + GarbledFloat32::from(b)).into();
+            assert_same_f32(sum, a + b, &format!("{:e} + {:e}", a, b));
+        }
+    }
+}
+
+#[test]
+fn test_float32_sub_matches_native() {
+    for a in F32_CASES {
+        for b in F32_CASES {
+            let difference: f32 = (GarbledFloat32::from(a) - GarbledFloat32::from(b)).into();
+            assert_same_f32(difference, a - b, &format!("{:e} - {:e}", a, b));
+        }
+    }
+}
+
+#[test]
+fn test_float32_mul_matches_native() {
+    for a in F32_CASES {
+        for b in F32_CASES {
+            let product: f32 = (GarbledFloat32::from(a) * GarbledFloat32::from(b)).into();
+            assert_same_f32(product, a * b, &format!("{:e} * {:e}", a, b));
+        }
+    }
+}
+
+#[test]
+fn test_float32_random_bit_patterns() {
+    let values = random_f32s(256);
+    for pair in values.chunks(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let sum: f32 = (GarbledFloat32::from(a) + GarbledFloat32::from(b)).into();
+        assert_same_f32(sum, a + b, &format!("{:e} + {:e}", a, b));
+        let product: f32 = (GarbledFloat32::from(a) * GarbledFloat32::from(b)).into();
+        assert_same_f32(product, a * b, &format!("{:e} * {:e}", a, b));
+    }
+}
+
+#[test]
+fn test_float32_rounding_and_cancellation() {
+    // ties to even, carries out of the significand, catastrophic cancellation and underflow
+    let cases = [
+        (1.0, f32::EPSILON / 2.0),
+        (1.0 + f32::EPSILON, f32::EPSILON / 2.0),
+        (16_777_215.0, 1.0),
+        (1.000_000_1, -1.0),
+        (f32::MIN_POSITIVE, -1.0e-45),
+        (f32::MAX, f32::MAX),
+    ];
+    for (a, b) in cases {
+        let sum: f32 = (GarbledFloat32::from(a) + GarbledFloat32::from(b)).into();
+        assert_same_f32(sum, a + b, &format!("{:e} + {:e}", a, b));
+    }
+
+    let products = [
+        (1.0e-20, 1.0e-20),
+        (f32::MIN_POSITIVE, 0.5),
+        (1.0e-45, 3.0),
+        (3.0e-40, 1.0e10),
+        (1.0e20, 1.0e20),
+        (1.000_000_1, 1.000_000_1),
+    ];
+    for (a, b) in products {
+        let product: f32 = (GarbledFloat32::from(a) * GarbledFloat32::from(b)).into();
+        assert_same_f32(product, a * b, &format!("{:e} * {:e}", a, b));
+    }
+}
+
+#[test]
+fn test_float64_arithmetic_matches_native() {
+    for a in F64_CASES {
+        for b in F64_CASES {
+            let sum: f64 = (GarbledFloat64::from(a) + GarbledFloat64::from(b)).into();
+            assert_same_f64(sum, a + b, &format!("{:e} + {:e}", a, b));
+            let product: f64 = (GarbledFloat64::from(a) * GarbledFloat64::from(b)).into();
+            assert_same_f64(product, a * b, &format!("{:e} * {:e}", a, b));
+        }
+    }
+}
+
+#[test]
+fn test_float_comparison_matches_native() {
+    for a in F32_CASES {
+        for b in F32_CASES {
+            let garbled_a = GarbledFloat32::from(a);
+            let garbled_b = GarbledFloat32::from(b);
+            assert_eq!(
+                garbled_a.partial_cmp(&garbled_b),
+                a.partial_cmp(&b),
+                "{:e} cmp {:e}",
+                a,
+                b
+            );
+            assert_eq!(garbled_a == garbled_b, a == b, "{:e} == {:e}", a, b);
+        }
+    }
+
+    assert!(GarbledFloat64::from(-1.0e300) < GarbledFloat64::from(5.0e-324));
+    assert!(GarbledFloat64::from(0.0) == GarbledFloat64::from(-0.0));
+    assert!(GarbledFloat64::from(f64::NAN) != GarbledFloat64::from(f64::NAN));
+}
@@ -0,0 +1,63 @@
+use compute::prelude::*;
+
+fn gcd(mut a: u8, mut b: u8) -> u8 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+fn check(a: u8, b: u8) {
+    let ga: GarbledUint8 = a.into();
+    let gb: GarbledUint8 = b.into();
+    let result: u8 = ga.gcd(&gb).into();
+    assert_eq!(result, gcd(a, b), "gcd({}, {})", a, b);
+}
+
+#[test]
+fn test_gcd_coprime() {
+    check(17, 5);
+    check(35, 64);
+    check(255, 254);
+}
+
+#[test]
+fn test_gcd_divides() {
+    check(12, 36);
+    check(128, 8);
+    check(7, 21);
+}
+
+#[test]
+fn test_gcd_common_factors() {
+    check(48, 180);
+    check(96, 160);
+}
+
+#[test]
+fn test_gcd_equal() {
+    check(42, 42);
+    check(1, 1);
+}
+
+#[test]
+fn test_gcd_zero() {
+    check(0, 9);
+    check(9, 0);
+    check(0, 0);
+}
+
+#[test]
+fn test_gcd_u16() {
+    let a: GarbledUint16 = 46368_u16.into();
+    let b: GarbledUint16 = 28657_u16.into();
+    let result: u16 = a.gcd(&b).into();
+    assert_eq!(result, 1);
+
+    let a: GarbledUint16 = 1071_u16.into();
+    let b: GarbledUint16 = 462_u16.into();
+    let result: u16 = a.gcd(&b).into();
+    assert_eq!(result, 21);
+}
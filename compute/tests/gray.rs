@@ -0,0 +1,59 @@
+use compute::prelude::*;
+
+fn u4(value: u8) -> GarbledUint4 {
+    GarbledUint::new((0..4).map(|i| (value >> i) & 1 == 1).collect())
+}
+
+fn value_of(x: &GarbledUint4) -> u8 {
+    x.bits()
+        .iter()
+        .enumerate()
+        .map(|(i, &bit)| (bit as u8) << i)
+        .sum()
+}
+
+#[test]
+fn test_gray_round_trip_u4() {
+    for value in 0..16_u8 {
+        let x = u4(value);
+        let gray = x.to_gray();
+        assert_eq!(value_of(&gray), value ^ (value >> 1), "to_gray({})", value);
+        assert_eq!(gray.from_gray(), x, "from_gray(to_gray({}))", value);
+    }
+}
+
+#[test]
+fn test_gray_adjacent_values_differ_in_one_bit() {
+    for value in 0..15_u8 {
+        let a = value_of(&u4(value).to_gray());
+        let b = value_of(&u4(value + 1).to_gray());
+        assert_eq!((a ^ b).count_ones(), 1);
+    }
+}
+
+#[test]
+fn test_gray_wide() {
+    for value in [0_u32, 1, 0xdead_beef, u32::MAX, 0x8000_0000] {
+        let x: GarbledUint32 = value.into();
+        let gray: u32 = x.to_gray().into();
+        assert_eq!(gray, value ^ (value >> 1));
+
+        let decoded: u32 = GarbledUint32::from(gray).from_gray().into();
+        assert_eq!(decoded, value);
+    }
+
+    let x: GarbledUint64 = 0x0123_4567_89ab_cdef_u64.into();
+    assert_eq!(x.to_gray().from_gray(), x);
+}
+
+#[test]
+fn test_gray_uses_no_and_gates() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint16::from(0_u16));
+    let gray = builder.to_gray(&a);
+    let output = builder.from_gray(&gray);
+    assert_eq!(
+        circuit_stats(&builder.compile(&output)).unwrap().and_gates,
+        0
+    );
+}
@@ -0,0 +1,64 @@
+use compute::prelude::*;
+
+fn within(a: u16, b: u16, k: usize) -> bool {
+    let ga: GarbledUint16 = a.into();
+    let gb: GarbledUint16 = b.into();
+    ga.within_hamming(&gb, k).into()
+}
+
+#[test]
+fn test_within_hamming_below() {
+    // distance 2
+    assert!(within(0b1010, 0b1001, 3));
+    assert!(within(0xffff, 0xffff, 0));
+    assert!(within(0, 0b111, 5));
+}
+
+#[test]
+fn test_within_hamming_equal() {
+    assert!(within(0b1010, 0b1001, 2));
+    assert!(within(0, 0xffff, 16));
+    assert!(within(0x8001, 0x0000, 2));
+}
+
+#[test]
+fn test_within_hamming_above() {
+    assert!(!within(0b1010, 0b1001, 1));
+    assert!(!within(0x1234, 0x1235, 0));
+    assert!(!within(0, 0xffff, 15));
+}
+
+#[test]
+fn test_within_hamming_matches_count_ones() {
+    for (a, b) in [
+        (0_u8, 255_u8),
+        (0xa5, 0x5a),
+        (17, 17),
+        (1, 128),
+        (0x0f, 0x1f),
+    ] {
+        let distance = (a ^ b).count_ones() as usize;
+        for k in 0..=9 {
+            let ga: GarbledUint8 = a.into();
+            let gb: GarbledUint8 = b.into();
+            let result: bool = ga.within_hamming(&gb, k).into();
+            assert_eq!(result, distance <= k, "{} vs {} within {}", a, b, k);
+        }
+    }
+}
+
+#[test]
+fn test_count_ones() {
+    for x in [0_u16, 1, 0b1010, 0x8001, 0x7fff, 0xffff, 0x1234] {
+        let value: GarbledUint16 = x.into();
+        assert_eq!(u32::from(value.count_ones()), x.count_ones(), "{x:#x}");
+    }
+
+    let value: GarbledUint128 = u128::MAX.into();
+    assert_eq!(u32::from(value.count_ones()), 128);
+
+    // the Hamming distance is the weight of the XOR
+    let a: GarbledUint<12> = 0b1100_1010_0110_u16.into();
+    let b: GarbledUint<12> = 0b0101_1010_0011_u16.into();
+    assert_eq!(u32::from((a ^ b).count_ones()), 4);
+}
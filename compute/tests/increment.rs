@@ -0,0 +1,68 @@
+use compute::prelude::*;
+
+#[test]
+fn test_uint_increment() {
+    for value in [0_u8, 1, 7, 127, 200, 254] {
+        let a: GarbledUint8 = value.into();
+        let result: u8 = a.increment().into();
+        assert_eq!(result, value + 1);
+    }
+}
+
+#[test]
+fn test_uint_decrement() {
+    for value in [1_u8, 2, 8, 128, 200, 255] {
+        let a: GarbledUint8 = value.into();
+        let result: u8 = a.decrement().into();
+        assert_eq!(result, value - 1);
+    }
+}
+
+#[test]
+fn test_uint_increment_wraps() {
+    let a: GarbledUint8 = 255_u8.into();
+    let result: u8 = a.increment().into();
+    assert_eq!(result, 0);
+
+    let a: GarbledUint16 = u16::MAX.into();
+    let result: u16 = a.increment().into();
+    assert_eq!(result, 0);
+}
+
+#[test]
+fn test_uint_decrement_wraps() {
+    let a: GarbledUint8 = 0_u8.into();
+    let result: u8 = a.decrement().into();
+    assert_eq!(result, 255);
+
+    let a: GarbledUint16 = 0_u16.into();
+    let result: u16 = a.decrement().into();
+    assert_eq!(result, u16::MAX);
+}
+
+#[test]
+fn test_increment_decrement_round_trip() {
+    let a: GarbledUint32 = 0x1234_ffff_u32.into();
+    assert_eq!(a.increment().decrement(), a);
+    let result: u32 = a.increment().into();
+    assert_eq!(result, 0x1235_0000);
+}
+
+#[test]
+fn test_int_increment_decrement() {
+    let a: GarbledInt8 = (-1_i8).into();
+    let result: i8 = a.increment().into();
+    assert_eq!(result, 0);
+
+    let a: GarbledInt8 = 0_i8.into();
+    let result: i8 = a.decrement().into();
+    assert_eq!(result, -1);
+
+    let a: GarbledInt8 = i8::MAX.into();
+    let result: i8 = a.increment().into();
+    assert_eq!(result, i8::MIN);
+
+    let a: GarbledInt8 = i8::MIN.into();
+    let result: i8 = a.decrement().into();
+    assert_eq!(result, i8::MAX);
+}
@@ -142,3 +142,360 @@ fn test_from_uint_to_int_i128() {
     let result: i128 = int.into();
     assert_eq!(result, 12297829382473034410_u128 as i128);
 }
+
+#[test]
+fn test_round_trip_i8() {
+    for value in [i8::MIN, -42, -1, 0, 1, 42, i8::MAX] {
+        let a: GarbledInt8 = value.into();
+        let result: i8 = a.into();
+        assert_eq!(result, value);
+    }
+}
+
+#[test]
+fn test_round_trip_i16() {
+    for value in [i16::MIN, -12345, -1, 0, 1, 12345, i16::MAX] {
+        let a: GarbledInt16 = value.into();
+        let result: i16 = a.into();
+        assert_eq!(result, value);
+    }
+}
+
+#[test]
+fn test_round_trip_i32() {
+    for value in [i32::MIN, -1234567890, -1, 0, 1, 1234567890, i32::MAX] {
+        let a: GarbledInt32 = value.into();
+        let result: i32 = a.into();
+        assert_eq!(result, value);
+    }
+}
+
+#[test]
+fn test_round_trip_i64() {
+    for value in [
+        i64::MIN,
+        -123456789012345,
+        -1,
+        0,
+        1,
+        123456789012345,
+        i64::MAX,
+    ] {
+        let a: GarbledInt64 = value.into();
+        let result: i64 = a.into();
+        assert_eq!(result, value);
+    }
+}
+
+#[test]
+fn test_round_trip_i128() {
+    for value in [i128::MIN, -1234567890123456789012345, -1, 0, 1, i128::MAX] {
+        let a: GarbledInt128 = value.into();
+        let result: i128 = a.into();
+        assert_eq!(result, value);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Int<N> can only support up to 8 bits for i8")]
+fn test_from_i8_too_wide() {
+    let _: GarbledInt16 = (-1_i8).into();
+}
+
+#[test]
+fn test_display_negative() {
+    let a: GarbledInt8 = (-42_i8).into();
+    assert_eq!(format!("{}", a), "-42");
+
+    let b: GarbledInt8 = i8::MIN.into();
+    assert_eq!(format!("{}", b), "-128");
+
+    let c: GarbledInt<4> = (-3_i8).into();
+    assert_eq!(format!("{}", c), "-3");
+
+    let d: GarbledInt<4> = 7_i8.into();
+    assert_eq!(format!("{}", d), "7");
+}
+
+#[test]
+fn test_display_wide() {
+    let mut bits = vec![false; 256];
+    bits[130] = true;
+    let a: GarbledInt256 = GarbledInt::new(bits);
+    assert_eq!(format!("{}", a), "1361129467683753853853498429727072845824");
+
+    let b: GarbledInt256 = GarbledInt::new(vec![true; 256]);
+    assert_eq!(format!("{}", b), "-1");
+
+    let c: GarbledInt256 = GarbledInt::new(vec![false; 256]);
+    assert_eq!(format!("{}", c), "0");
+}
+
+#[test]
+fn test_radix_format() {
+    let a: GarbledInt8 = (-1_i8).into();
+    assert_eq!(format!("{:b}", a), "11111111");
+    assert_eq!(format!("{:x}", a), "ff");
+
+    let b: GarbledInt16 = (-42_i16).into();
+    assert_eq!(format!("{:X}", b), format!("{:X}", -42_i16));
+    assert_eq!(format!("{:#06x}", b), format!("{:#06x}", -42_i16));
+}
+
+#[test]
+fn test_index() {
+    let a: GarbledInt8 = (-2_i8).into(); // 11111110
+    assert!(!a[0]);
+    assert!(a[1]);
+    assert!(a[7]);
+}
+
+#[test]
+#[should_panic(expected = "bit index 16 out of range for GarbledInt<16>")]
+fn test_index_out_of_range() {
+    let a: GarbledInt16 = 1_i16.into();
+    let _ = a[16];
+}
+
+#[test]
+fn test_partial_eq_and_hash() {
+    use std::collections::HashSet;
+
+    let a: GarbledInt8 = (-42_i8).into();
+    let b: GarbledInt8 = (-42_i8).into();
+    let c: GarbledInt8 = 42_i8.into();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let set: HashSet<GarbledInt8> = [a, b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[cfg(all(feature = "rand", not(target_arch = "wasm32")))]
+#[test]
+fn test_random_seeded() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let first: i32 = GarbledInt32::random(&mut rng).into();
+    let second: i32 = GarbledInt32::random(&mut rng).into();
+    assert_ne!(first, second);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    assert_eq!(i32::from(GarbledInt32::random(&mut rng)), first);
+    assert_eq!(i32::from(GarbledInt32::random(&mut rng)), second);
+}
+
+#[test]
+fn test_from_bits_endianness() {
+    // sign bit first: 0b1000_0000 is -128
+    let sign_first = [true, false, false, false, false, false, false, false];
+    let a = GarbledInt8::from_bits_be(&sign_first);
+    assert_eq!(i8::from(a.clone()), -128);
+    assert_eq!(a.to_bits_be(), sign_first);
+    assert!(a.to_bits_le()[7]);
+
+    let b = GarbledInt::<4>::from_bits_le(&[true, false, false, false]);
+    assert_eq!(i8::from(b), 1);
+
+    let c: GarbledInt8 = (-3_i8).into();
+    assert_eq!(GarbledInt8::from_bits_le(&c.to_bits_le()), c);
+    assert_eq!(GarbledInt8::from_bits_be(&c.to_bits_be()), c);
+}
+
+#[test]
+fn test_bits_accessors() {
+    let a: GarbledInt8 = (-2_i8).into();
+    let expected = vec![false, true, true, true, true, true, true, true];
+    assert_eq!(*a.bits(), *expected);
+    assert_eq!(a.clone().into_bits(), expected);
+    assert_eq!(GarbledInt8::from_bits(expected), a);
+}
+
+#[test]
+fn test_bit_access() {
+    let value: GarbledInt8 = 5_i8.into();
+    assert!(bool::from(value.test_bit(2)));
+    assert!(!bool::from(value.test_bit(7)));
+
+    // setting the sign bit of 5 gives 5 - 128
+    assert_eq!(i8::from(value.with_bit_set(7)), -123);
+
+    let minus_one: GarbledInt8 = (-1_i8).into();
+    assert_eq!(i8::from(minus_one.with_bit_cleared(7)), i8::MAX);
+    assert_eq!(i8::from(minus_one.set_bit(0, &false.into())), -2);
+}
+
+#[test]
+fn test_reinterpret_negative_as_unsigned() {
+    let a: GarbledInt8 = (-1_i8).into();
+    assert_eq!(u8::from(a.to_unsigned()), 255);
+
+    let b: GarbledInt8 = i8::MIN.into();
+    assert_eq!(u8::from(b.to_unsigned()), 128);
+
+    let c: GarbledInt16 = (-300_i16).into();
+    assert_eq!(u16::from(c.to_unsigned()), (-300_i16) as u16);
+
+    // non-negative values keep their value
+    let d: GarbledInt32 = 12345_i32.into();
+    assert_eq!(u32::from(d.to_unsigned()), 12345);
+}
+
+#[test]
+fn test_reinterpret_high_unsigned_as_signed() {
+    let a: GarbledUint8 = 255_u8.into();
+    assert_eq!(i8::from(a.to_signed()), -1);
+
+    let b: GarbledUint8 = 200_u8.into();
+    assert_eq!(i8::from(b.to_signed()), 200_u8 as i8);
+
+    let c: GarbledUint16 = 0x8000_u16.into();
+    assert_eq!(i16::from(c.to_signed()), i16::MIN);
+
+    // short bit vectors are zero-padded to all N bits
+    assert_eq!(i8::from(GarbledUint8::one().to_signed()), 1);
+}
+
+#[test]
+fn test_reinterpret_round_trip() {
+    for value in [-128_i8, -77, -1, 0, 1, 100, 127] {
+        let a: GarbledInt8 = value.into();
+        assert_eq!(a.clone().to_unsigned().to_signed(), a);
+    }
+}
+
+#[test]
+fn test_signed_try_conversions() {
+    let value = GarbledInt8::try_from_i32(-128).unwrap();
+    assert_eq!(i8::from(value), -128);
+    let error = GarbledInt8::try_from_i32(128).unwrap_err();
+    assert_eq!((error.required, error.available), (9, 8));
+    assert!(GarbledInt8::try_from_i64(-129).is_err());
+    assert_eq!(i8::from(GarbledInt8::try_from(&-56_i16).unwrap()), -56);
+    assert!(GarbledInt8::try_from(&200_i16).is_err());
+
+    // narrowing keeps the sign
+    let wide = GarbledInt64::try_from_i64(-300).unwrap();
+    assert_eq!(wide.try_into_i16(), Ok(-300));
+    assert_eq!(i32::try_from(&wide), Ok(-300));
+    assert!(wide.try_into_i8().is_err());
+
+    // widening sign-extends
+    let narrow = GarbledInt8::try_from_i8(-1).unwrap();
+    assert_eq!(narrow.try_into_i128(), Ok(-1));
+    let positive = GarbledInt8::try_from_i8(127).unwrap();
+    assert_eq!(positive.try_into_i64(), Ok(127));
+}
+
+#[test]
+fn test_signed_byte_order() {
+    let value = -0x1234_5678_i64;
+    let garbled: GarbledInt64 = value.into();
+    assert_eq!(garbled.to_le_bytes(), value.to_le_bytes());
+    assert_eq!(garbled.to_be_bytes(), value.to_be_bytes());
+
+    let decoded = GarbledInt64::from_be_bytes(&value.to_be_bytes()).unwrap();
+    assert_eq!(i64::from(decoded), value);
+    let decoded = GarbledInt16::from_le_bytes(&(-2_i16).to_le_bytes()).unwrap();
+    assert_eq!(i16::from(decoded), -2);
+}
+
+#[test]
+fn test_constant_stores_all_bits() {
+    assert_eq!(i8::from(GarbledInt8::constant(-128)), -128);
+    assert_eq!(i8::from(GarbledInt8::constant(127)), 127);
+    assert_eq!(i16::from(GarbledInt16::constant(-2)), -2);
+    assert_eq!(GarbledInt::<1>::constant(-1).bits().len(), 1);
+    assert_eq!(GarbledInt256::constant(-1).to_bits_le(), vec![true; 256]);
+
+    let a: GarbledInt32 = (-40_i32).into();
+    assert_eq!(i32::from(a + GarbledInt32::constant(-2)), -42);
+}
+
+#[test]
+#[should_panic(expected = "128 does not fit in GarbledInt<8>")]
+fn test_constant_overflow() {
+    GarbledInt8::constant(128);
+}
+
+#[test]
+fn test_sign_extend_and_truncate() {
+    let negative: GarbledInt8 = (-100_i8).into();
+    let wide: GarbledInt32 = negative.sign_extend();
+    assert_eq!(i32::from(wide.clone()), -100);
+    let positive: GarbledInt8 = 100_i8.into();
+    assert_eq!(i64::from(positive.sign_extend::<64>()), 100);
+
+    // narrowing wraps like `as`
+    let value: GarbledInt32 = 300_i32.into();
+    assert_eq!(i8::from(value.truncate::<8>()), 300_i32 as i8);
+    assert_eq!(i8::from(wide.truncate::<8>()), -100);
+}
+
+#[test]
+fn test_reinterpret_with_width_change() {
+    let negative: GarbledInt8 = (-1_i8).into();
+    assert_eq!(
+        u16::from(negative.sign_extend_unsigned::<16>()),
+        -1_i8 as u16
+    );
+    assert_eq!(
+        u16::from(negative.zero_extend_unsigned::<16>()),
+        -1_i8 as u8 as u16
+    );
+
+    let positive: GarbledInt8 = 100_i8.into();
+    assert_eq!(u32::from(positive.sign_extend_unsigned::<32>()), 100);
+    assert_eq!(u8::from(positive.zero_extend_unsigned::<8>()), 100);
+
+    let value: GarbledUint8 = 200_u8.into();
+    assert_eq!(i16::from(value.zero_extend_signed::<16>()), 200);
+    assert_eq!(i8::from(value.zero_extend_signed::<8>()), 200_u8 as i8);
+
+    // a signed intermediate feeding wider unsigned arithmetic
+    let delta: GarbledInt8 = (-3_i8).into();
+    let base: GarbledUint16 = 1000_u16.into();
+    let sum = base + delta.sign_extend_unsigned::<16>();
+    assert_eq!(u16::from(sum), 997);
+}
+
+#[test]
+fn test_parse_signed_literals() {
+    let value: GarbledInt8 = "-128".parse().unwrap();
+    assert_eq!(i8::from(value), -128);
+    let value: GarbledInt8 = "+127".parse().unwrap();
+    assert_eq!(i8::from(value), 127);
+    let value: GarbledInt16 = "-0x7fff".parse().unwrap();
+    assert_eq!(i16::from(value), -0x7fff);
+    let value: GarbledInt32 = "-0".parse().unwrap();
+    assert_eq!(i32::from(value), 0);
+
+    assert_eq!(i8::from(GarbledInt8::from_hex("-80").unwrap()), -128);
+    assert_eq!(i8::from(GarbledInt8::from_bin("-0b101").unwrap()), -5);
+    assert_eq!(
+        i64::from(GarbledInt64::from_str_radix("-zz", 36).unwrap()),
+        -1295
+    );
+}
+
+#[test]
+fn test_parse_signed_overflow() {
+    use compute::error::ParseError;
+
+    assert_eq!(
+        "128".parse::<GarbledInt8>().unwrap_err(),
+        ParseError::Overflow
+    );
+    assert_eq!(
+        "-129".parse::<GarbledInt8>().unwrap_err(),
+        ParseError::Overflow
+    );
+    // hex is a magnitude, like `i8::from_str_radix("ff", 16)`
+    assert_eq!(
+        GarbledInt8::from_hex("ff").unwrap_err(),
+        ParseError::Overflow
+    );
+    assert_eq!("-".parse::<GarbledInt8>().unwrap_err(), ParseError::Empty);
+}
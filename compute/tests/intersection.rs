@@ -0,0 +1,30 @@
+use compute::prelude::*;
+
+fn intersection_size(a: &[u16], b: &[u16]) -> u8 {
+    let a: Vec<GarbledUint16> = a.iter().map(|&value| value.into()).collect();
+    let b: Vec<GarbledUint16> = b.iter().map(|&value| value.into()).collect();
+    GarbledUint16::intersection_size::<8>(&a, &b).into()
+}
+
+#[test]
+fn test_intersection_size_disjoint() {
+    assert_eq!(intersection_size(&[1, 3, 5, 7], &[2, 4, 6, 8]), 0);
+}
+
+#[test]
+fn test_intersection_size_partial_overlap() {
+    assert_eq!(intersection_size(&[1, 2, 3, 4, 5], &[4, 5, 6]), 2);
+    assert_eq!(intersection_size(&[10, 200, 3000], &[3000, 10]), 2);
+}
+
+#[test]
+fn test_intersection_size_identical() {
+    let set = [0, 17, 256, 9999, 65535];
+    assert_eq!(intersection_size(&set, &set), 5);
+}
+
+#[test]
+fn test_intersection_size_empty() {
+    assert_eq!(intersection_size(&[], &[1, 2]), 0);
+    assert_eq!(intersection_size(&[1, 2], &[]), 0);
+}
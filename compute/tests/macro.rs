@@ -219,7 +219,6 @@ fn test_macro_if_else4() {
     assert_eq!(result, (a + b) * (a + b));
 }
 
-#[ignore = "division not yet supported"]
 #[test]
 fn test_macro_division() {
     #[encrypted(execute)]
@@ -234,7 +233,6 @@ fn test_macro_division() {
     assert_eq!(result, a / b);
 }
 
-#[ignore = "modulo not yet supported"]
 #[test]
 fn test_macro_remainder() {
     #[encrypted(execute)]
@@ -580,6 +578,37 @@ fn test_macro_rem() {
     assert_eq!(result, a % b);
 }
 
+#[test]
+fn test_macro_rem_wide() {
+    #[encrypted(execute)]
+    fn rem16(a: u16, b: u16) -> u16 {
+        a % b
+    }
+
+    #[encrypted(execute)]
+    fn rem32(a: u32, b: u32) -> u32 {
+        a % b
+    }
+
+    #[encrypted(execute)]
+    fn rem64(a: u64, b: u64) -> u64 {
+        a % b
+    }
+
+    #[encrypted(execute)]
+    fn rem128(a: u128, b: u128) -> u128 {
+        a % b
+    }
+
+    assert_eq!(rem16(65535_u16, 1000_u16), 65535 % 1000);
+    assert_eq!(
+        rem32(0xdead_beef_u32, 0x8000_0001_u32),
+        0xdead_beef_u32 % 0x8000_0001
+    );
+    assert_eq!(rem64(u64::MAX, 12345_u64), u64::MAX % 12345);
+    assert_eq!(rem128(u128::MAX, 1_u128 << 100), u128::MAX % (1 << 100));
+}
+
 #[test]
 fn test_macro_rem_with_remainder() {
     #[encrypted(execute)]
@@ -606,6 +635,72 @@ fn test_macro_constants() {
     assert_eq!(result, 30_u8);
 }
 
+#[test]
+fn test_macro_constants_are_not_inputs() {
+    #[encrypted(compile)]
+    fn constants(a: u8) -> u8 {
+        a + 20
+    }
+
+    let (circuit, inputs) = constants(10_u8);
+
+    // only the 8 bits of `a` are inputs, the literal is built from public constant wires
+    assert_eq!(inputs.len(), 8);
+    assert_eq!(circuit.contrib_inputs(), 8);
+    assert_eq!(circuit.eval_inputs(), 0);
+
+    let result = get_executor().execute(&circuit, &inputs, &[]).unwrap();
+    let result: u8 = GarbledUint8::new(result).into();
+    assert_eq!(result, 30);
+}
+
+#[test]
+fn test_macro_reused_input_declared_once() {
+    #[encrypted(compile)]
+    fn reuse(a: u8, b: u8, c: u8) -> u8 {
+        a * b + a * c + a
+    }
+
+    let (circuit, inputs) = reuse(7_u8, 5_u8, 3_u8);
+
+    // `a` is used three times but contributes its 8 input bits once
+    assert_eq!(inputs.len(), 24);
+    assert_eq!(circuit.contrib_inputs(), 24);
+
+    // the macro looks every parameter up by name, so each use of `a` maps to the same wires
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = GarbledUint8::from(7_u8);
+    let a1 = builder.keyed_input("a", &a);
+    let b = builder.keyed_input("b", &GarbledUint8::from(5_u8));
+    let c = builder.keyed_input("c", &GarbledUint8::from(3_u8));
+    let a2 = builder.keyed_input("a", &a);
+    let a3 = builder.keyed_input("a", &a);
+    assert_eq!(a1, a2);
+    assert_eq!(a1, a3);
+    assert_eq!(builder.inputs(), inputs.as_slice());
+
+    let ab = builder.mul(&a1, &b);
+    let ac = builder.mul(&a2, &c);
+    let sum = builder.add(&ab, &ac);
+    let output = builder.add(&sum, &a3);
+    assert_eq!(builder.inputs().len(), 24);
+    let keyed: u8 = builder.compile_and_execute::<8>(&output).unwrap().into();
+    assert_eq!(keyed, 7 * 5 + 7 * 3 + 7);
+
+    // declaring `a` afresh at every use sends its bits three times
+    let mut builder = WRK17CircuitBuilder::default();
+    for _ in 0..3 {
+        builder.input(&a);
+    }
+    builder.input(&GarbledUint8::from(5_u8));
+    builder.input(&GarbledUint8::from(3_u8));
+    assert_eq!(builder.inputs().len(), 40);
+
+    let result = get_executor().execute(&circuit, &inputs, &[]).unwrap();
+    let result: u8 = GarbledUint8::new(result).into();
+    assert_eq!(result, 7 * 5 + 7 * 3 + 7);
+}
+
 #[test]
 fn test_macro_embedded_constants() {
     #[encrypted(execute)]
@@ -675,6 +770,74 @@ fn test_sub_assign() {
     assert_eq!(result, 246_u8);
 }
 
+#[test]
+fn test_macro_overflow_check_add() {
+    #[encrypted(execute, overflow_check)]
+    fn checked_add(a: u8, b: u8) -> u8 {
+        a + b
+    }
+
+    assert_eq!(checked_add(200_u8, 100_u8), (44, true));
+    assert_eq!(checked_add(100_u8, 50_u8), (150, false));
+    assert_eq!(checked_add(255_u8, 0_u8), (255, false));
+}
+
+#[test]
+fn test_macro_overflow_check_sub_and_mul() {
+    #[encrypted(execute, overflow_check)]
+    fn checked_sub(a: u8, b: u8) -> u8 {
+        let c = a;
+        c -= b
+    }
+
+    assert_eq!(checked_sub(10_u8, 20_u8), (246, true));
+    assert_eq!(checked_sub(20_u8, 10_u8), (10, false));
+
+    #[encrypted(execute, overflow_check)]
+    fn checked_mul(a: u8, b: u8) -> u8 {
+        a * b
+    }
+
+    for (a, b) in [
+        (15_u8, 17_u8),
+        (16, 16),
+        (128, 2),
+        (2, 128),
+        (255, 1),
+        (0, 255),
+        (3, 100),
+    ] {
+        let (expected, overflowed) = a.overflowing_mul(b);
+        assert_eq!(checked_mul(a, b), (expected, overflowed), "{} * {}", a, b);
+    }
+}
+
+#[test]
+fn test_macro_overflow_check_intermediate() {
+    // the flag covers every operation, even if a later one brings the value back in range
+    #[encrypted(execute, overflow_check)]
+    fn round_trip(a: u8, b: u8) -> u8 {
+        let sum = a + b;
+        sum - b
+    }
+
+    assert_eq!(round_trip(200_u8, 100_u8), (200, true));
+    assert_eq!(round_trip(100_u8, 50_u8), (100, false));
+
+    // operations without an overflow bit leave the flag clear
+    #[encrypted(execute, overflow_check)]
+    fn select(a: u16, b: u16) -> u16 {
+        if a > b {
+            a
+        } else {
+            b / 2
+        }
+    }
+
+    assert_eq!(select(7_u16, 3_u16), (7, false));
+    assert_eq!(select(3_u16, 10_u16), (5, false));
+}
+
 #[test]
 fn test_mul_assign() {
     #[encrypted(execute)]
@@ -1310,3 +1473,310 @@ fn test_macro_if() {
     let result = if_test(a);
     assert_eq!(result, 100);
 }
+
+#[test]
+fn test_macro_signed_greater_than() {
+    #[encrypted(execute)]
+    fn greater_than(a: i8, b: i8) -> i8 {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+
+    assert_eq!(greater_than(-1_i8, 1_i8), 1);
+    assert_eq!(greater_than(1_i8, -1_i8), 1);
+    assert_eq!(greater_than(-5_i8, -3_i8), -3);
+    assert_eq!(greater_than(-128_i8, 127_i8), 127);
+}
+
+#[test]
+fn test_macro_signed_less_than() {
+    #[encrypted(execute)]
+    fn less_than(a: i8, b: i8) -> i8 {
+        if a < b {
+            a
+        } else {
+            b
+        }
+    }
+
+    assert_eq!(less_than(-1_i8, 1_i8), -1);
+    assert_eq!(less_than(1_i8, -1_i8), -1);
+    assert_eq!(less_than(-5_i8, -3_i8), -5);
+    assert_eq!(less_than(-128_i8, 127_i8), -128);
+}
+
+#[test]
+fn test_macro_signed_greater_than_or_equal() {
+    #[encrypted(execute)]
+    fn greater_than_or_equal(a: i16, b: i16) -> i16 {
+        if a >= b {
+            a - b
+        } else {
+            b - a
+        }
+    }
+
+    assert_eq!(greater_than_or_equal(-1_i16, 1_i16), 2);
+    assert_eq!(greater_than_or_equal(-300_i16, -300_i16), 0);
+    assert_eq!(greater_than_or_equal(-300_i16, -500_i16), 200);
+}
+
+#[test]
+fn test_macro_signed_less_than_or_equal() {
+    #[encrypted(execute)]
+    fn less_than_or_equal(a: i32, b: i32) -> i32 {
+        if a <= b {
+            a + b
+        } else {
+            a - b
+        }
+    }
+
+    assert_eq!(less_than_or_equal(-1_i32, 1_i32), 0);
+    assert_eq!(less_than_or_equal(-7_i32, -7_i32), -14);
+    assert_eq!(less_than_or_equal(5_i32, -7_i32), 12);
+}
+
+#[test]
+fn test_macro_max_plus() {
+    #[encrypted(execute)]
+    fn max_plus(a: u8, b: u8, c: u8) -> u8 {
+        a.max(b) + c
+    }
+
+    for (a, b, c) in [(3_u8, 9_u8, 4_u8), (200, 17, 50), (7, 7, 0), (0, 255, 1)] {
+        assert_eq!(max_plus(a, b, c), a.max(b).wrapping_add(c));
+    }
+}
+
+#[test]
+fn test_macro_min_and_clamp() {
+    #[encrypted(execute)]
+    fn min_clamp(a: u8, b: u8) -> u8 {
+        let m = a.min(b);
+        m.clamp(10, 100)
+    }
+
+    for (a, b) in [(3_u8, 9_u8), (200, 170), (50, 60), (0, 255)] {
+        assert_eq!(min_clamp(a, b), a.min(b).clamp(10, 100));
+    }
+}
+
+#[test]
+fn test_macro_signed_gadgets() {
+    #[encrypted(execute)]
+    fn signed_gadgets(a: i8, b: i8) -> i8 {
+        if a.lt(&b) {
+            a.abs() + b.min(a)
+        } else {
+            a.max(b) - b.abs()
+        }
+    }
+
+    for (a, b) in [(-5_i8, 3_i8), (3, -5), (-100, -20), (42, 42)] {
+        let expected = if a < b {
+            a.abs().wrapping_add(b.min(a))
+        } else {
+            a.max(b).wrapping_sub(b.abs())
+        };
+        assert_eq!(signed_gadgets(a, b), expected, "a = {}, b = {}", a, b);
+    }
+}
+
+#[test]
+fn test_macro_comparison_methods() {
+    #[encrypted(execute)]
+    fn compare(a: u16, b: u16) -> u16 {
+        if a.eq(&b) {
+            1
+        } else if a.ge(&b) {
+            2
+        } else {
+            3
+        }
+    }
+
+    assert_eq!(compare(5_u16, 5_u16), 1);
+    assert_eq!(compare(500_u16, 5_u16), 2);
+    assert_eq!(compare(5_u16, 500_u16), 3);
+}
+
+#[test]
+fn test_macro_while_constant_bound() {
+    #[encrypted(execute)]
+    fn accumulate(x: u8) -> u8 {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < 4 {
+            acc = acc + x;
+            i += 1;
+        }
+        acc
+    }
+
+    assert_eq!(accumulate(3_u8), 12);
+    assert_eq!(accumulate(0_u8), 0);
+    assert_eq!(accumulate(70_u8), 24); // wraps around at 8 bits
+}
+
+#[test]
+fn test_macro_mutable_reassignment() {
+    #[encrypted(execute)]
+    fn affine(x: u8, y: u8) -> u8 {
+        let mut acc = 0;
+        acc = acc + x;
+        acc = acc * 2;
+        acc = acc - y;
+        acc
+    }
+
+    assert_eq!(affine(5_u8, 3_u8), 7);
+    assert_eq!(affine(0_u8, 1_u8), 255);
+    assert_eq!(affine(200_u8, 0_u8), 144); // wraps around at 8 bits
+}
+
+#[test]
+fn test_macro_mixed_compound_and_plain_assignment() {
+    #[encrypted(execute)]
+    fn mixed(a: u16, b: u16) -> u16 {
+        let mut acc = a;
+        acc += b;
+        acc = acc * 3;
+        acc -= a;
+        acc ^= b;
+        acc
+    }
+
+    for (a, b) in [(1_u16, 2_u16), (100, 7), (0, 0), (60000, 9000)] {
+        let expected = ((a.wrapping_add(b)).wrapping_mul(3).wrapping_sub(a)) ^ b;
+        assert_eq!(mixed(a, b), expected);
+    }
+}
+
+#[test]
+fn test_macro_reassignment_in_loop_and_branch() {
+    #[encrypted(execute)]
+    fn sum_capped(x: u8, cap: u8) -> u8 {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < 3 {
+            acc += x;
+            acc = if acc > cap { cap } else { acc };
+            i += 1;
+        }
+        acc
+    }
+
+    assert_eq!(sum_capped(2_u8, 100_u8), 6);
+    assert_eq!(sum_capped(40_u8, 100_u8), 100);
+    assert_eq!(sum_capped(0_u8, 5_u8), 0);
+}
+
+#[test]
+fn test_macro_compile_metadata() {
+    #[encrypted(compile)]
+    fn qualifies_for_discount(purchase_amount: u16, discount_threshold: u16) -> bool {
+        purchase_amount >= discount_threshold
+    }
+
+    let metadata: serde_json::Value =
+        serde_json::from_str(qualifies_for_discount_metadata()).unwrap();
+
+    assert_eq!(metadata["name"], "qualifies_for_discount");
+
+    let inputs = metadata["inputs"].as_array().unwrap();
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(inputs[0]["name"], "purchase_amount");
+    assert_eq!(inputs[1]["name"], "discount_threshold");
+    for input in inputs {
+        assert_eq!(input["width"], 16);
+        assert_eq!(input["signed"], false);
+        assert_eq!(input["party"], "contributor");
+    }
+
+    assert_eq!(metadata["output"]["width"], 1);
+    assert_eq!(metadata["output"]["signed"], false);
+
+    // the parameter bits lead the input vector, so a runner can substitute new arguments
+    let (circuit, mut inputs) = qualifies_for_discount(0_u16, 0_u16);
+    assert_eq!(circuit.output_gates().len(), 1);
+
+    let purchase_amount: GarbledUint16 = 50_u16.into();
+    let discount_threshold: GarbledUint16 = 80_u16.into();
+    inputs[..16].copy_from_slice(&purchase_amount.bits());
+    inputs[16..32].copy_from_slice(&discount_threshold.bits());
+
+    let result = get_executor().execute(&circuit, &inputs, &[]).unwrap();
+    assert_eq!(result, vec![false]);
+}
+
+#[test]
+fn test_macro_signed_return_negative() {
+    #[encrypted(execute)]
+    fn difference(a: i16, b: i16) -> i16 {
+        a - b
+    }
+
+    for (a, b) in [
+        (3_i16, 10_i16),
+        (-300, 500),
+        (0, 1),
+        (i16::MIN, 1),
+        (-1, i16::MAX),
+        (1000, -24),
+    ] {
+        assert_eq!(difference(a, b), a.wrapping_sub(b), "a = {}, b = {}", a, b);
+    }
+}
+
+#[test]
+fn test_macro_signed_division() {
+    #[encrypted(execute)]
+    fn quotient(a: i16, b: i16) -> i16 {
+        a / b
+    }
+
+    #[encrypted(execute)]
+    fn remainder(a: i16, b: i16) -> i16 {
+        a % b
+    }
+
+    for (a, b) in [(-7_i16, 2_i16), (7, -2), (-7, -2), (i16::MIN, -1), (300, 7)] {
+        assert_eq!(quotient(a, b), a.wrapping_div(b), "a = {}, b = {}", a, b);
+        assert_eq!(remainder(a, b), a.wrapping_rem(b), "a = {}, b = {}", a, b);
+    }
+}
+
+#[test]
+fn test_macro_negation() {
+    #[encrypted(execute)]
+    fn negate(a: i16) -> i16 {
+        -a
+    }
+
+    #[encrypted(execute)]
+    fn offset(a: i16, b: i16) -> i16 {
+        -(a + b) + -3
+    }
+
+    for a in [0_i16, 1, -1, 300, i16::MIN, i16::MAX] {
+        assert_eq!(negate(a), a.wrapping_neg(), "a = {}", a);
+    }
+    assert_eq!(offset(10_i16, -4_i16), -9);
+    assert_eq!(offset(-10_i16, -4_i16), 11);
+}
+
+#[test]
+fn test_macro_signed_return_wider_than_parameters() {
+    #[encrypted(execute)]
+    fn difference(a: i8, b: i8) -> i32 {
+        a - b
+    }
+
+    assert_eq!(difference(3_i8, 10_i8), -7);
+    assert_eq!(difference(-100_i8, 27_i8), -127);
+    assert_eq!(difference(10_i8, 3_i8), 7);
+}
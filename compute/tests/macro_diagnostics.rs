@@ -0,0 +1,29 @@
+use std::process::Command;
+
+// The `.stderr` snapshots pin rustc's rendering of the diagnostics, so they are only compared on
+// the toolchain they were recorded with. Bump this together with `TRYBUILD=overwrite`.
+const SNAPSHOT_RUSTC: &str = "rustc 1.95.";
+
+// Unsupported constructs in #[encrypted] functions must fail with a diagnostic pointing at the
+// offending expression rather than a panic inside the macro. Mixing widths on the typed builder
+// must fail to type-check.
+#[test]
+fn test_macro_unsupported_constructs() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default();
+    if !version.starts_with(SNAPSHOT_RUSTC) {
+        eprintln!(
+            "skipping diagnostics snapshots recorded with {}x on {}",
+            SNAPSHOT_RUSTC,
+            version.trim()
+        );
+        return;
+    }
+
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,60 @@
+use compute::prelude::*;
+
+#[test]
+fn test_mask_on_returns_value() {
+    let on = GarbledBit::one();
+    assert_eq!(u8::from(GarbledUint8::mask(&on, &0xA5_u8.into())), 0xA5);
+    assert_eq!(
+        u16::from(GarbledUint16::mask(&on, &0xBEEF_u16.into())),
+        0xBEEF
+    );
+    assert_eq!(
+        u32::from(GarbledUint32::mask(&on, &0xDEAD_BEEF_u32.into())),
+        0xDEAD_BEEF
+    );
+    assert_eq!(
+        u64::from(GarbledUint64::mask(&on, &u64::MAX.into())),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_mask_off_returns_zero() {
+    let off = GarbledBit::zero();
+    assert_eq!(u8::from(GarbledUint8::mask(&off, &0xA5_u8.into())), 0);
+    assert_eq!(u16::from(GarbledUint16::mask(&off, &0xBEEF_u16.into())), 0);
+    assert_eq!(
+        u32::from(GarbledUint32::mask(&off, &0xDEAD_BEEF_u32.into())),
+        0
+    );
+    assert_eq!(u64::from(GarbledUint64::mask(&off, &u64::MAX.into())), 0);
+}
+
+#[test]
+fn test_mask_signed() {
+    let on = GarbledBit::one();
+    let off = GarbledBit::zero();
+    for value in [i8::MIN, -42, -1, 0, 1, i8::MAX] {
+        assert_eq!(i8::from(GarbledInt8::mask(&on, &value.into())), value);
+        assert_eq!(i8::from(GarbledInt8::mask(&off, &value.into())), 0);
+    }
+    assert_eq!(
+        i32::from(GarbledInt32::mask(&on, &(-123_456_i32).into())),
+        -123_456
+    );
+    assert_eq!(
+        i32::from(GarbledInt32::mask(&off, &(-123_456_i32).into())),
+        0
+    );
+}
+
+#[test]
+fn test_mask_matches_mux_against_zero() {
+    for cond in [false, true] {
+        let cond: GarbledBit = cond.into();
+        let x: GarbledUint16 = 0x1234_u16.into();
+        let masked = GarbledUint::mask(&cond, &x);
+        let muxed = GarbledUint::mux(&cond, &x, &0_u16.into());
+        assert_eq!(u16::from(masked), u16::from(muxed));
+    }
+}
@@ -0,0 +1,203 @@
+use compute::prelude::*;
+
+fn mul_with<const N: usize>(
+    multiplier: Multiplier,
+    adder: Adder,
+    a: u128,
+    b: u128,
+) -> (u128, CircuitStats) {
+    let a: GarbledUint<N> = a.into();
+    let b: GarbledUint<N> = b.into();
+
+    let mut builder = WRK17CircuitBuilder::default()
+        .with_adder(adder)
+        .with_multiplier(multiplier);
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let product = builder.mul(&a, &b);
+
+    let stats = circuit_stats(&builder.compile(&product)).unwrap();
+    let result: GarbledUint<N> = builder.compile_and_execute(&product).unwrap();
+    (result.into(), stats)
+}
+
+fn check_width<const N: usize>() {
+    let mask = if N == 128 { u128::MAX } else { (1 << N) - 1 };
+    let values = [
+        0,
+        1,
+        mask,
+        mask >> 1,
+        0x5555_5555_5555_5555_5555_5555_5555_5555 & mask,
+        0xdead_beef_0123_4567_89ab_cdef_f00d_cafe & mask,
+    ];
+
+    for &a in &values {
+        for &b in &values {
+            let expected = a.wrapping_mul(b) & mask;
+            for adder in [Adder::RippleCarry, Adder::CarryLookahead] {
+                let (wallace, _) = mul_with::<N>(Multiplier::Wallace, adder, a, b);
+                assert_eq!(wallace, expected, "{a} * {b} at {N} bits");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_wallace_matches_native() {
+    check_width::<1>();
+    check_width::<2>();
+    check_width::<3>();
+    check_width::<5>();
+    check_width::<8>();
+    check_width::<13>();
+    check_width::<16>();
+    check_width::<32>();
+    check_width::<64>();
+}
+
+#[test]
+fn test_wallace_widening_mul() {
+    let x = 0xffff_ffff_ffff_fffb_u64;
+    let y = 0x1234_5678_9abc_def0_u64;
+
+    let mut builder = WRK17CircuitBuilder::default().with_multiplier(Multiplier::Wallace);
+    let a = builder.input(&GarbledUint64::from(x));
+    let b = builder.input(&GarbledUint64::from(y));
+    let product = builder.widening_mul(&a, &b);
+
+    let result: GarbledUint128 = builder.compile_and_execute(&product).unwrap();
+    assert_eq!(u128::from(result), x as u128 * y as u128);
+}
+
+#[test]
+#[should_panic(expected = "inputs must be added before any constant")]
+fn test_wallace_mul_then_input() {
+    let mut builder = WRK17CircuitBuilder::default().with_multiplier(Multiplier::Wallace);
+    let a = builder.input(&GarbledUint8::from(200_u8));
+    let b = builder.input(&GarbledUint8::from(3_u8));
+    builder.mul(&a, &b);
+    builder.input(&GarbledUint8::from(1_u8));
+}
+
+#[test]
+fn test_wallace_gate_counts() {
+    let (_, shift_and_add) = mul_with::<64>(Multiplier::ShiftAndAdd, Adder::RippleCarry, 3, 5);
+    let (_, wallace) = mul_with::<64>(Multiplier::Wallace, Adder::RippleCarry, 3, 5);
+
+    // the tree never builds the partial product bits below each row's shift
+    assert!(
+        wallace.and_gates * 3 < shift_and_add.and_gates * 2,
+        "wallace {} vs shift-and-add {} AND gates",
+        wallace.and_gates,
+        shift_and_add.and_gates
+    );
+
+    // with a shallow final adder, depth is the tree's levels rather than one adder per row
+    let (_, shift_and_add) = mul_with::<64>(Multiplier::ShiftAndAdd, Adder::CarryLookahead, 3, 5);
+    let (_, wallace) = mul_with::<64>(Multiplier::Wallace, Adder::CarryLookahead, 3, 5);
+    assert!(
+        wallace.and_depth <= 20,
+        "wallace depth {}",
+        wallace.and_depth
+    );
+    assert!(
+        shift_and_add.and_depth > 4 * wallace.and_depth,
+        "shift-and-add depth {}",
+        shift_and_add.and_depth
+    );
+}
+
+fn widening_mul_with<const N: usize, const M: usize>(
+    multiplier: Multiplier,
+    a: u128,
+    b: u128,
+) -> (u128, CircuitStats) {
+    let a: GarbledUint<N> = a.into();
+    let b: GarbledUint<N> = b.into();
+
+    let mut builder = WRK17CircuitBuilder::default().with_multiplier(multiplier);
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let product = builder.widening_mul(&a, &b);
+
+    let stats = circuit_stats(&builder.compile(&product)).unwrap();
+    let result: GarbledUint<M> = builder.compile_and_execute(&product).unwrap();
+    (result.into(), stats)
+}
+
+fn check_karatsuba<const N: usize, const M: usize>() {
+    let mask = (1 << N) - 1;
+    let values = [
+        0,
+        1,
+        mask,
+        mask >> 1,
+        0x5555_5555_5555_5555 & mask,
+        0xdead_beef_f00d_cafe & mask,
+    ];
+
+    for threshold in [0, 4, 7] {
+        let karatsuba = Multiplier::Karatsuba { threshold };
+        for &a in &values {
+            for &b in &values {
+                let (product, _) = mul_with::<N>(karatsuba, Adder::RippleCarry, a, b);
+                assert_eq!(product, a.wrapping_mul(b) & mask, "{a} * {b} at {N} bits");
+                let (product, _) = widening_mul_with::<N, M>(karatsuba, a, b);
+                assert_eq!(product, a * b, "{a} * {b} widened from {N} bits");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_karatsuba_matches_native() {
+    check_karatsuba::<1, 2>();
+    check_karatsuba::<4, 8>();
+    check_karatsuba::<5, 10>();
+    check_karatsuba::<9, 18>();
+    check_karatsuba::<16, 32>();
+    check_karatsuba::<23, 46>();
+    check_karatsuba::<32, 64>();
+    check_karatsuba::<64, 128>();
+}
+
+#[test]
+fn test_karatsuba_gate_counts() {
+    let x = 0xffff_ffff_ffff_fffb_u128;
+    let (product, wallace) = widening_mul_with::<64, 128>(Multiplier::Wallace, x, x);
+    let (karatsuba_product, karatsuba) =
+        widening_mul_with::<64, 128>(Multiplier::karatsuba(), x, x);
+    assert_eq!(karatsuba_product, product);
+    assert!(
+        karatsuba.and_gates < wallace.and_gates,
+        "karatsuba {} vs wallace {} AND gates",
+        karatsuba.and_gates,
+        wallace.and_gates
+    );
+
+    // a full 128-bit product saves over a third of the AND gates
+    let and_gates = |multiplier| {
+        let mut builder = WRK17CircuitBuilder::default().with_multiplier(multiplier);
+        let a = builder.input(&GarbledUint128::from(3_u128));
+        let b = builder.input(&GarbledUint128::from(5_u128));
+        let product = builder.widening_mul(&a, &b);
+        circuit_stats(&builder.compile(&product)).unwrap().and_gates
+    };
+    let wallace = and_gates(Multiplier::Wallace);
+    let karatsuba = and_gates(Multiplier::karatsuba());
+    assert!(
+        karatsuba * 3 < wallace * 2,
+        "karatsuba {karatsuba} vs wallace {wallace} AND gates"
+    );
+
+    // the crossover is the caller's: a threshold at the width is the Wallace tree itself
+    let (_, at_width) = mul_with::<64>(
+        Multiplier::Karatsuba { threshold: 64 },
+        Adder::RippleCarry,
+        3,
+        5,
+    );
+    let (_, wallace) = mul_with::<64>(Multiplier::Wallace, Adder::RippleCarry, 3, 5);
+    assert_eq!(at_width.and_gates, wallace.and_gates);
+}
@@ -0,0 +1,59 @@
+use compute::prelude::*;
+
+fn garble(values: &[u8]) -> Vec<GarbledUint8> {
+    values.iter().map(|&value| value.into()).collect()
+}
+
+fn plain(values: Vec<GarbledUint8>) -> Vec<u8> {
+    values.into_iter().map(u8::from).collect()
+}
+
+#[test]
+fn test_mux_store_each_position() {
+    let values = [10_u8, 20, 30, 40];
+    let new_value: GarbledUint8 = 99_u8.into();
+
+    for position in 0..values.len() {
+        let index: GarbledUint<2> = (position as u8).into();
+        let result = plain(GarbledUint8::mux_store(
+            &index,
+            &garble(&values),
+            &new_value,
+        ));
+
+        let mut expected = values;
+        expected[position] = 99;
+        assert_eq!(result, expected, "write to position {}", position);
+    }
+}
+
+#[test]
+fn test_mux_store_index_out_of_range() {
+    let values = [10_u8, 20, 30];
+    let new_value: GarbledUint8 = 99_u8.into();
+
+    // index 3 fits in 2 bits but has no slot
+    let index: GarbledUint<2> = 3_u8.into();
+    let result = plain(GarbledUint8::mux_store(
+        &index,
+        &garble(&values),
+        &new_value,
+    ));
+    assert_eq!(result, values);
+
+    // a 1-bit index can only reach the first two slots
+    let index: GarbledUint<1> = 1_u8.into();
+    let result = plain(GarbledUint8::mux_store(
+        &index,
+        &garble(&values),
+        &new_value,
+    ));
+    assert_eq!(result, [10, 99, 30]);
+}
+
+#[test]
+fn test_mux_store_empty() {
+    let index: GarbledUint8 = 0_u8.into();
+    let new_value: GarbledUint8 = 1_u8.into();
+    assert!(GarbledUint8::mux_store(&index, &[], &new_value).is_empty());
+}
@@ -0,0 +1,32 @@
+use compute::prelude::*;
+
+fn check(condition: bool, x: i8) {
+    let gx: GarbledInt8 = x.into();
+    let result: i8 = GarbledInt8::negate_if(&condition.into(), &gx).into();
+    let expected = if condition { x.wrapping_neg() } else { x };
+    assert_eq!(result, expected, "negate_if({}, {})", condition, x);
+}
+
+#[test]
+fn test_negate_if_true() {
+    for x in [1, 42, i8::MAX, -1, -42, i8::MIN, 0] {
+        check(true, x);
+    }
+}
+
+#[test]
+fn test_negate_if_false() {
+    for x in [1, 42, i8::MAX, -1, -42, i8::MIN, 0] {
+        check(false, x);
+    }
+}
+
+#[test]
+fn test_negate_if_wide() {
+    for (condition, x) in [(true, i64::MIN), (true, -123_456_789), (false, i64::MIN)] {
+        let gx: GarbledInt64 = x.into();
+        let result: i64 = GarbledInt64::negate_if(&condition.into(), &gx).into();
+        let expected = if condition { x.wrapping_neg() } else { x };
+        assert_eq!(result, expected);
+    }
+}
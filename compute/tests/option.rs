@@ -0,0 +1,58 @@
+use compute::prelude::*;
+
+fn padding() -> GarbledUint16 {
+    0xdead_u16.into()
+}
+
+#[test]
+fn test_option_unwrap_or() {
+    let default: GarbledUint16 = 7_u16.into();
+    let some = GarbledOption::some(GarbledUint16::from(42_u16));
+    let none = GarbledOption::none(padding());
+
+    assert_eq!(u16::from(some.unwrap_or(&default)), 42);
+    assert_eq!(u16::from(none.unwrap_or(&default)), 7);
+}
+
+#[test]
+fn test_option_or() {
+    let a = GarbledOption::some(GarbledUint16::from(1_u16));
+    let b = GarbledOption::some(GarbledUint16::from(2_u16));
+    let none = GarbledOption::none(padding());
+    let default: GarbledUint16 = 0_u16.into();
+
+    assert_eq!(u16::from(a.or(&b).unwrap_or(&default)), 1);
+    assert_eq!(u16::from(none.or(&b).unwrap_or(&default)), 2);
+    assert_eq!(u16::from(a.or(&none).unwrap_or(&default)), 1);
+
+    let neither = none.or(&none.clone());
+    assert!(!neither.is_some().bits()[0]);
+    assert_eq!(u16::from(neither.unwrap_or(&default)), 0);
+}
+
+#[test]
+fn test_option_map_keeps_presence() {
+    let offset: GarbledUint16 = 100_u16.into();
+    let default: GarbledUint16 = 0_u16.into();
+
+    let some = GarbledOption::some(GarbledUint16::from(5_u16)).map(|value| &value + &offset);
+    assert!(some.is_some().bits()[0]);
+    assert_eq!(u16::from(some.unwrap_or(&default)), 105);
+
+    // the padding is mapped too, but the flag still hides it
+    let none = GarbledOption::none(padding()).map(|value| &value + &offset);
+    assert!(!none.is_some().bits()[0]);
+    assert_eq!(u16::from(none.unwrap_or(&default)), 0);
+}
+
+#[test]
+fn test_option_computed_flag_signed() {
+    let value: GarbledInt32 = (-12_i32).into();
+    let default: GarbledInt32 = 99_i32.into();
+
+    let present = GarbledOption::new(true.into(), value.clone());
+    let absent = GarbledOption::new(false.into(), value);
+    assert_eq!(i32::from(present.unwrap_or(&default)), -12);
+    assert_eq!(i32::from(absent.unwrap_or(&default)), 99);
+    assert_eq!(i32::from(absent.or(&present).unwrap_or(&default)), -12);
+}
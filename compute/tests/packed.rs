@@ -0,0 +1,87 @@
+// Runs against whichever bit storage is enabled: one `bool` per bit by default, `u64` words with
+// the `packed` feature. Everything goes through the accessors, so both must give the same results.
+use compute::prelude::*;
+use std::borrow::Cow;
+
+#[test]
+fn test_storage_round_trips_bits() {
+    for width in [1, 7, 63, 64, 65, 130] {
+        let bits: Vec<bool> = (0..width).map(|i| i % 3 == 0 || i % 7 == 1).collect();
+        let value = GarbledUint::<130>::from_bits(bits.clone());
+        assert_eq!(value.len(), width);
+        assert_eq!(value.bits(), bits);
+        assert_eq!(value.iter_bits().collect::<Vec<_>>(), bits);
+        for (i, &bit) in bits.iter().enumerate() {
+            assert_eq!(value[i], bit);
+        }
+        assert_eq!(value.into_bits(), bits);
+    }
+}
+
+#[test]
+fn test_bits_borrow_unpacked_storage() {
+    // one `bool` per bit can be lent out as is; packed words are unpacked on every call
+    let value: GarbledUint64 = 0xdead_beef_u64.into();
+    assert_eq!(
+        matches!(value.bits(), Cow::Borrowed(_)),
+        cfg!(not(feature = "packed"))
+    );
+
+    let value: GarbledInt32 = (-5_i32).into();
+    assert_eq!(
+        matches!(value.bits(), Cow::Borrowed(_)),
+        cfg!(not(feature = "packed"))
+    );
+}
+
+#[test]
+fn test_storage_conversions() {
+    let a: GarbledUint128 = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128.into();
+    assert_eq!(
+        u128::from(a.clone()),
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210
+    );
+    assert_eq!(format!("{:x}", a), "123456789abcdeffedcba9876543210");
+
+    let b: GarbledInt64 = (-1234567890123_i64).into();
+    assert_eq!(i64::from(b.clone()), -1234567890123);
+    let unsigned: GarbledUint64 = b.clone().into();
+    assert_eq!(GarbledInt64::from(unsigned), b);
+}
+
+#[test]
+fn test_storage_executes_circuits() {
+    let a: GarbledUint64 = 0xdead_beef_cafe_f00d_u64.into();
+    let b: GarbledUint64 = 0x0123_4567_89ab_cdef_u64.into();
+    let sum: u64 = (&a + &b).into();
+    assert_eq!(
+        sum,
+        0xdead_beef_cafe_f00d_u64.wrapping_add(0x0123_4567_89ab_cdef)
+    );
+
+    let shifted: u64 = (a.clone() << 4).into();
+    assert_eq!(shifted, 0xdead_beef_cafe_f00d_u64 << 4);
+
+    let mut c = a;
+    c >>= 8;
+    assert_eq!(u64::from(c), 0xdead_beef_cafe_f00d_u64 >> 8);
+}
+
+#[test]
+fn test_storage_word_access() {
+    let words = [0x0123_4567_89ab_cdef, u64::MAX, 0xffff];
+    let value = GarbledUint::<130>::from_words(&words);
+    // bits past N are dropped
+    assert_eq!(
+        value.to_words(),
+        vec![0x0123_4567_89ab_cdef, u64::MAX, 0b11]
+    );
+    assert!(value[129]);
+    assert_eq!(value.to_bits_le().len(), 130);
+
+    // values storing fewer than N bits are zero-padded to N.div_ceil(64) words
+    assert_eq!(GarbledUint::<130>::one().to_words(), vec![1, 0, 0]);
+
+    let sum = &GarbledUint64::from_words(&[u64::MAX - 1]) + &GarbledUint64::from_words(&[1]);
+    assert_eq!(sum.to_words(), vec![u64::MAX]);
+}
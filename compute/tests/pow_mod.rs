@@ -0,0 +1,51 @@
+use compute::prelude::*;
+
+fn native_pow_mod(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    for _ in 0..exponent {
+        result = result * base % modulus;
+    }
+    result
+}
+
+#[test]
+fn test_pow_mod_u8() {
+    for (base, exponent, modulus) in [
+        (3_u8, 4_u8, 7_u8),
+        (2, 10, 255),
+        (200, 3, 251),
+        (5, 0, 13),
+        (0, 0, 13),
+        (0, 5, 13),
+        (9, 7, 1),
+        (255, 255, 254),
+    ] {
+        let a: GarbledUint8 = base.into();
+        let result: u8 = a.pow_mod(&exponent.into(), &modulus.into()).into();
+        let expected = native_pow_mod(base as u64, exponent as u64, modulus as u64);
+        assert_eq!(
+            result as u64, expected,
+            "{}^{} mod {}",
+            base, exponent, modulus
+        );
+    }
+}
+
+#[test]
+fn test_pow_mod_u16() {
+    for (base, exponent, modulus) in [
+        (7_u16, 13_u16, 1000_u16),
+        (65535, 2, 65521),
+        (12345, 0, 777),
+        (3, 100, 1),
+    ] {
+        let a: GarbledUint16 = base.into();
+        let result: u16 = a.pow_mod(&exponent.into(), &modulus.into()).into();
+        let expected = native_pow_mod(base as u64, exponent as u64, modulus as u64);
+        assert_eq!(
+            result as u64, expected,
+            "{}^{} mod {}",
+            base, exponent, modulus
+        );
+    }
+}
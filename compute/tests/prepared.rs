@@ -0,0 +1,68 @@
+use compute::prelude::*;
+
+fn multiply_add_circuit() -> (WRK17CircuitBuilder, Circuit) {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(0_u8));
+    let b = builder.input(&GarbledUint8::from(0_u8));
+    let c = builder.input(&GarbledUint8::from(0_u8));
+    let product = builder.mul(&a, &b);
+    let output = builder.add(&product, &c);
+    let circuit = builder.compile(&output);
+    (builder, circuit)
+}
+
+fn inputs(a: u8, b: u8, c: u8) -> Vec<bool> {
+    [a, b, c]
+        .iter()
+        .flat_map(|&value| GarbledUint8::from(value).into_bits())
+        .collect()
+}
+
+#[test]
+fn test_prepared_matches_execute() {
+    let (_, circuit) = multiply_add_circuit();
+    let executor = get_executor();
+    let prepared = executor.prepared(&circuit).unwrap();
+
+    for (a, b, c) in [
+        (0, 0, 0),
+        (3, 7, 1),
+        (255, 255, 255),
+        (16, 16, 9),
+        (200, 3, 100),
+    ] {
+        let input = inputs(a, b, c);
+        let expected = executor.execute(&circuit, &input, &[]).unwrap();
+        let result = prepared.execute_inputs(&input, &[]).unwrap();
+        assert_eq!(result, expected);
+
+        let value: u8 = GarbledUint8::new(result).into();
+        assert_eq!(value, a.wrapping_mul(b).wrapping_add(c));
+    }
+}
+
+#[test]
+fn test_prepared_reused_in_loop() {
+    let (_, circuit) = multiply_add_circuit();
+    let executor = get_executor();
+    let prepared = executor.prepared(&circuit).unwrap();
+
+    let mut total = 0_u32;
+    for i in 0..=255_u8 {
+        let result = prepared.execute_inputs(&inputs(i, 2, 1), &[]).unwrap();
+        let value: u8 = GarbledUint8::new(result).into();
+        assert_eq!(value, i.wrapping_mul(2).wrapping_add(1));
+        total += value as u32;
+    }
+    assert_eq!(total, (0..=255_u32).map(|i| (i * 2 + 1) % 256).sum());
+}
+
+#[test]
+fn test_prepared_rejects_wrong_input_length() {
+    let (_, circuit) = multiply_add_circuit();
+    let executor = get_executor();
+    let prepared = executor.prepared(&circuit).unwrap();
+
+    assert!(prepared.execute_inputs(&[true; 8], &[]).is_err());
+    assert!(prepared.execute_inputs(&inputs(1, 2, 3), &[true]).is_err());
+}
@@ -0,0 +1,115 @@
+use compute::prelude::*;
+
+#[derive(GarbledEncode, Debug, Clone, PartialEq)]
+struct Bid {
+    amount: u32,
+    bidder: u8,
+}
+
+#[test]
+fn test_plain_encode_and_reveal() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = Plain::new(Bid {
+        amount: 120,
+        bidder: 1,
+    })
+    .encode(&mut builder);
+    let b = Plain::new(Bid {
+        amount: 150,
+        bidder: 2,
+    })
+    .encode(&mut builder);
+
+    // only the winner is revealed; the losing bid never leaves the circuit
+    let b_wins = builder.lt(&a.wires().amount, &b.wires().amount);
+    let winner: Encoded<Bid> = Encoded::from_wires(BidWires {
+        amount: builder.mux(&b_wins, &b.wires().amount, &a.wires().amount),
+        bidder: builder.mux(&b_wins, &b.wires().bidder, &a.wires().bidder),
+    });
+
+    let winner = winner.reveal(&builder).unwrap();
+    assert_eq!(
+        winner.get(),
+        &Bid {
+            amount: 150,
+            bidder: 2
+        }
+    );
+}
+
+#[test]
+fn test_reveal_tuple_of_encoded_values() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let x = Plain::new(GarbledInt16::from(-40_i16)).encode(&mut builder);
+    let y = Plain::new(GarbledInt16::from(15_i16)).encode(&mut builder);
+
+    let sum = builder.add(x.wires(), y.wires());
+    let x_lt_y = builder.signed_lt(x.wires(), y.wires());
+    let revealed: Encoded<(GarbledInt16, GarbledBit)> = Encoded::from_wires((sum, x_lt_y.into()));
+
+    let (sum, x_lt_y) = revealed.reveal(&builder).unwrap().into_inner();
+    assert_eq!(i16::from(sum), -25);
+    assert!(bool::from(x_lt_y));
+}
+
+#[test]
+fn test_constant_uses_no_inputs() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let bid = Plain::new(Bid {
+        amount: 120,
+        bidder: 1,
+    })
+    .encode(&mut builder);
+    let inputs = builder.inputs().len();
+
+    let reserve = Constant::new(GarbledUint32::constant(100)).encode(&mut builder);
+    let fee = Constant::new(GarbledUint32::constant(5)).encode(&mut builder);
+    assert_eq!(builder.inputs().len(), inputs);
+
+    let above_reserve = builder.lt(reserve.wires(), &bid.wires().amount);
+    let net = builder.sub(&bid.wires().amount, fee.wires());
+    let zero = Constant::new(GarbledUint32::constant(0)).encode(&mut builder);
+    let net: Encoded<GarbledUint32> =
+        Encoded::from_wires(builder.mux(&above_reserve, &net, zero.wires()));
+    assert_eq!(u32::from(net.reveal(&builder).unwrap().into_inner()), 115);
+}
+
+#[test]
+fn test_evaluator_input_records_no_bits() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let ours = Plain::new(Bid {
+        amount: 120,
+        bidder: 1,
+    })
+    .encode(&mut builder);
+    let theirs: Encoded<Bid> = Encoded::evaluator_input(&mut builder);
+    assert_eq!(builder.inputs().len(), Bid::WIDTH);
+    assert_eq!(builder.evaluator_inputs(), Bid::WIDTH);
+
+    let theirs_win = builder.lt(&ours.wires().amount, &theirs.wires().amount);
+    let winner: Encoded<Bid> = Encoded::from_wires(BidWires {
+        amount: builder.mux(&theirs_win, &theirs.wires().amount, &ours.wires().amount),
+        bidder: builder.mux(&theirs_win, &theirs.wires().bidder, &ours.wires().bidder),
+    });
+
+    // the evaluator passes its encoded bid only when the circuit runs
+    let bid = Bid {
+        amount: 150,
+        bidder: 2,
+    };
+    let winner = winner
+        .reveal_with_evaluator_inputs(&builder, &bid.encode())
+        .unwrap();
+    assert_eq!(winner.into_inner(), bid);
+
+    // without them the executor rejects the circuit
+    assert!(theirs.reveal(&builder).is_err());
+}
+
+#[test]
+#[should_panic(expected = "garbler inputs must be added before evaluator inputs")]
+fn test_garbler_input_after_evaluator_input() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let _: Encoded<GarbledUint8> = Encoded::evaluator_input(&mut builder);
+    Plain::new(GarbledUint8::from(1_u8)).encode(&mut builder);
+}
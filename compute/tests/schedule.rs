@@ -0,0 +1,72 @@
+use compute::prelude::*;
+
+fn multiply_circuit() -> (Circuit, Vec<bool>) {
+    let a: GarbledUint8 = 13_u8.into();
+    let b: GarbledUint8 = 11_u8.into();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let product = builder.mul(&a, &b);
+    let output = builder.add(&product, &a);
+
+    (builder.compile(&output), builder.inputs().clone())
+}
+
+#[test]
+fn test_circuit_stats() {
+    let (circuit, _) = multiply_circuit();
+    let stats = circuit_stats(&circuit).unwrap();
+
+    assert_eq!(stats.gates, circuit.gates().len());
+    assert_eq!(stats.and_gates, circuit.and_gates());
+    assert_eq!(stats.inputs, 16);
+    assert_eq!(stats.outputs, 8);
+    assert!(stats.and_depth > 0);
+    assert_eq!(stats.and_levels.len(), stats.and_depth);
+    assert_eq!(stats.and_levels.iter().sum::<usize>(), stats.and_gates);
+}
+
+#[test]
+fn test_schedule_by_depth_preserves_semantics() {
+    let (circuit, inputs) = multiply_circuit();
+    let before = circuit_stats(&circuit).unwrap();
+    let expected = get_executor().execute(&circuit, &inputs, &[]).unwrap();
+
+    let scheduled = schedule_by_depth(circuit).unwrap();
+    let after = circuit_stats(&scheduled).unwrap();
+
+    // same output, same size and depth
+    let result = get_executor().execute(&scheduled, &inputs, &[]).unwrap();
+    assert_eq!(result, expected);
+    let result: u8 = GarbledUint8::new(result).into();
+    assert_eq!(result, 13_u8.wrapping_mul(11).wrapping_add(13));
+    assert_eq!(after, before);
+
+    // AND gates are now grouped by level: their depths never decrease in gate order
+    let mut and_depths = Vec::new();
+    let mut depths: Vec<usize> = Vec::new();
+    for gate in scheduled.gates() {
+        let depth = match *gate {
+            Gate::InContrib | Gate::InEval => 0,
+            Gate::Xor(a, b) => depths[a as usize].max(depths[b as usize]),
+            Gate::And(a, b) => depths[a as usize].max(depths[b as usize]) + 1,
+            Gate::Not(a) => depths[a as usize],
+        };
+        if let Gate::And(_, _) = gate {
+            and_depths.push(depth);
+        }
+        depths.push(depth);
+    }
+    assert!(and_depths.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_forward_references_are_rejected() {
+    // gate 1 reads gate 2, which is not defined yet
+    let gates = vec![Gate::InContrib, Gate::And(0, 2), Gate::Not(0)];
+    let circuit = Circuit::new(gates, vec![1]);
+
+    assert!(circuit_stats(&circuit).is_err());
+    assert!(schedule_by_depth(circuit).is_err());
+}
@@ -0,0 +1,32 @@
+use compute::prelude::*;
+
+fn table() -> Vec<GarbledUint8> {
+    [17_u8, 42, 200, 255].iter().map(|&v| v.into()).collect()
+}
+
+#[test]
+fn test_select_one_hot_each_element() {
+    let values = table();
+    for (i, expected) in [17_u8, 42, 200, 255].into_iter().enumerate() {
+        let mask: GarbledUint4 = GarbledUint::new((0..4).map(|bit| bit == i).collect());
+        let result: u8 = GarbledUint::select_one_hot(&mask, &values).into();
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_select_one_hot_empty_mask_is_zero() {
+    let values = table();
+    let mask: GarbledUint4 = GarbledUint::new(vec![false; 4]);
+    let result: u8 = GarbledUint::select_one_hot(&mask, &values).into();
+    assert_eq!(result, 0);
+}
+
+#[test]
+fn test_select_one_hot_multiple_bits_ors_values() {
+    // not a valid one-hot mask; documents the OR-of-selected behavior
+    let values = table();
+    let mask: GarbledUint4 = GarbledUint::new(vec![true, true, false, false]);
+    let result: u8 = GarbledUint::select_one_hot(&mask, &values).into();
+    assert_eq!(result, 17 | 42);
+}
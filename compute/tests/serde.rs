@@ -0,0 +1,58 @@
+#![cfg(feature = "serde")]
+
+use compute::prelude::*;
+
+#[test]
+fn test_serde_uint_round_trip() {
+    let value: GarbledUint32 = 0xdead_beef_u32.into();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "[239,190,173,222]");
+
+    let decoded: GarbledUint32 = serde_json::from_str(&json).unwrap();
+    assert_eq!(u32::from(decoded), 0xdead_beef);
+}
+
+#[test]
+fn test_serde_int_round_trip() {
+    let value: GarbledInt16 = (-2_i16).into();
+    let json = serde_json::to_string(&value).unwrap();
+    let decoded: GarbledInt16 = serde_json::from_str(&json).unwrap();
+    assert_eq!(i16::from(decoded), -2);
+}
+
+#[test]
+fn test_serde_rejects_wrong_width() {
+    let result: Result<GarbledUint32, _> = serde_json::from_str("[1,2,3]");
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.contains("expected 4 bytes but the slice holds 3"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_serde_compiled_circuit_in_message() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Job {
+        program: CompiledCircuit,
+        threshold: GarbledUint8,
+    }
+
+    #[encrypted(compile)]
+    fn double(a: u8) -> u8 {
+        a + a
+    }
+
+    let job = Job {
+        program: CompiledCircuit::from(&double(21_u8)),
+        threshold: 40_u8.into(),
+    };
+    let json = serde_json::to_string(&job).unwrap();
+    let job: Job = serde_json::from_str(&json).unwrap();
+
+    let (circuit, inputs) = job.program.into();
+    let result = get_executor().execute(&circuit, &inputs, &[]).unwrap();
+    assert_eq!(u8::from(GarbledUint8::new(result)), 42);
+    assert_eq!(u8::from(job.threshold), 40);
+}
@@ -0,0 +1,96 @@
+use compute::prelude::*;
+
+#[test]
+fn test_small_uint_masks_to_width() {
+    let a = SmallGarbledUint::<4>::from_word(0xff);
+    assert_eq!(a.word(), 0xf);
+    assert_eq!(a.len(), 4);
+
+    let b = SmallGarbledUint128::from_word(u128::MAX);
+    assert_eq!(b.word(), u128::MAX);
+    assert_eq!(SmallGarbledUint8::zero().word(), 0);
+    assert_eq!(SmallGarbledUint8::one().word(), 1);
+}
+
+#[test]
+fn test_small_uint_bits() {
+    let a: SmallGarbledUint8 = 0b1010_0101_u8.into();
+    assert!(a.test_bit(0));
+    assert!(!a.test_bit(1));
+    assert!(a.test_bit(7));
+    assert_eq!(
+        a.iter_bits().collect::<Vec<_>>(),
+        vec![true, false, true, false, false, true, false, true]
+    );
+
+    let b = a.set_bit(1, true).set_bit(7, false);
+    assert_eq!(u8::from(b), 0b0010_0111);
+    // `a` is `Copy` and unchanged
+    assert_eq!(u8::from(a), 0b1010_0101);
+}
+
+#[test]
+#[should_panic(expected = "bit index 8 out of range for 8 bits")]
+fn test_small_uint_bit_out_of_range() {
+    SmallGarbledUint8::zero().test_bit(8);
+}
+
+#[test]
+fn test_small_uint_converts_to_and_from_garbled_uint() {
+    let value = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128;
+    let small: SmallGarbledUint128 = value.into();
+    let uint: GarbledUint128 = small.into();
+    assert_eq!(u128::from(uint.clone()), value);
+    assert_eq!(SmallGarbledUint128::from(&uint), small);
+
+    let small: SmallGarbledUint<12> = 0xabc_u16.into();
+    assert_eq!(u16::from(small.to_uint()), 0xabc);
+    assert_eq!(
+        SmallGarbledUint::<12>::from(GarbledUint::<12>::from(0xabc_u16)),
+        small
+    );
+    assert_eq!(small.to_string(), "2748");
+}
+
+#[test]
+fn test_small_uint_as_circuit_input() {
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input_encoded(&SmallGarbledUint32::from(1000_u32));
+    let b = builder.input_encoded(&SmallGarbledUint32::from(234_u32));
+    let sum = builder.add(&a, &b);
+
+    let result: SmallGarbledUint32 = builder.compile_and_decode(&sum).unwrap();
+    assert_eq!(u32::from(result), 1234);
+}
+
+#[test]
+fn test_small_uint_operators() {
+    let a = SmallGarbledUint16::from(1000_u16);
+    let b = SmallGarbledUint16::from(7_u16);
+    assert_eq!(u16::from(a + b), 1007);
+    assert_eq!(u16::from(b - a), 7_u16.wrapping_sub(1000));
+    assert_eq!(u16::from(a * b), 7000);
+    assert_eq!(u16::from(a / b), 142);
+    assert_eq!(u16::from(a % b), 6);
+    assert_eq!(u16::from(a & b), 1000 & 7);
+    assert_eq!(u16::from(a | b), 1000 | 7);
+    assert_eq!(u16::from(a ^ b), 1000 ^ 7);
+    assert_eq!(u16::from(!a), !1000);
+    assert_eq!(u16::from(a << 3), 8000);
+    assert_eq!(u16::from(a >> 3), 125);
+    assert_eq!(u16::from(a << 16), 0);
+
+    let mut c = a;
+    c += b;
+    c <<= 1;
+    assert_eq!(u16::from(c), 2014);
+}
+
+#[test]
+fn test_small_uint_ordering() {
+    let a = SmallGarbledUint8::from(200_u8);
+    let b = SmallGarbledUint8::from(13_u8);
+    assert!(a > b);
+    assert_eq!(a.max(b), a);
+    assert_eq!(SmallGarbledUint8::default(), SmallGarbledUint8::zero());
+}
@@ -0,0 +1,60 @@
+use compute::prelude::*;
+
+fn sort_u8(values: &[u8]) -> Vec<u8> {
+    let garbled: Vec<GarbledUint8> = values.iter().map(|&value| value.into()).collect();
+    GarbledUint8::bitonic_sort(&garbled)
+        .into_iter()
+        .map(|value| value.into())
+        .collect()
+}
+
+#[test]
+fn test_bitonic_sort_4() {
+    for values in [
+        [3_u8, 1, 2, 0],
+        [255, 0, 255, 7],
+        [9, 9, 9, 9],
+        [0, 1, 2, 3],
+    ] {
+        let mut expected = values.to_vec();
+        expected.sort();
+        assert_eq!(sort_u8(&values), expected);
+    }
+}
+
+#[test]
+fn test_bitonic_sort_8() {
+    for values in [
+        [200_u8, 13, 77, 0, 255, 42, 42, 1],
+        [8, 7, 6, 5, 4, 3, 2, 1],
+        [1, 0, 1, 0, 1, 0, 1, 0],
+    ] {
+        let mut expected = values.to_vec();
+        expected.sort();
+        assert_eq!(sort_u8(&values), expected);
+    }
+}
+
+#[test]
+fn test_bitonic_sort_pads_to_power_of_two() {
+    // sentinels equal to the maximum value must not displace real elements
+    let values = [255_u8, 4, 255, 0, 17];
+    let mut expected = values.to_vec();
+    expected.sort();
+    assert_eq!(sort_u8(&values), expected);
+
+    assert_eq!(sort_u8(&[42]), vec![42]);
+    assert!(sort_u8(&[]).is_empty());
+}
+
+#[test]
+fn test_conditional_swap() {
+    let a: GarbledUint8 = 10_u8.into();
+    let b: GarbledUint8 = 20_u8.into();
+
+    let (x, y) = GarbledUint8::conditional_swap(&true.into(), &a, &b);
+    assert_eq!((u8::from(x), u8::from(y)), (20, 10));
+
+    let (x, y) = GarbledUint8::conditional_swap(&false.into(), &a, &b);
+    assert_eq!((u8::from(x), u8::from(y)), (10, 20));
+}
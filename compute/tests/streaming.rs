@@ -0,0 +1,69 @@
+use compute::prelude::*;
+
+fn multiplier<const N: usize>(a: u128, b: u128) -> (Circuit, Vec<bool>) {
+    let a: GarbledUint<N> = a.into();
+    let b: GarbledUint<N> = b.into();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let product = builder.mul(&a, &b);
+    (builder.compile(&product), builder.inputs().clone())
+}
+
+#[test]
+fn test_streaming_matches_simulator() {
+    let (circuit, inputs) = multiplier::<16>(51_234, 777);
+    let streamed = evaluate_streaming(&circuit, &inputs, &[]).unwrap();
+    let simulated = get_executor().execute(&circuit, &inputs, &[]).unwrap();
+
+    assert_eq!(streamed.output, simulated);
+    let product: u16 = GarbledUint16::new(streamed.output).into();
+    assert_eq!(product, 51_234_u16.wrapping_mul(777));
+}
+
+#[test]
+fn test_streaming_wide_multiplier_memory() {
+    let a = 0x0123_4567_89ab_cdef_u128;
+    let b = 0xfedc_ba98_7654_3210_u128;
+    let (circuit, inputs) = multiplier::<64>(a, b);
+
+    let streamed = evaluate_streaming(&circuit, &inputs, &[]).unwrap();
+    let product: u64 = GarbledUint64::new(streamed.output.clone()).into();
+    assert_eq!(product, (a as u64).wrapping_mul(b as u64));
+
+    let gates = circuit.gates().len();
+    assert!(
+        streamed.peak_live_wires * 4 < gates,
+        "peak {} of {} gates",
+        streamed.peak_live_wires,
+        gates
+    );
+}
+
+#[test]
+fn test_streaming_peak_live_wires() {
+    // ((a ^ b) ^ c) ^ d: all four inputs are held before the first XOR frees two slots, and
+    // every later result reuses one of them
+    let gates = vec![
+        Gate::InContrib,
+        Gate::InContrib,
+        Gate::InContrib,
+        Gate::InContrib,
+        Gate::Xor(0, 1),
+        Gate::Xor(4, 2),
+        Gate::Xor(5, 3),
+        Gate::Xor(6, 6),
+    ];
+    let circuit = Circuit::new(gates, vec![6]);
+
+    let streamed = evaluate_streaming(&circuit, &[true, false, true, true], &[]).unwrap();
+    assert_eq!(streamed.output, vec![true]);
+    assert_eq!(streamed.peak_live_wires, 4);
+}
+
+#[test]
+fn test_streaming_missing_input() {
+    let (circuit, inputs) = multiplier::<8>(3, 4);
+    assert!(evaluate_streaming(&circuit, &inputs[..4], &[]).is_err());
+}
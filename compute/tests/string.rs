@@ -0,0 +1,64 @@
+use compute::prelude::*;
+
+type Name = GarbledString<16>;
+
+fn check(flag: GarbledBit) -> bool {
+    flag.bits()[0]
+}
+
+#[test]
+fn test_string_round_trip() {
+    let name = Name::new("alice@example");
+    assert_eq!(String::from(name), "alice@example");
+    assert_eq!(String::from(Name::new("")), "");
+    assert_eq!(String::from(Name::new("grüße")), "grüße");
+}
+
+#[test]
+fn test_string_equals() {
+    let alice = Name::new("alice");
+    assert!(check(alice.equals(&Name::new("alice"))));
+    assert!(!check(alice.equals(&Name::new("alicf"))));
+    assert!(!check(alice.equals(&Name::new("alice2"))));
+    assert!(!check(alice.equals(&Name::new("alic"))));
+    assert!(!check(alice.equals(&Name::new("Alice"))));
+    assert!(check(Name::new("").equals(&Name::new(""))));
+}
+
+#[test]
+fn test_string_starts_with() {
+    let email = Name::new("bob@example.org");
+    assert!(check(email.starts_with(&Name::new("bob@"))));
+    assert!(check(email.starts_with(&Name::new(""))));
+    assert!(check(email.starts_with(&email.clone())));
+    assert!(!check(email.starts_with(&Name::new("bob@x"))));
+    assert!(!check(email.starts_with(&Name::new("ob"))));
+    assert!(!check(Name::new("bo").starts_with(&Name::new("bob"))));
+}
+
+#[test]
+fn test_string_eq_ignore_ascii_case() {
+    let name = Name::new("Alice.Smith");
+    assert!(check(name.eq_ignore_ascii_case(&Name::new("alice.smith"))));
+    assert!(check(name.eq_ignore_ascii_case(&Name::new("ALICE.SMITH"))));
+    assert!(!check(name.eq_ignore_ascii_case(&Name::new("alice_smith"))));
+    assert!(!check(name.eq_ignore_ascii_case(&Name::new("alice.smit"))));
+
+    // '@' and '[' sit next to 'A' and 'Z' and must not fold onto '`' and '{'
+    assert!(!check(Name::new("@").eq_ignore_ascii_case(&Name::new("`"))));
+    assert!(!check(Name::new("[").eq_ignore_ascii_case(&Name::new("{"))));
+    // non-ASCII bytes are compared exactly
+    assert!(!check(Name::new("É").eq_ignore_ascii_case(&Name::new("é"))));
+}
+
+#[test]
+#[should_panic(expected = "do not fit")]
+fn test_string_too_long() {
+    GarbledString::<4>::new("hello");
+}
+
+#[test]
+#[should_panic(expected = "NUL")]
+fn test_string_rejects_nul() {
+    Name::new("a\0b");
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "debug-trace")]
+
+use compute::prelude::*;
+
+#[test]
+fn test_adder_transcript() {
+    let a: GarbledUint8 = 3_u8.into();
+    let b: GarbledUint8 = 1_u8.into();
+
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&a);
+    let b = builder.input(&b);
+    let first_gate = builder.len();
+    let sum = builder.add(&a, &b);
+    let adder_gates = first_gate..builder.len();
+
+    let transcript = builder.compile_and_trace(&sum).unwrap();
+    let result: u8 = GarbledUint8::new(transcript.output.clone()).into();
+    assert_eq!(result, 4);
+
+    assert_eq!(transcript.wires.len(), builder.len() as usize);
+    assert!(transcript.wire(a[0]) && transcript.wire(a[1]) && transcript.wire(b[0]));
+    assert!(!transcript.wire(b[1]));
+    assert!(!transcript.wire(sum[0]) && !transcript.wire(sum[1]) && transcript.wire(sum[2]));
+
+    // 3 + 1 carries out of bits 0 and 1 into bit 2, so exactly two of the adder's AND gates
+    // are set: a[0] & b[0], and the carry into bit 1 propagated through a[1] ^ b[1]
+    let circuit = builder.compile(&sum);
+    let carries: Vec<(u32, u32, u32)> = adder_gates
+        .filter_map(|wire| match circuit.gates()[wire as usize] {
+            Gate::And(x, y) if transcript.wire(wire) => Some((wire, x, y)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(carries.len(), 2);
+    let (carry, x, y) = carries[0];
+    assert_eq!((x, y), (a[0], b[0]));
+    assert_eq!(carries[1].2, carry);
+
+    // output wires in the transcript agree with the garbled execution
+    for (wire, bit) in sum.iter().zip(&transcript.output) {
+        assert_eq!(transcript.wire(*wire), *bit);
+    }
+
+    let dump = transcript.dump(&circuit);
+    assert_eq!(dump.lines().count(), circuit.gates().len());
+    assert!(dump.contains(&format!("{}: And({}, {}) = 1", carry, a[0], b[0])));
+}
+
+#[test]
+fn test_transcript_missing_input() {
+    let a: GarbledUint8 = 3_u8.into();
+    let mut builder = WRK17CircuitBuilder::default();
+    let a = builder.input(&a);
+    let circuit = builder.compile(&a);
+
+    assert!(simulate_with_transcript(&circuit, &[true; 4], &[]).is_err());
+}
@@ -0,0 +1,67 @@
+use compute::prelude::*;
+
+#[test]
+fn test_typed_arithmetic() {
+    let mut builder = TypedCircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(23_u8));
+    let b = builder.input(&GarbledUint8::from(5_u8));
+
+    let product = builder.mul(&a, &b);
+    let quotient = builder.div(&product, &b);
+    let result = builder.xor(&quotient, &a);
+
+    let result: u8 = builder.compile_and_execute(&result).unwrap().into();
+    assert_eq!(result, 0);
+}
+
+#[test]
+fn test_typed_extend_and_truncate() {
+    let mut builder = TypedCircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(250_u8));
+    let b = builder.input(&GarbledUint8::from(10_u8));
+
+    // widening first keeps the carry that an 8-bit sum would drop
+    let wide_a = builder.extend::<8, 16>(&a);
+    let wide_b = builder.extend::<8, 16>(&b);
+    let sum = builder.add(&wide_a, &wide_b);
+    let result: u16 = builder.compile_and_execute(&sum).unwrap().into();
+    assert_eq!(result, 260);
+
+    let low = builder.truncate::<16, 8>(&sum);
+    let result: u8 = builder.compile_and_execute(&low).unwrap().into();
+    assert_eq!(result, 4);
+}
+
+#[test]
+fn test_typed_comparison_and_mux() {
+    let mut builder = TypedCircuitBuilder::default();
+    let a = builder.input(&GarbledUint16::from(300_u16));
+    let b = builder.input(&GarbledUint16::from(200_u16));
+
+    let a_lt_b = builder.lt(&a, &b);
+    let smaller = builder.mux(&a_lt_b, &a, &b);
+    let result: u16 = builder.compile_and_execute(&smaller).unwrap().into();
+    assert_eq!(result, 200);
+
+    let flag: bool = builder.compile_and_execute(&a_lt_b).unwrap().into();
+    assert!(!flag);
+}
+
+#[test]
+fn test_typed_input_is_padded() {
+    // `zero()` stores a single bit, but the handle still covers all 8
+    let mut builder = TypedCircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::zero());
+    assert_eq!(a.indices().len(), 8);
+
+    let one = builder.constant(&GarbledUint8::one());
+    let sum = builder.add(&a, &one);
+    let result: u8 = builder.compile_and_execute(&sum).unwrap().into();
+    assert_eq!(result, 1);
+}
+
+#[test]
+#[should_panic(expected = "Wires<8> needs exactly 8 wires")]
+fn test_wires_from_indices_checks_width() {
+    Wires::<8>::from_indices(GateIndexVec::new(vec![0, 1, 2]));
+}
@@ -0,0 +1,9 @@
+use compute::prelude::*;
+
+#[derive(GarbledEncode)]
+struct Account {
+    balance: u64,
+    owner: String,
+}
+
+fn main() {}
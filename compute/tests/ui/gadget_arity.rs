@@ -0,0 +1,10 @@
+use compute::prelude::*;
+
+#[encrypted(execute)]
+fn larger(a: u8, b: u8) -> u8 {
+    a.max(b, a)
+}
+
+fn main() {
+    larger(1_u8, 2_u8);
+}
@@ -0,0 +1,14 @@
+use compute::prelude::*;
+
+#[encrypted(execute)]
+fn count_down(a: u8, b: u8) -> u8 {
+    let mut total = a;
+    while total > b {
+        total = total - b;
+    }
+    total
+}
+
+fn main() {
+    count_down(9_u8, 2_u8);
+}
@@ -0,0 +1,8 @@
+use compute::prelude::*;
+
+fn main() {
+    let mut builder = TypedCircuitBuilder::default();
+    let a = builder.input(&GarbledUint8::from(200_u8));
+    let b = builder.input(&GarbledUint16::from(1000_u16));
+    builder.add(&a, &b);
+}
@@ -0,0 +1,10 @@
+use compute::prelude::*;
+
+#[encrypted(execute)]
+fn magnitude(a: u8) -> u8 {
+    a.abs()
+}
+
+fn main() {
+    magnitude(1_u8);
+}
@@ -0,0 +1,10 @@
+use compute::prelude::*;
+
+#[encrypted(execute)]
+fn power(a: u8, b: u8) -> u8 {
+    a ** b
+}
+
+fn main() {
+    power(2_u8, 3_u8);
+}
@@ -0,0 +1,10 @@
+use compute::prelude::*;
+
+#[encrypted(execute)]
+fn early(a: u8, b: u8) -> u8 {
+    return a + b;
+}
+
+fn main() {
+    early(1_u8, 2_u8);
+}
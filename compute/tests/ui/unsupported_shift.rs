@@ -0,0 +1,11 @@
+use compute::prelude::*;
+
+#[encrypted(execute)]
+fn shift(a: u16, b: u16) -> u16 {
+    let c = a + b;
+    c << b
+}
+
+fn main() {
+    shift(1_u16, 2_u16);
+}
@@ -1,4 +1,8 @@
-use compute::uint::{GarbledUint128, GarbledUint16, GarbledUint32, GarbledUint64, GarbledUint8};
+use compute::error::LengthError;
+use compute::uint::{
+    GarbledUint, GarbledUint128, GarbledUint16, GarbledUint256, GarbledUint32, GarbledUint512,
+    GarbledUint64, GarbledUint8,
+};
 
 #[test]
 fn test_display() {
@@ -52,3 +56,550 @@ fn test_from_u128() {
     let value: u128 = a.into();
     assert_eq!(value, 12297829382473034410);
 }
+
+#[test]
+fn test_binary_format() {
+    let a: GarbledUint<4> = 5u8.into();
+    assert_eq!(format!("{:04b}", a), "0101");
+    assert_eq!(format!("{:b}", a), "101");
+    assert_eq!(format!("{:#b}", a), "0b101");
+
+    let b: GarbledUint8 = 170u8.into();
+    assert_eq!(format!("{:08b}", b), format!("{:08b}", 170u8));
+}
+
+#[test]
+fn test_hex_format() {
+    let a: GarbledUint8 = 10u8.into();
+    assert_eq!(format!("{:02x}", a), "0a");
+    assert_eq!(format!("{:02X}", a), "0A");
+    assert_eq!(format!("{:#x}", a), "0xa");
+
+    let b: GarbledUint<12> = 0xabcu16.into();
+    assert_eq!(format!("{:x}", b), "abc");
+
+    let c: GarbledUint32 = 0u32.into();
+    assert_eq!(format!("{:x}", c), "0");
+}
+
+#[test]
+fn test_iter_bits() {
+    let a: GarbledUint8 = 0b1010_0001u8.into();
+    let bits: Vec<bool> = a.iter_bits().collect();
+    assert_eq!(
+        bits,
+        vec![true, false, false, false, false, true, false, true]
+    );
+}
+
+#[test]
+fn test_from_iterator_round_trip() {
+    let a: GarbledUint16 = 43707u16.into();
+    let b: GarbledUint16 = a.iter_bits().collect();
+    let value: u16 = b.into();
+    assert_eq!(value, 43707);
+
+    // Build a mask with only the upper nibble set
+    let mask: GarbledUint8 = (0..8).map(|i| i >= 4).collect();
+    let value: u8 = mask.into();
+    assert_eq!(value, 0xf0);
+}
+
+#[test]
+#[should_panic(expected = "GarbledUint<8> must be built from exactly 8 bits, got 4")]
+fn test_from_iterator_wrong_length() {
+    let _: GarbledUint8 = [true; 4].into_iter().collect();
+}
+
+#[test]
+fn test_index() {
+    let a: GarbledUint8 = 0b0000_1001u8.into();
+    assert!(a[0]);
+    assert!(!a[1]);
+    assert!(a[3]);
+    assert!(!a[7]);
+}
+
+#[test]
+#[should_panic(expected = "bit index 8 out of range for GarbledUint<8>")]
+fn test_index_out_of_range() {
+    let a: GarbledUint8 = 1u8.into();
+    let _ = a[8];
+}
+
+#[test]
+fn test_partial_eq_and_hash() {
+    use std::collections::HashSet;
+
+    let a: GarbledUint8 = 42u8.into();
+    let b: GarbledUint8 = 42u8.into();
+    let c: GarbledUint8 = 43u8.into();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a, &b);
+
+    let set: HashSet<GarbledUint8> = [a, b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let a: GarbledUint128 = 12297829382473034410u128.into();
+    let bytes = a.to_bytes();
+    assert_eq!(bytes, 12297829382473034410u128.to_le_bytes());
+
+    let b = GarbledUint128::from_bytes(&bytes).unwrap();
+    let value: u128 = b.into();
+    assert_eq!(value, 12297829382473034410);
+}
+
+#[test]
+fn test_bytes_round_trip_unaligned() {
+    let a: GarbledUint<12> = 0xabcu16.into();
+    let bytes = a.to_bytes();
+    assert_eq!(bytes, vec![0xbc, 0x0a]);
+
+    let b = GarbledUint::<12>::from_bytes(&bytes).unwrap();
+    let value: u16 = b.into();
+    assert_eq!(value, 0xabc);
+}
+
+#[test]
+fn test_from_bytes_too_short() {
+    let result = GarbledUint32::from_bytes(&[0xff, 0xff, 0xff]);
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_biguint_round_trip_256() {
+    use num_bigint::BigUint;
+
+    // 2^255 + 2^128 + 12345
+    let value = (BigUint::from(1u8) << 255) + (BigUint::from(1u8) << 128) + BigUint::from(12345u32);
+    let garbled = GarbledUint256::from_biguint(&value).unwrap();
+    assert_eq!(garbled.to_biguint(), value);
+
+    // Arithmetic on the full width wraps modulo 2^256
+    let other = GarbledUint256::from_biguint(&(BigUint::from(1u8) << 255)).unwrap();
+    let sum = (&garbled + &other).to_biguint();
+    assert_eq!(sum, (BigUint::from(1u8) << 128) + BigUint::from(12345u32));
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_biguint_too_large() {
+    use num_bigint::BigUint;
+
+    let value = BigUint::from(1u8) << 256;
+    assert!(GarbledUint256::from_biguint(&value).is_err());
+
+    let value = BigUint::from(256u16);
+    assert!(GarbledUint8::from_biguint(&value).is_err());
+    assert!(GarbledUint8::from_biguint(&BigUint::from(255u8)).is_ok());
+}
+
+#[test]
+fn test_u64_limbs_round_trip_256() {
+    let limbs = [0x0123_4567_89ab_cdef, 0, u64::MAX, 1 << 63];
+    let garbled: GarbledUint256 = limbs.into();
+    assert_eq!(<[u64; 4]>::from(garbled.clone()), limbs);
+    assert!(bool::from(garbled.test_bit(255)));
+    assert!(!bool::from(garbled.test_bit(64)));
+
+    // 2^256 - 1 + 1 wraps to zero
+    let max: GarbledUint256 = [u64::MAX; 4].into();
+    let one: GarbledUint256 = [1_u64, 0, 0, 0].into();
+    assert_eq!(<[u64; 4]>::from(&max + &one), [0; 4]);
+}
+
+#[test]
+fn test_u8_array_round_trip_512() {
+    let mut bytes = [0u8; 64];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = i as u8 * 3;
+    }
+    let garbled: GarbledUint512 = bytes.into();
+    assert_eq!(<[u8; 64]>::from(garbled.clone()), bytes);
+
+    // the first byte is least significant, like `to_bytes`
+    assert_eq!(garbled.to_bytes(), bytes.to_vec());
+    let low: [u64; 8] = garbled.into();
+    assert_eq!(low[0], u64::from_le_bytes(bytes[..8].try_into().unwrap()));
+}
+
+#[test]
+fn test_limbs_into_wider_array_zero_fill() {
+    let garbled: GarbledUint<96> = [u64::MAX, 0xffff_ffff].into();
+    assert_eq!(<[u64; 4]>::from(garbled), [u64::MAX, 0xffff_ffff, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Uint<N> can only support up to 256 bits for [u64; 4]")]
+fn test_limbs_too_narrow() {
+    let _: GarbledUint512 = [0u64; 4].into();
+}
+
+#[test]
+fn test_reverse_bits() {
+    let a: GarbledUint8 = 0b0000_0001_u8.into();
+    let result: u8 = a.reverse_bits().into();
+    assert_eq!(result, 0b1000_0000);
+
+    for value in [0_u8, 1, 0x0f, 0xa5, 0xff, 0x36] {
+        let a: GarbledUint8 = value.into();
+        let result: u8 = a.reverse_bits().into();
+        assert_eq!(result, value.reverse_bits());
+    }
+
+    for value in [0_u16, 1, 0x1234, 0x8001, 0xfff0] {
+        let a: GarbledUint16 = value.into();
+        let result: u16 = a.reverse_bits().into();
+        assert_eq!(result, value.reverse_bits());
+    }
+
+    for value in [0_u32, 1, 0xdead_beef, 0x8000_0000, 0x0102_0304] {
+        let a: GarbledUint32 = value.into();
+        let result: u32 = a.reverse_bits().into();
+        assert_eq!(result, value.reverse_bits());
+    }
+}
+
+#[test]
+fn test_reverse_bits_short_vector() {
+    // one() stores a single bit, which still lands in the top position of the full width
+    let result: u8 = GarbledUint8::one().reverse_bits().into();
+    assert_eq!(result, 0b1000_0000);
+}
+
+#[test]
+fn test_swap_bytes() {
+    for value in [0_u16, 1, 0x1234, 0xff00, 0xabcd] {
+        let a: GarbledUint16 = value.into();
+        let result: u16 = a.swap_bytes().into();
+        assert_eq!(result, value.swap_bytes());
+    }
+
+    for value in [0_u32, 1, 0xdead_beef, 0x0102_0304, 0xff00_00ff] {
+        let a: GarbledUint32 = value.into();
+        let result: u32 = a.swap_bytes().into();
+        assert_eq!(result, value.swap_bytes());
+    }
+
+    for value in [0_u64, 1, 0x0102_0304_0506_0708, u64::MAX - 0xff] {
+        let a: GarbledUint64 = value.into();
+        let result: u64 = a.swap_bytes().into();
+        assert_eq!(result, value.swap_bytes());
+    }
+
+    // a single byte is unchanged
+    let a: GarbledUint8 = 0xa5_u8.into();
+    let result: u8 = a.swap_bytes().into();
+    assert_eq!(result, 0xa5);
+}
+
+#[cfg(all(feature = "rand", not(target_arch = "wasm32")))]
+#[test]
+fn test_random_seeded() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let first = GarbledUint64::random(&mut rng);
+    let second = GarbledUint64::random(&mut rng);
+    assert_eq!(first.len(), 64);
+    assert_eq!(second.len(), 64);
+
+    let first: u64 = first.into();
+    let second: u64 = second.into();
+    assert_ne!(first, second);
+
+    // the same seed reproduces the same sequence
+    let mut rng = StdRng::seed_from_u64(42);
+    assert_eq!(u64::from(GarbledUint64::random(&mut rng)), first);
+    assert_eq!(u64::from(GarbledUint64::random(&mut rng)), second);
+}
+
+#[test]
+fn test_from_bits_endianness() {
+    let a = GarbledUint::<4>::from_bits_be(&[true, false, false, false]);
+    assert_eq!(u8::from(a), 8);
+
+    let b = GarbledUint::<4>::from_bits_le(&[true, false, false, false]);
+    assert_eq!(u8::from(b.clone()), 1);
+
+    // `new` takes the same little-endian order as `from_bits_le`
+    assert_eq!(GarbledUint::<4>::new(vec![true, false, false, false]), b);
+}
+
+#[test]
+fn test_to_bits_roundtrip() {
+    let a: GarbledUint8 = 0b1100_0101_u8.into();
+    let le = a.to_bits_le();
+    let be = a.to_bits_be();
+    assert_eq!(le, vec![true, false, true, false, false, false, true, true]);
+    assert_eq!(be, vec![true, true, false, false, false, true, false, true]);
+    assert_eq!(GarbledUint8::from_bits_le(&le), a);
+    assert_eq!(GarbledUint8::from_bits_be(&be), a);
+
+    // short bit vectors are padded to the full width
+    assert_eq!(GarbledUint8::zero().to_bits_be(), vec![false; 8]);
+}
+
+#[test]
+fn test_bits_accessors() {
+    let a: GarbledUint8 = 0b1100_0101_u8.into();
+    let expected = vec![true, false, true, false, false, false, true, true];
+    assert_eq!(*a.bits(), *expected);
+    assert_eq!(a.clone().into_bits(), expected);
+    assert_eq!(GarbledUint8::from_bits(expected), a);
+
+    // the stored bits are not padded, unlike `to_bits_le`
+    assert_eq!(*GarbledUint8::zero().bits(), [false]);
+
+    // shifts read and write through the accessors with unchanged results
+    let shifted: u8 = (a.clone() << 2).into();
+    assert_eq!(shifted, 0b0001_0100);
+    let mut b = a;
+    b >>= 3;
+    assert_eq!(
+        b.into_bits(),
+        vec![false, false, false, true, true, false, false, false]
+    );
+}
+
+#[test]
+#[should_panic(expected = "needs exactly 4 bits")]
+fn test_from_bits_wrong_length() {
+    GarbledUint::<4>::from_bits_le(&[true, false]);
+}
+
+#[test]
+fn test_bit_access() {
+    let flags: GarbledUint8 = 0b0100_0001_u8.into();
+    assert!(bool::from(flags.test_bit(0)));
+    assert!(!bool::from(flags.test_bit(1)));
+    assert!(bool::from(flags.test_bit(6)));
+    assert!(!bool::from(flags.test_bit(7)));
+
+    let flags = flags.with_bit_set(7).with_bit_cleared(0);
+    assert_eq!(u8::from(flags.clone()), 0b1100_0000);
+
+    // setting an already set bit, or clearing a clear one, changes nothing
+    let flags = flags.with_bit_set(6).with_bit_cleared(1);
+    assert_eq!(u8::from(flags.clone()), 0b1100_0000);
+
+    let flags = flags.set_bit(3, &true.into()).set_bit(7, &false.into());
+    assert_eq!(u8::from(flags), 0b0100_1000);
+
+    // short bit vectors read as zero above their length
+    assert!(!bool::from(GarbledUint8::one().test_bit(5)));
+    assert_eq!(u8::from(GarbledUint8::zero().with_bit_set(4)), 16);
+}
+
+#[test]
+#[should_panic(expected = "bit index 8 out of range for 8 bits")]
+fn test_bit_access_out_of_range() {
+    let value: GarbledUint8 = 1_u8.into();
+    value.with_bit_set(8);
+}
+
+#[test]
+fn test_try_from_checks_the_value() {
+    use compute::error::ConversionError;
+
+    // a u16 that fits in 8 bits converts, while `From<u16>` keeps the low 8 bits of any value
+    let value = GarbledUint8::try_from_u16(200).unwrap();
+    assert_eq!(u8::from(value), 200);
+    let value = GarbledUint8::try_from(&200_u16).unwrap();
+    assert_eq!(u8::from(value), 200);
+    assert!(GarbledUint8::try_from(&300_u16).is_err());
+
+    assert_eq!(
+        GarbledUint8::try_from_u16(256).unwrap_err(),
+        ConversionError {
+            required: 9,
+            available: 8
+        }
+    );
+    assert!(GarbledUint::<12>::try_from_u128(0xfff).is_ok());
+    assert!(GarbledUint::<12>::try_from_u128(0x1000).is_err());
+}
+
+#[test]
+fn test_try_into_checks_the_value() {
+    let small: GarbledUint128 = 255_u128.into();
+    assert_eq!(small.try_into_u8(), Ok(255));
+    assert_eq!(u16::try_from(&small), Ok(255));
+
+    let large: GarbledUint128 = (1_u128 << 64).into();
+    let error = large.try_into_u64().unwrap_err();
+    assert_eq!((error.required, error.available), (65, 64));
+    assert_eq!(
+        error.to_string(),
+        "value needs 65 bits but the target only holds 64"
+    );
+    assert_eq!(large.try_into_u128(), Ok(1 << 64));
+}
+
+#[test]
+fn test_byte_order_matches_primitives() {
+    let value = 0x0102_0304_u32;
+    let garbled: GarbledUint32 = value.into();
+    assert_eq!(garbled.to_le_bytes(), value.to_le_bytes());
+    assert_eq!(garbled.to_be_bytes(), value.to_be_bytes());
+
+    let from_le = GarbledUint32::from_le_bytes(&value.to_le_bytes()).unwrap();
+    let from_be = GarbledUint32::from_be_bytes(&value.to_be_bytes()).unwrap();
+    assert_eq!(u32::from(from_le), value);
+    assert_eq!(u32::from(from_be), value);
+}
+
+#[test]
+fn test_byte_order_pads_short_values() {
+    // `zero()` stores a single bit, but all N / 8 bytes are still produced
+    assert_eq!(GarbledUint64::zero().to_le_bytes(), vec![0; 8]);
+
+    // 12 bits take two bytes; the padding sits in the most significant byte
+    let value: GarbledUint<12> = 0xabc_u16.into();
+    assert_eq!(value.to_le_bytes(), vec![0xbc, 0x0a]);
+    assert_eq!(value.to_be_bytes(), vec![0x0a, 0xbc]);
+    let value = GarbledUint::<12>::from_be_bytes(&[0xfa, 0xbc]).unwrap();
+    assert_eq!(u16::from(value), 0xabc);
+}
+
+#[test]
+fn test_from_le_bytes_wrong_length() {
+    assert_eq!(
+        GarbledUint32::from_le_bytes(&[0; 3]).unwrap_err(),
+        LengthError {
+            expected: 4,
+            found: 3
+        }
+    );
+    assert!(GarbledUint32::from_be_bytes(&[0; 5]).is_err());
+
+    // a value stored in fewer bits still encodes to all `N.div_ceil(8)` bytes
+    assert_eq!(GarbledUint32::zero().to_bytes(), [0; 4]);
+}
+
+#[test]
+fn test_slice_and_concat() {
+    let value: GarbledUint32 = 0xdead_beef_u32.into();
+    let low: GarbledUint16 = value.slice(0);
+    let high: GarbledUint16 = value.slice(16);
+    assert_eq!(u16::from(low.clone()), 0xbeef);
+    assert_eq!(u16::from(high.clone()), 0xdead);
+    assert_eq!(u8::from(value.slice::<8>(12)), 0xdb);
+
+    let joined: GarbledUint32 = low.concat(&high);
+    assert_eq!(u32::from(joined), 0xdead_beef);
+    let swapped: GarbledUint32 = high.concat(&low);
+    assert_eq!(u32::from(swapped), 0xbeef_dead);
+
+    // short stored values are zero-padded to their width before joining
+    let padded: GarbledUint16 = GarbledUint8::one().concat(&GarbledUint8::one());
+    assert_eq!(u16::from(padded), 0x0101);
+}
+
+#[test]
+#[should_panic(expected = "8 bits at offset 28 do not fit in GarbledUint<32>")]
+fn test_slice_out_of_range() {
+    let value: GarbledUint32 = 1_u32.into();
+    value.slice::<8>(28);
+}
+
+#[test]
+fn test_constant_stores_all_bits() {
+    assert_eq!(GarbledUint8::constant(0).bits().len(), 8);
+    assert_eq!(u8::from(GarbledUint8::constant(1)), 1);
+    assert_eq!(u16::from(GarbledUint16::constant(0xbeef)), 0xbeef);
+    assert_eq!(
+        GarbledUint256::constant(u128::MAX).to_words(),
+        vec![u64::MAX, u64::MAX, 0, 0]
+    );
+
+    // unlike `one()`, a constant can be an operand of an N-bit circuit
+    let a: GarbledUint64 = 41_u64.into();
+    assert_eq!(u64::from(a + GarbledUint64::constant(1)), 42);
+}
+
+#[test]
+#[should_panic(expected = "256 does not fit in GarbledUint<8>")]
+fn test_constant_overflow() {
+    GarbledUint8::constant(256);
+}
+
+#[test]
+fn test_zero_extend_and_truncate() {
+    let value: GarbledUint8 = 0xf0_u8.into();
+    let wide: GarbledUint32 = value.zero_extend();
+    assert_eq!(u32::from(wide.clone()), 0xf0);
+    assert_eq!(
+        GarbledUint8::one().zero_extend::<64>().to_bits_le().len(),
+        64
+    );
+
+    let narrow: GarbledUint<4> = wide.truncate();
+    assert_eq!(u8::from(narrow), 0);
+    let value: GarbledUint32 = 0x1234_5678_u32.into();
+    assert_eq!(u16::from(value.truncate::<16>()), 0x5678);
+}
+
+#[test]
+fn test_parse_literals() {
+    let value: GarbledUint32 = "0xdeadbeef".parse().unwrap();
+    assert_eq!(u32::from(value), 0xdead_beef);
+    let value: GarbledUint8 = "0b1010_0101".parse().unwrap();
+    assert_eq!(u8::from(value), 0b1010_0101);
+    let value: GarbledUint16 = "0o777".parse().unwrap();
+    assert_eq!(u16::from(value), 0o777);
+    let value: GarbledUint64 = "+18446744073709551615".parse().unwrap();
+    assert_eq!(u64::from(value), u64::MAX);
+
+    assert_eq!(
+        u32::from(GarbledUint32::from_hex("DEAD_beef").unwrap()),
+        0xdead_beef
+    );
+    assert_eq!(u8::from(GarbledUint8::from_bin("0B11").unwrap()), 3);
+
+    // widths above 128 bits parse without a primitive in between
+    let max: GarbledUint256 = "0x"
+        .chars()
+        .chain(['f'; 64])
+        .collect::<String>()
+        .parse()
+        .unwrap();
+    assert_eq!(<[u64; 4]>::from(max), [u64::MAX; 4]);
+    let value: GarbledUint256 = "340282366920938463463374607431768211456".parse().unwrap();
+    assert_eq!(<[u64; 4]>::from(value), [0, 0, 1, 0]);
+}
+
+#[test]
+fn test_parse_errors() {
+    use compute::error::ParseError;
+
+    assert_eq!("".parse::<GarbledUint8>().unwrap_err(), ParseError::Empty);
+    assert_eq!("0x".parse::<GarbledUint8>().unwrap_err(), ParseError::Empty);
+    assert_eq!(
+        "12a".parse::<GarbledUint8>().unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        "0b102".parse::<GarbledUint8>().unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        "-1".parse::<GarbledUint8>().unwrap_err(),
+        ParseError::InvalidDigit
+    );
+    assert_eq!(
+        "256".parse::<GarbledUint8>().unwrap_err(),
+        ParseError::Overflow
+    );
+    assert_eq!(
+        GarbledUint::<12>::from_hex("1000").unwrap_err(),
+        ParseError::Overflow
+    );
+    assert!(GarbledUint::<12>::from_hex("fff").is_ok());
+}
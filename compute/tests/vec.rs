@@ -0,0 +1,73 @@
+use compute::prelude::*;
+
+fn padded(values: &[u8], capacity: usize) -> GarbledVec<GarbledUint8> {
+    let values = values.iter().map(|&value| value.into()).collect();
+    GarbledVec::from_values(values, capacity, GarbledUint8::from(0_u8))
+}
+
+fn live(vec: &GarbledVec<GarbledUint8>) -> Vec<bool> {
+    vec.live_mask()
+        .into_iter()
+        .map(|flag| flag.bits()[0])
+        .collect()
+}
+
+#[test]
+fn test_vec_push_and_len() {
+    let mut vec = GarbledVec::with_capacity(4, GarbledUint8::from(0_u8));
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(u32::from(vec.len().clone()), 0);
+
+    vec.push(7_u8.into());
+    vec.push(9_u8.into());
+    assert_eq!(u32::from(vec.len().clone()), 2);
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(live(&vec), [true, true, false, false]);
+}
+
+#[test]
+#[should_panic(expected = "GarbledVec is full")]
+fn test_vec_push_past_capacity() {
+    let mut vec = padded(&[1, 2], 2);
+    vec.push(3_u8.into());
+}
+
+#[test]
+fn test_vec_live_mask() {
+    assert_eq!(live(&padded(&[], 3)), [false, false, false]);
+    assert_eq!(live(&padded(&[5], 3)), [true, false, false]);
+    assert_eq!(live(&padded(&[5, 6, 7], 3)), [true, true, true]);
+}
+
+#[test]
+fn test_vec_is_empty() {
+    let mut vec = GarbledVec::with_capacity(2, GarbledUint8::from(0_u8));
+    assert!(vec.is_empty().bits()[0]);
+    vec.push(7_u8.into());
+    assert!(!vec.is_empty().bits()[0]);
+}
+
+#[test]
+fn test_vec_fold_ignores_padding() {
+    // the padding is nonzero so a sum that included it would be off
+    let values: Vec<GarbledUint16> = [10_u16, 20, 30].iter().map(|&v| v.into()).collect();
+    let vec = GarbledVec::from_values(values, 6, GarbledUint16::from(1000_u16));
+
+    let sum = vec.fold(GarbledUint16::from(0_u16), |acc, element| acc + element);
+    assert_eq!(u16::from(sum), 60);
+
+    let empty = GarbledVec::from_values(Vec::new(), 6, GarbledUint16::from(1000_u16));
+    let sum = empty.fold(GarbledUint16::from(5_u16), |acc, element| acc + element);
+    assert_eq!(u16::from(sum), 5);
+}
+
+#[test]
+fn test_vec_map_keeps_length() {
+    let vec = padded(&[1, 2, 3], 5);
+    let doubled = vec.map(|element| element + element);
+    assert_eq!(doubled.capacity(), 5);
+    assert_eq!(u32::from(doubled.len().clone()), 3);
+
+    let sum = doubled.fold(GarbledUint8::from(0_u8), |acc, element| acc + element);
+    assert_eq!(u8::from(sum), 12);
+}
@@ -0,0 +1,45 @@
+//! Exercises the single-threaded, deterministic execution path used on `wasm32` targets; the
+//! same tests also run on the threaded, entropy-seeded path elsewhere.
+
+use compute::prelude::*;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+#[test]
+fn test_wasm_macro_execution() {
+    #[encrypted(execute)]
+    fn qualifies_for_discount(purchase_amount: u16) -> bool {
+        let DISCOUNT_THRESHOLD = 80;
+        purchase_amount >= DISCOUNT_THRESHOLD
+    }
+
+    assert!(qualifies_for_discount(100_u16));
+    assert!(qualifies_for_discount(80_u16));
+    assert!(!qualifies_for_discount(79_u16));
+}
+
+#[test]
+fn test_wasm_batch_runs_on_calling_thread() {
+    let lhs: Vec<GarbledUint8> = (0..8_u8).map(|i| (i * 5).into()).collect();
+    let rhs: Vec<GarbledUint8> = (0..8_u8).map(|i| (i + 1).into()).collect();
+
+    let batched = GarbledUint8::batch_apply(WRK17CircuitBuilder::add, &lhs, &rhs);
+
+    for (i, result) in batched.into_iter().enumerate() {
+        let result: u8 = result.into();
+        assert_eq!(result, i as u8 * 6 + 1);
+    }
+}
+
+#[test]
+fn test_wasm_requires_explicit_rng() {
+    let circuit = Circuit::new(vec![Gate::InContrib], vec![0]);
+
+    assert_eq!(
+        GatewayGarbler::start(&circuit, &[true]).is_err(),
+        cfg!(target_arch = "wasm32")
+    );
+    assert!(
+        GatewayGarbler::start_with_rng(&circuit, &[true], ChaCha20Rng::seed_from_u64(7)).is_ok()
+    );
+}
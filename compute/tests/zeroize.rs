@@ -0,0 +1,40 @@
+#![cfg(feature = "zeroize")]
+
+use compute::prelude::*;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+#[test]
+fn test_values_zeroize_on_drop() {
+    assert_zeroize_on_drop::<GarbledUint64>();
+    assert_zeroize_on_drop::<GarbledInt64>();
+}
+
+#[test]
+fn test_zeroize_wipes_the_bits() {
+    let mut value: GarbledUint16 = 0xbeef_u16.into();
+    assert_eq!(value.bits().len(), 16);
+    value.zeroize();
+    assert!(value.bits().is_empty());
+    assert_eq!(u16::from(value), 0);
+
+    let mut value: GarbledInt64 = (-42_i64).into();
+    assert_eq!(value.bits().len(), 64);
+    value.zeroize();
+    assert!(value.bits().is_empty());
+    assert_eq!(i64::from(value), 0);
+}
+
+#[test]
+fn test_values_still_convert_and_execute() {
+    // `into_bits` takes the bits out, leaving nothing behind for the drop to wipe
+    let value: GarbledUint16 = 0xbeef_u16.into();
+    assert_eq!(u16::from(GarbledUint16::new(value.into_bits())), 0xbeef);
+    let value: GarbledInt16 = (-1234_i16).into();
+    assert_eq!(i16::from(GarbledUint16::from(value).to_signed()), -1234);
+
+    let a: GarbledUint32 = 1_000_000_u32.into();
+    let b: GarbledUint32 = 234_567_u32.into();
+    assert_eq!(u32::from(&a + &b), 1_234_567);
+}
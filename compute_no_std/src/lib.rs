@@ -0,0 +1,27 @@
+#![no_std]
+
+use compute::int::GarbledInt8;
+use compute::uint::{GarbledUint, GarbledUint8};
+
+/// Converts a `u8` into a `GarbledUint8` and back.
+pub fn roundtrip_u8(value: u8) -> u8 {
+    let garbled: GarbledUint8 = value.into();
+    garbled.into()
+}
+
+/// Converts an `i8` into a `GarbledInt8` and back.
+pub fn roundtrip_i8(value: i8) -> i8 {
+    let garbled: GarbledInt8 = value.into();
+    garbled.into()
+}
+
+/// Flips the bit at `index` by rebuilding the value from its bits.
+pub fn flip_bit(value: u8, index: usize) -> u8 {
+    let garbled: GarbledUint8 = value.into();
+    let flipped: GarbledUint<8> = garbled
+        .iter_bits()
+        .enumerate()
+        .map(|(i, bit)| bit ^ (i == index))
+        .collect();
+    flipped.into()
+}
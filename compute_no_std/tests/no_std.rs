@@ -0,0 +1,18 @@
+use compute_no_std::{flip_bit, roundtrip_i8, roundtrip_u8};
+
+#[test]
+fn test_no_std_roundtrip() {
+    for value in [0_u8, 1, 42, 127, 128, 255] {
+        assert_eq!(roundtrip_u8(value), value);
+    }
+
+    for value in [i8::MIN, -42, -1, 0, 1, i8::MAX] {
+        assert_eq!(roundtrip_i8(value), value);
+    }
+}
+
+#[test]
+fn test_no_std_bit_manipulation() {
+    assert_eq!(flip_bit(0b1010_1010, 0), 0b1010_1011);
+    assert_eq!(flip_bit(0b1010_1010, 7), 0b0010_1010);
+}